@@ -0,0 +1,238 @@
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: cli <command> [options] <file>
+
+Commands:
+  check <file>                 Parse and type check a file
+  build <file> [-o <out>]      Compile a file to a wasm module (defaults to <file>.wasm)
+  parse [--emit=<kind>] <file> Print the parsed file, where <kind> is `ast` (default), `tokens`
+                               or `estree-json`
+  help                         Print this message
+
+Options:
+  --error-format=<format>      Print errors as `human` (default) or `json`, one object per line";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Check {
+        file: PathBuf,
+        error_format: ErrorFormat,
+    },
+    Build {
+        file: PathBuf,
+        out: PathBuf,
+        error_format: ErrorFormat,
+    },
+    Parse {
+        file: PathBuf,
+        emit: EmitKind,
+        error_format: ErrorFormat,
+    },
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ErrorFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+impl std::str::FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown error format `{}`, expected `human` or `json`",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitKind {
+    Ast,
+    Tokens,
+    EstreeJson,
+}
+
+impl std::str::FromStr for EmitKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ast" => Ok(Self::Ast),
+            "tokens" => Ok(Self::Tokens),
+            "estree-json" => Ok(Self::EstreeJson),
+            _ => Err(format!(
+                "unknown emit kind `{}`, expected `ast`, `tokens` or `estree-json`",
+                s
+            )),
+        }
+    }
+}
+
+/// Parses the command line arguments, excluding the program name
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    let Some(command) = args.next() else {
+        return Err("no command given".to_owned());
+    };
+
+    let mut file: Option<PathBuf> = None;
+    let mut out: Option<PathBuf> = None;
+    let mut emit: Option<EmitKind> = None;
+    let mut error_format = ErrorFormat::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--out" if command == "build" => {
+                let Some(path) = args.next() else {
+                    return Err(format!("`{}` expects a path", arg));
+                };
+                out = Some(path.into());
+            }
+            "--emit" if command == "parse" => {
+                let Some(kind) = args.next() else {
+                    return Err("`--emit` expects `ast`, `tokens` or `estree-json`".to_owned());
+                };
+                emit = Some(kind.parse()?);
+            }
+            "--error-format" => {
+                let Some(format) = args.next() else {
+                    return Err("`--error-format` expects `human` or `json`".to_owned());
+                };
+                error_format = format.parse()?;
+            }
+            _ if arg.starts_with("--error-format=") => {
+                error_format = arg["--error-format=".len()..].parse()?;
+            }
+            _ if command == "parse" && arg.starts_with("--emit=") => {
+                emit = Some(arg["--emit=".len()..].parse()?);
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{}` for `{}`", arg, command));
+            }
+            _ if file.is_some() => return Err(format!("unexpected argument `{}`", arg)),
+            _ => file = Some(arg.into()),
+        }
+    }
+
+    let require_file = |file: Option<PathBuf>| file.ok_or_else(|| "no input file given".to_owned());
+
+    match command.as_str() {
+        "check" => Ok(Command::Check {
+            file: require_file(file)?,
+            error_format,
+        }),
+        "build" => {
+            let file = require_file(file)?;
+            let out = out.unwrap_or_else(|| file.with_extension("wasm"));
+            Ok(Command::Build {
+                file,
+                out,
+                error_format,
+            })
+        }
+        "parse" => Ok(Command::Parse {
+            file: require_file(file)?,
+            emit: emit.unwrap_or(EmitKind::Ast),
+            error_format,
+        }),
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => Err(format!("unknown command `{}`", command)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Command, EmitKind, ErrorFormat};
+    use pretty_assertions::assert_eq;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_owned()).collect()
+    }
+
+    #[test]
+    fn check_command() {
+        assert_eq!(
+            parse(args("check main.ts")),
+            Ok(Command::Check {
+                file: "main.ts".into(),
+                error_format: ErrorFormat::Human,
+            })
+        );
+        assert_eq!(
+            parse(args("check --error-format=json main.ts")),
+            Ok(Command::Check {
+                file: "main.ts".into(),
+                error_format: ErrorFormat::Json,
+            })
+        );
+    }
+
+    #[test]
+    fn build_command() {
+        assert_eq!(
+            parse(args("build main.ts -o out.wasm")),
+            Ok(Command::Build {
+                file: "main.ts".into(),
+                out: "out.wasm".into(),
+                error_format: ErrorFormat::Human,
+            })
+        );
+        assert_eq!(
+            parse(args("build src/main.ts")),
+            Ok(Command::Build {
+                file: "src/main.ts".into(),
+                out: "src/main.wasm".into(),
+                error_format: ErrorFormat::Human,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_command() {
+        assert_eq!(
+            parse(args("parse --emit=tokens main.ts")),
+            Ok(Command::Parse {
+                file: "main.ts".into(),
+                emit: EmitKind::Tokens,
+                error_format: ErrorFormat::Human,
+            })
+        );
+        assert_eq!(
+            parse(args("parse --emit estree-json main.ts")),
+            Ok(Command::Parse {
+                file: "main.ts".into(),
+                emit: EmitKind::EstreeJson,
+                error_format: ErrorFormat::Human,
+            })
+        );
+        assert_eq!(
+            parse(args("parse main.ts")),
+            Ok(Command::Parse {
+                file: "main.ts".into(),
+                emit: EmitKind::Ast,
+                error_format: ErrorFormat::Human,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(parse(args("")).is_err());
+        assert!(parse(args("check")).is_err());
+        assert!(parse(args("check a.ts b.ts")).is_err());
+        assert!(parse(args("check -o out.wasm a.ts")).is_err());
+        assert!(parse(args("parse --emit=wasm a.ts")).is_err());
+        assert!(parse(args("run a.ts")).is_err());
+        assert!(parse(args("check --error-format=xml a.ts")).is_err());
+    }
+}
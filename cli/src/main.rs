@@ -1,11 +1,194 @@
-use compiler::{compile, CompilerTarget};
+mod args;
 
-fn main() {
-    let code = r#"
-    var a = 123;
-    let b = 456;
-    const c = 789;
-    "#;
+use args::{Command, EmitKind, ErrorFormat, USAGE};
+use compiler::{compile, CodegenError, CompileError, CompilerTarget};
+use lexer::{Lexer, SourceFile};
+use parser::{
+    ast_types::programs::Program,
+    estree::to_estree_json,
+    utils::{diagnostic::Diagnostic, parser_error::ParserError},
+    Parser,
+};
+use semantic::errors::{ErrorSeverity, SemanticError};
+use std::{path::Path, process::ExitCode};
 
-    compile(&code, CompilerTarget::Wasm, "out.wasm");
+/// The source code contains syntax or semantic errors
+const EXIT_INVALID_SOURCE: u8 = 1;
+/// The command line arguments were invalid, or a file could not be read or written
+const EXIT_USAGE: u8 = 2;
+
+fn main() -> ExitCode {
+    let command = match args::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(msg) => {
+            eprintln!("error: {}\n\n{}", msg, USAGE);
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    match command {
+        Command::Check { file, error_format } => {
+            let Some(file) = read_source(&file) else {
+                return ExitCode::from(EXIT_USAGE);
+            };
+            match check(&file, error_format) {
+                Some(_) => ExitCode::SUCCESS,
+                None => ExitCode::from(EXIT_INVALID_SOURCE),
+            }
+        }
+        Command::Build {
+            file,
+            out,
+            error_format,
+        } => {
+            let Some(file) = read_source(&file) else {
+                return ExitCode::from(EXIT_USAGE);
+            };
+            match compile(file.text(), CompilerTarget::Wasm, &out) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(CompileError::Parse(errors)) => {
+                    report(&errors, &file, error_format);
+                    ExitCode::from(EXIT_INVALID_SOURCE)
+                }
+                Err(CompileError::Semantic(errors)) => {
+                    report(&errors, &file, error_format);
+                    ExitCode::from(EXIT_INVALID_SOURCE)
+                }
+                Err(CompileError::Io(err)) => {
+                    eprintln!("error: could not write `{}`: {}", out.display(), err);
+                    ExitCode::from(EXIT_USAGE)
+                }
+                Err(CompileError::Codegen(err)) => {
+                    report(&[err], &file, error_format);
+                    ExitCode::from(EXIT_INVALID_SOURCE)
+                }
+            }
+        }
+        Command::Parse {
+            file,
+            emit,
+            error_format,
+        } => {
+            let Some(file) = read_source(&file) else {
+                return ExitCode::from(EXIT_USAGE);
+            };
+            match emit {
+                EmitKind::Tokens => {
+                    let mut lexer = Lexer::new(file.text());
+                    for token in lexer.by_ref() {
+                        println!("{:?}", token);
+                    }
+
+                    // Lexing goes on after an error, so every error is reported after the tokens
+                    let errors = lexer
+                        .take_errors()
+                        .into_iter()
+                        .map(ParserError::from)
+                        .collect::<Vec<_>>();
+                    if errors.is_empty() {
+                        ExitCode::SUCCESS
+                    } else {
+                        report(&errors, &file, error_format);
+                        ExitCode::from(EXIT_INVALID_SOURCE)
+                    }
+                }
+                EmitKind::Ast | EmitKind::EstreeJson => {
+                    let (program, errors) = Parser::new(file.text()).parse_with_errors();
+                    if errors.is_empty() {
+                        match emit {
+                            EmitKind::EstreeJson => println!("{}", to_estree_json(&program, &file)),
+                            _ => println!("{:#?}", program),
+                        }
+                        ExitCode::SUCCESS
+                    } else {
+                        report(&errors, &file, error_format);
+                        ExitCode::from(EXIT_INVALID_SOURCE)
+                    }
+                }
+            }
+        }
+        Command::Help => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn read_source(path: &Path) -> Option<SourceFile> {
+    match std::fs::read_to_string(path) {
+        Ok(source) => Some(SourceFile::new(path.display().to_string(), source)),
+        Err(err) => {
+            eprintln!("error: could not read `{}`: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+/// An error that can be reported to the user
+trait Report {
+    fn print(&self, file: &SourceFile);
+    fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic;
+}
+
+impl Report for ParserError {
+    fn print(&self, file: &SourceFile) {
+        ParserError::print(self, file)
+    }
+
+    fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        ParserError::to_diagnostic(self, file)
+    }
+}
+
+impl Report for SemanticError {
+    fn print(&self, file: &SourceFile) {
+        SemanticError::print(self, file)
+    }
+
+    fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        SemanticError::to_diagnostic(self, file)
+    }
+}
+
+impl Report for CodegenError {
+    fn print(&self, file: &SourceFile) {
+        CodegenError::print(self, file)
+    }
+
+    fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        CodegenError::to_diagnostic(self, file)
+    }
+}
+
+/// Prints the errors to stderr, either rendered for humans or as one JSON object per line
+fn report(errors: &[impl Report], file: &SourceFile, format: ErrorFormat) {
+    for err in errors.iter() {
+        match format {
+            ErrorFormat::Human => err.print(file),
+            ErrorFormat::Json => eprintln!("{}", err.to_diagnostic(file).to_json()),
+        }
+    }
+}
+
+/// Parses and analyzes the source code, printing every error that is found.
+/// Returns `None` if the source code contains syntax errors or critical semantic errors.
+fn check(file: &SourceFile, error_format: ErrorFormat) -> Option<Program> {
+    let (program, parser_errors) = Parser::new(file.text()).parse_with_errors();
+
+    if !parser_errors.is_empty() {
+        report(&parser_errors, file, error_format);
+        return None;
+    }
+
+    let errors = semantic::analyze(&program);
+    report(&errors, file, error_format);
+
+    if errors
+        .iter()
+        .any(|err| err.severity == ErrorSeverity::Critical)
+    {
+        None
+    } else {
+        Some(program)
+    }
 }
@@ -0,0 +1,114 @@
+use lexer::SourceFile;
+use parser::{
+    ast_types::node_objects::Node,
+    utils::{
+        diagnostic::{Diagnostic, DiagnosticSpan, Severity},
+        parser_error::ParserError,
+    },
+};
+use semantic::errors::SemanticError;
+
+/// Every way that compiling a program can fail
+#[derive(Debug)]
+pub enum CompileError {
+    /// The source code contains one or more syntax errors
+    Parse(Vec<ParserError>),
+    /// The program contains at least one critical semantic error.
+    /// Warnings that were found alongside it are included as well.
+    Semantic(Vec<SemanticError>),
+    /// The program is valid, but the target can't generate code for it
+    Codegen(CodegenError),
+    /// The compiled module could not be written to disk
+    Io(std::io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodegenError {
+    pub node: Node,
+    pub message: String,
+}
+
+impl CodegenError {
+    pub fn unsupported(what: &str, node: Node) -> Self {
+        Self {
+            node,
+            message: format!("{} are not yet supported by this target", what),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        "C0001"
+    }
+
+    pub fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        Diagnostic {
+            code: self.code().to_owned(),
+            severity: Severity::Error,
+            message: self.message.clone(),
+            primary: DiagnosticSpan::new(
+                file.line_index(),
+                self.node.start,
+                self.node.end,
+                Some("can't be compiled yet".to_owned()),
+            ),
+            secondary: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn print(&self, file: &SourceFile) {
+        self.to_diagnostic(file).print(file)
+    }
+}
+
+impl std::fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error[{}]: {}", self.code(), self.message)
+    }
+}
+
+impl std::fmt::Display for CompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(errors) => {
+                let out = errors
+                    .iter()
+                    .map(|err| format!("error[{}]: {}", err.kind.code(), err.kind.message()))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                write!(f, "{}", out)
+            }
+            Self::Semantic(errors) => {
+                let out = errors
+                    .iter()
+                    .map(|err| err.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                write!(f, "{}", out)
+            }
+            Self::Codegen(err) => write!(f, "{}", err),
+            Self::Io(err) => write!(f, "Failed to write the compiled module: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CodegenError> for CompileError {
+    fn from(value: CodegenError) -> Self {
+        Self::Codegen(value)
+    }
+}
+
+impl From<std::io::Error> for CompileError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
@@ -1,6 +1,10 @@
+mod errors;
 mod targets;
 
-use parser::{nodes::program::Program, Parser};
+pub use errors::{CodegenError, CompileError};
+use parser::{ast_types::programs::Program, Parser};
+use semantic::errors::ErrorSeverity;
+use std::path::Path;
 
 #[derive(Debug, Clone, Copy)]
 pub enum CompilerTarget {
@@ -9,33 +13,44 @@ pub enum CompilerTarget {
 
 pub trait Compiler<'a> {
     fn new(program: &'a Program) -> Self;
-    fn compile(&mut self, out_path: &str);
+    fn compile(&mut self) -> Result<Vec<u8>, CodegenError>;
 }
 
-pub fn compile(code: &str, target: CompilerTarget, out_path: &str) {
-    let mut parser = Parser::new(&code);
-    let result = parser.parse();
+/// Compiles the source code and returns the compiled module in memory
+pub fn compile_to_bytes(code: &str, target: CompilerTarget) -> Result<Vec<u8>, CompileError> {
+    let mut parser = Parser::new(code);
+    let (ast, parser_errors) = parser.parse_with_errors();
 
-    if let Err(err) = result {
-        panic!("{:?}", err);
+    if !parser_errors.is_empty() {
+        return Err(CompileError::Parse(parser_errors));
     }
 
-    let ast = result.unwrap();
-
     let semantic_result = semantic::analyze(&ast);
 
-    for err in semantic_result.iter() {
-        eprintln!("\n\n{}", &err);
-    }
-
-    if semantic_result.len() != 0 {
-        return;
+    if semantic_result
+        .iter()
+        .any(|err| err.severity == ErrorSeverity::Critical)
+    {
+        return Err(CompileError::Semantic(semantic_result));
     }
 
-    match target {
+    let bytes = match target {
         CompilerTarget::Wasm => {
             let mut compiler = targets::wasm::WasmCompiler::new(&ast);
-            compiler.compile(out_path);
+            compiler.compile()?
         }
-    }
+    };
+
+    Ok(bytes)
+}
+
+/// Compiles the source code and writes the compiled module to `out_path`
+pub fn compile(
+    code: &str,
+    target: CompilerTarget,
+    out_path: impl AsRef<Path>,
+) -> Result<(), CompileError> {
+    let bytes = compile_to_bytes(code, target)?;
+    std::fs::write(out_path, bytes)?;
+    Ok(())
 }
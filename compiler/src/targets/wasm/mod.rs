@@ -1,10 +1,16 @@
-use parser::{nodes::program::Program, statements::Statement};
+use parser::ast_types::{
+    node_objects::Spanned,
+    programs::{Program, ProgramBody},
+    statements::{FunctionBodyBody, Statement},
+};
+use std::collections::HashMap;
+use string_cache::DefaultAtom as Atom;
 use wasm_encoder::{
     CodeSection, ExportSection, Function, FunctionSection, Instruction, Module, TypeSection,
     ValType,
 };
 
-use crate::Compiler;
+use crate::{CodegenError, Compiler};
 
 pub struct WasmCompiler<'a> {
     program: &'a Program,
@@ -12,7 +18,8 @@ pub struct WasmCompiler<'a> {
     functions: FunctionSection,
     exports: ExportSection,
     codes: CodeSection,
-    // scopes: todo!(),
+    /// The local variables of each nested scope, mapped to their local index
+    scopes: Vec<HashMap<Atom, u32>>,
 }
 
 impl<'a> Compiler<'a> for WasmCompiler<'a> {
@@ -28,36 +35,42 @@ impl<'a> Compiler<'a> for WasmCompiler<'a> {
             functions,
             exports,
             codes,
+            scopes: Vec::new(),
         }
     }
 
-    fn compile(&mut self, out_path: &str) {
+    fn compile(&mut self) -> Result<Vec<u8>, CodegenError> {
         let mut module = Module::new();
 
-        for stmt in self.program.body.iter() {
-            self.enter_statement(stmt);
+        for item in self.program.body.iter() {
+            match item {
+                ProgramBody::Statement(stmt) => {
+                    self.enter_statement(stmt)?;
+                }
+                ProgramBody::ImportOrExportDeclaration(decl) => {
+                    return Err(CodegenError::unsupported("Modules", *decl.node()));
+                }
+            }
         }
 
         module.section(&self.types);
         module.section(&self.functions);
         module.section(&self.exports);
         module.section(&self.codes);
-        let wasm_bytes = module.finish();
-
-        std::fs::write(out_path, wasm_bytes).expect("Failed to write to file");
+        Ok(module.finish())
     }
 }
 
 impl<'a> WasmCompiler<'a> {
     fn push_scope(&mut self) {
-        todo!()
+        self.scopes.push(HashMap::new());
     }
 
     fn pop_scope(&mut self) {
-        todo!()
+        self.scopes.pop();
     }
 
-    fn enter_statement(&mut self, stmt: &Statement) -> Instruction<'a> {
+    fn enter_statement(&mut self, stmt: &Statement) -> Result<Instruction<'a>, CodegenError> {
         match stmt {
             Statement::FunctionDeclaration(decl) => {
                 self.push_scope();
@@ -74,18 +87,29 @@ impl<'a> WasmCompiler<'a> {
 
                 let mut _f = Function::new(locals);
 
-                for s in decl.body.statements.iter() {
-                    self.enter_statement(s);
+                for item in decl.body.body.iter() {
+                    if let FunctionBodyBody::Statement(s) = item {
+                        self.enter_statement(s)?;
+                    }
                 }
 
                 self.pop_scope();
-                todo!()
+                Err(CodegenError::unsupported(
+                    "Function declarations",
+                    decl.node,
+                ))
             }
-            Statement::ReturnStatement(_stmt) => todo!(),
-            Statement::VariableDeclaration(_decl) => {
-                todo!()
+            Statement::ReturnStatement(stmt) => {
+                Err(CodegenError::unsupported("Return statements", stmt.node))
             }
-            _ => todo!("{:?}", &stmt),
+            Statement::VariableDeclaration(decl) => Err(CodegenError::unsupported(
+                "Variable declarations",
+                decl.node,
+            )),
+            _ => Err(CodegenError::unsupported(
+                "Statements of this kind",
+                *stmt.node(),
+            )),
         }
     }
 }
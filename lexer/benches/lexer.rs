@@ -0,0 +1,23 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use lexer::{Lexer, TokenKind};
+
+const JQUERY: &str = include_str!("../../jquery-3.7.1.js");
+
+fn lex(source_code: &str) -> usize {
+    let mut lexer = Lexer::new(source_code);
+    let mut count = 0;
+    while !lexer.next_token().is(TokenKind::Eof) {
+        count += 1;
+    }
+    count
+}
+
+fn jquery(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(JQUERY.len() as u64));
+    group.bench_function("jquery-3.7.1.js", |b| b.iter(|| lex(black_box(JQUERY))));
+    group.finish();
+}
+
+criterion_group!(benches, jquery);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+/// A comment in the source code.
+/// The lexer skips over comments, but collects them for tools like formatters and documentation generators.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Comment {
+    #[serde(rename = "type")]
+    pub kind: CommentKind,
+    /// The text of the comment, without its `//`, `/*` or `*/` delimiters
+    #[serde(rename = "value")]
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub enum CommentKind {
+    /// A `// ...` comment
+    Line,
+    /// A `/* ... */` comment
+    Block,
+}
@@ -0,0 +1,116 @@
+/// An error found while tokenizing, like a malformed number.
+/// The lexer never stops at an error: it records it, and still produces a best-effort token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LexError {
+    pub fn new(kind: LexErrorKind, start: usize, end: usize) -> Self {
+        Self { kind, start, end }
+    }
+}
+
+/// Every kind of lexical error, each with a stable code.
+/// Codes must never be reused or renumbered, since tooling may depend on them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LexErrorKind {
+    /// L0001
+    MissingDigits,
+    /// L0002
+    InvalidNumericSeparator,
+    /// L0003
+    InvalidBigInt,
+    /// L0004
+    LeadingZero,
+    /// L0005
+    InvalidNumberSuffix,
+    /// L0006
+    InvalidHexEscape,
+    /// L0007
+    InvalidUnicodeEscape,
+    /// L0008
+    OctalEscape,
+    /// L0009
+    UnterminatedString,
+    /// L0010
+    UnterminatedTemplate,
+    /// L0011
+    UnterminatedRegex,
+    /// L0012
+    InvalidRegexFlags,
+    /// L0013
+    InvalidIdentifierEscape,
+    /// L0014
+    UnterminatedComment,
+    /// L0015
+    UnexpectedCharacter,
+}
+
+impl LexErrorKind {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MissingDigits => "L0001",
+            Self::InvalidNumericSeparator => "L0002",
+            Self::InvalidBigInt => "L0003",
+            Self::LeadingZero => "L0004",
+            Self::InvalidNumberSuffix => "L0005",
+            Self::InvalidHexEscape => "L0006",
+            Self::InvalidUnicodeEscape => "L0007",
+            Self::OctalEscape => "L0008",
+            Self::UnterminatedString => "L0009",
+            Self::UnterminatedTemplate => "L0010",
+            Self::UnterminatedRegex => "L0011",
+            Self::InvalidRegexFlags => "L0012",
+            Self::InvalidIdentifierEscape => "L0013",
+            Self::UnterminatedComment => "L0014",
+            Self::UnexpectedCharacter => "L0015",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::MissingDigits => "expected digits in numeric literal",
+            Self::InvalidNumericSeparator => "invalid numeric separator",
+            Self::InvalidBigInt => "invalid BigInt literal",
+            Self::LeadingZero => "numeric literals cannot start with a leading `0`",
+            Self::InvalidNumberSuffix => {
+                "an identifier or digit cannot directly follow a numeric literal"
+            }
+            Self::InvalidHexEscape => "invalid hexadecimal escape sequence",
+            Self::InvalidUnicodeEscape => "invalid Unicode escape sequence",
+            Self::OctalEscape => "octal escape sequences are not allowed",
+            Self::UnterminatedString => "unterminated string literal",
+            Self::UnterminatedTemplate => "unterminated template literal",
+            Self::UnterminatedRegex => "unterminated regular expression",
+            Self::InvalidRegexFlags => "invalid regular expression flags",
+            Self::InvalidIdentifierEscape => "invalid escape sequence in identifier",
+            Self::UnterminatedComment => "unterminated comment",
+            Self::UnexpectedCharacter => "unexpected character",
+        }
+    }
+
+    pub fn help(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidNumericSeparator => {
+                Some("`_` is only allowed between two digits, like `1_000`")
+            }
+            Self::InvalidBigInt => Some("BigInt literals must be integers, like `10n`"),
+            Self::LeadingZero => Some("use the `0o` prefix for octal numbers, like `0o17`"),
+            Self::InvalidHexEscape => Some("hexadecimal escapes have two digits, like `\\x41`"),
+            Self::InvalidUnicodeEscape => {
+                Some("Unicode escapes are written like `\\u0041` or `\\u{1F600}`")
+            }
+            Self::OctalEscape => Some("use a hexadecimal escape instead, like `\\x41`"),
+            Self::InvalidRegexFlags => Some(
+                "the flags are `d`, `g`, `i`, `m`, `s`, `u`, `v` and `y`, each used at most once",
+            ),
+            Self::InvalidIdentifierEscape => {
+                Some("identifiers can only contain `\\u` escapes of letters, digits, `$` and `_`")
+            }
+            _ => None,
+        }
+    }
+}
@@ -40,6 +40,8 @@ pub enum Keyword {
     False,
     Typeof,
     Throw,
+    Import,
+    Export,
 
     // Typescript keywords
     StringType,  // : string
@@ -91,6 +93,8 @@ impl std::fmt::Display for Keyword {
             Self::False => "false",
             Self::Typeof => "typeof",
             Self::Throw => "throw",
+            Self::Import => "import",
+            Self::Export => "export",
 
             // Typescript keywords
             Self::StringType => "string",
@@ -143,6 +147,8 @@ static KEYWORDS: phf::Map<&'static str, Keyword> = phf_map! {
     "false" => Keyword::False,
     "typeof" => Keyword::Typeof,
     "throw" => Keyword::Throw,
+    "import" => Keyword::Import,
+    "export" => Keyword::Export,
 
     // TypeScript keywords
     "string" => Keyword::StringType,
@@ -152,16 +158,42 @@ static KEYWORDS: phf::Map<&'static str, Keyword> = phf_map! {
     "declare" => Keyword::Declare
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TypeKeyword {
     String,
     Number,
     Boolean,
 }
 
+impl std::str::FromStr for Keyword {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KEYWORDS.get(s).cloned().ok_or(())
+    }
+}
+
 impl Keyword {
-    pub fn from_str(s: &str) -> Option<Keyword> {
-        KEYWORDS.get(s).cloned()
+    /// Whether the keyword is only reserved in some places, so it can also be an identifier,
+    /// like `type` in `let type = "a"` or `of` in `of(1)`
+    pub fn is_contextual(&self) -> bool {
+        matches!(
+            self,
+            Self::Async
+                | Self::Static
+                | Self::Of
+                | Self::Abstract
+                | Self::Implements
+                | Self::Private
+                | Self::Protected
+                | Self::Interface
+                | Self::Type
+                | Self::StringType
+                | Self::NumberType
+                | Self::BooleanType
+                | Self::Declare
+        )
     }
 
     pub fn as_type_keyword(&self) -> Option<TypeKeyword> {
@@ -1,33 +1,148 @@
 use crate::{
-    token::RegexValue, AssignmentOperator, BinaryOperator, Keyword, LogicalOperator, Token,
-    TokenKind, TokenValue, UnaryOperator, UpdateOperator,
+    comment::{Comment, CommentKind},
+    error::{LexError, LexErrorKind},
+    operators::Operator,
+    token::{RegexValue, TemplateValue},
+    AssignmentOperator, BinaryOperator, Keyword, LogicalOperator, Token, TokenKind, TokenValue,
+    UnaryOperator, UpdateOperator,
 };
-use std::{collections::VecDeque, str::Chars};
+use std::{borrow::Cow, collections::VecDeque};
+
+/// A saved state of the lexer, to go back to with `Lexer::rewind`
+#[derive(Debug, Clone)]
+pub struct LexerCheckpoint {
+    position: usize,
+    curr_char: Option<char>,
+    token_queue: VecDeque<Token>,
+    errors_len: usize,
+    comments_len: usize,
+    braces: Vec<bool>,
+    regex_allowed: bool,
+    parens: Vec<bool>,
+    last_keyword: Option<Keyword>,
+    after_dot: bool,
+    after_close_brace: bool,
+}
 
 pub struct Lexer<'a> {
     source: &'a str,
-    chars: Chars<'a>,
     position: usize,
     curr_char: Option<char>,
-    char_queue: VecDeque<char>,
     token_queue: VecDeque<Token>,
+    errors: Vec<LexError>,
+    comments: Vec<Comment>,
+    /// For every open `{`, whether it's the `${` of a template substitution,
+    /// so that its `}` continues the template
+    braces: Vec<bool>,
+    /// Whether a `/` starts a regular expression rather than a division, which depends on the previous token
+    regex_allowed: bool,
+    /// For every open `(`, whether it's the condition of an `if`, `for` or `while`,
+    /// so that a regex can follow its `)`
+    parens: Vec<bool>,
+    last_keyword: Option<Keyword>,
+    /// Whether the last token was a `.`, after which a keyword is a property name, like in `a.default`
+    after_dot: bool,
+    /// Whether the last token was a `}`, which can end either a block or an expression
+    after_close_brace: bool,
+    /// The state before the last `/` that was lexed as a regex right after a `}`,
+    /// so that the parser can lex it again as a division with `relex_as_division`
+    regex_after_brace: Option<LexerCheckpoint>,
+    /// Scratch space for the cooked text of string and template tokens, which is only decoded
+    /// to check the escape sequences, and reused between tokens so that lexing doesn't allocate
+    string_buffer: String,
+    template_buffer: TemplateValue,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(source: &'a str) -> Self {
-        let mut chars = source.chars();
-        let curr_char = chars.next();
-
         Self {
             source,
-            chars,
             position: 0,
-            curr_char,
-            char_queue: Default::default(),
+            curr_char: source.chars().next(),
             token_queue: Default::default(),
+            errors: Vec::new(),
+            comments: Vec::new(),
+            braces: Vec::new(),
+            regex_allowed: true,
+            parens: Vec::new(),
+            last_keyword: None,
+            after_dot: false,
+            after_close_brace: false,
+            regex_after_brace: None,
+            string_buffer: String::new(),
+            template_buffer: TemplateValue::default(),
         }
     }
 
+    /// The errors found in the tokens lexed so far
+    pub fn errors(&self) -> &[LexError] {
+        &self.errors
+    }
+
+    /// Takes the errors found in the tokens lexed so far
+    pub fn take_errors(&mut self) -> Vec<LexError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Takes the comments found before the tokens lexed so far
+    pub fn take_comments(&mut self) -> Vec<Comment> {
+        std::mem::take(&mut self.comments)
+    }
+
+    /// Saves the current state, so that the lexer can later go back to it with `rewind`
+    pub fn checkpoint(&self) -> LexerCheckpoint {
+        LexerCheckpoint {
+            position: self.position,
+            curr_char: self.curr_char,
+            token_queue: self.token_queue.clone(),
+            errors_len: self.errors.len(),
+            comments_len: self.comments.len(),
+            braces: self.braces.clone(),
+            regex_allowed: self.regex_allowed,
+            parens: self.parens.clone(),
+            last_keyword: self.last_keyword,
+            after_dot: self.after_dot,
+            after_close_brace: self.after_close_brace,
+        }
+    }
+
+    /// Goes back to a saved state, forgetting the errors and comments found since then.
+    /// Errors and comments that were taken since the checkpoint aren't restored.
+    pub fn rewind(&mut self, checkpoint: LexerCheckpoint) {
+        self.position = checkpoint.position;
+        self.curr_char = checkpoint.curr_char;
+        self.token_queue = checkpoint.token_queue;
+        self.errors.truncate(checkpoint.errors_len);
+        self.comments.truncate(checkpoint.comments_len);
+        self.braces = checkpoint.braces;
+        self.regex_allowed = checkpoint.regex_allowed;
+        self.parens = checkpoint.parens;
+        self.last_keyword = checkpoint.last_keyword;
+        self.after_dot = checkpoint.after_dot;
+        self.after_close_brace = checkpoint.after_close_brace;
+    }
+
+    /// Lexes the last token again as a division, or as `/=`, when it was lexed as a regex right after a `}`.
+    /// A `}` usually ends a block, after which a `/` starts a regex, but the parser knows when it ends
+    /// an expression instead, like in `{} / 2` or `function() {} / 2`.
+    /// Returns `None` if the token wasn't lexed as a regex after a `}`.
+    pub fn relex_as_division(&mut self, token: &Token) -> Option<Token> {
+        let checkpoint = self
+            .regex_after_brace
+            .take()
+            .filter(|checkpoint| checkpoint.position == token.start)?;
+
+        self.rewind(checkpoint);
+        // The tokens that were queued before the regex have been taken already
+        self.token_queue.clear();
+        self.regex_allowed = false;
+
+        Some(Token {
+            newline_before: token.newline_before,
+            ..self.lex_next_token()
+        })
+    }
+
     pub fn next_token(&mut self) -> Token {
         if let Some(token) = self.token_queue.pop_front() {
             token
@@ -37,11 +152,7 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn peek_token(&mut self) -> &Token {
-        if self.token_queue.is_empty() {
-            let token = self.lex_next_token();
-            self.token_queue.push_back(token);
-        }
-        self.token_queue.front().unwrap()
+        self.peek_token_at(0)
     }
 
     pub fn peek_token_at(&mut self, offset: usize) -> &Token {
@@ -49,66 +160,147 @@ impl<'a> Lexer<'a> {
             let token = self.lex_next_token();
             self.token_queue.push_back(token);
         }
-        self.token_queue.get(offset).unwrap()
+        &self.token_queue[offset]
     }
 
-    fn peek_char(&mut self, offset: usize) -> Option<char> {
-        while self.char_queue.len() <= offset {
-            if let Some(ch) = self.chars.next() {
-                self.char_queue.push_back(ch);
-            } else {
-                return None;
-            }
-        }
-        self.char_queue.get(offset).cloned()
+    /// The character `offset + 1` characters after the current one
+    fn peek_char(&self, offset: usize) -> Option<char> {
+        self.source[self.position..].chars().nth(offset + 1)
     }
 
     fn advance(&mut self) {
         if let Some(ch) = self.curr_char {
             self.position += ch.len_utf8();
+            self.curr_char = self.source[self.position..].chars().next();
         }
-        if self.char_queue.len() > 0 {
-            self.curr_char = self.char_queue.pop_front();
-        } else {
-            self.curr_char = self.chars.next();
+    }
+
+    /// Skips the whitespace and comments before the next token, collecting the comments.
+    /// Returns whether they contain a line terminator.
+    fn skip_trivia(&mut self) -> bool {
+        let mut newline_before = false;
+
+        loop {
+            let next_char = self.peek_char(0);
+            match self.curr_char {
+                Some(ch) if is_line_terminator(ch) => {
+                    newline_before = true;
+                    self.advance();
+                }
+                Some(ch) if ch.is_whitespace() || ch == '\u{FEFF}' => self.advance(),
+                Some('/') if next_char == Some('/') => self.parse_line_comment(),
+                Some('/') if next_char == Some('*') => newline_before |= self.parse_block_comment(),
+                _ => return newline_before,
+            }
         }
     }
 
-    fn skip_whitespace(&mut self) {
-        while self.curr_char.is_some_and(|ch| ch.is_whitespace()) {
+    /// Parses a `// ...` comment, up to the end of the line
+    fn parse_line_comment(&mut self) {
+        let start_pos = self.position;
+        self.advance(); // Skip "/"
+        self.advance(); // Skip "/"
+
+        while self.curr_char.is_some_and(|ch| !is_line_terminator(ch)) {
             self.advance();
         }
+
+        self.comments.push(Comment {
+            kind: CommentKind::Line,
+            text: self.source[start_pos + 2..self.position].to_owned(),
+            start: start_pos,
+            end: self.position,
+        });
+    }
+
+    /// Parses a `/* ... */` comment, and returns whether it contains a line terminator
+    fn parse_block_comment(&mut self) -> bool {
+        let start_pos = self.position;
+        let mut has_newline = false;
+        self.advance(); // Skip "/"
+        self.advance(); // Skip "*"
+
+        let text_end = loop {
+            let next_char = self.peek_char(0);
+            match self.curr_char {
+                Some('*') if next_char == Some('/') => {
+                    let text_end = self.position;
+                    self.advance(); // Skip "*"
+                    self.advance(); // Skip "/"
+                    break text_end;
+                }
+                Some(ch) => {
+                    has_newline |= is_line_terminator(ch);
+                    self.advance();
+                }
+                None => {
+                    self.error(LexErrorKind::UnterminatedComment, start_pos);
+                    break self.position;
+                }
+            }
+        };
+
+        self.comments.push(Comment {
+            kind: CommentKind::Block,
+            text: self.source[start_pos + 2..text_end].to_owned(),
+            start: start_pos,
+            end: self.position,
+        });
+
+        has_newline
     }
 
     fn lex_next_token(&mut self) -> Token {
         use TokenKind as TK;
         use TokenValue as TV;
-        self.skip_whitespace();
+        let newline_before = self.skip_trivia();
 
-        if self.curr_char.is_none() {
-            return Token::eof();
-        }
+        let Some(ch) = self.curr_char else {
+            return Token {
+                start: self.position,
+                end: self.position,
+                newline_before,
+                ..Token::eof()
+            };
+        };
 
         let start = self.position;
 
-        let (token_kind, token_value) = match self.curr_char.unwrap() {
-            '0'..='9' => (TK::Number, TV::Number(self.parse_number())),
-            'a'..='z' | 'A'..='Z' | '_' | '$' => {
-                let word = self.parse_identifier();
-                if let Some(keyword) = Keyword::from_str(word) {
-                    match keyword {
-                        Keyword::True => (TK::Boolean, TV::Boolean(true)),
-                        Keyword::False => (TK::Boolean, TV::Boolean(false)),
-                        _ => (TK::Keyword, TV::Keyword(keyword)),
-                    }
-                } else {
-                    (TK::Identifier, TV::Identifier(word.into()))
+        let (token_kind, token_value) = match ch {
+            '0'..='9' => self.parse_number(),
+            ch if ch == '\\' || is_identifier_start(ch) => {
+                let (name, has_escapes) = self.parse_identifier();
+                // An escaped keyword, like `\u0069f`, is always an identifier
+                match name.parse::<Keyword>().ok().filter(|_| !has_escapes) {
+                    Some(Keyword::True) => (TK::Boolean, TV::Boolean(true)),
+                    Some(Keyword::False) => (TK::Boolean, TV::Boolean(false)),
+                    Some(keyword) => (TK::Keyword, TV::Keyword(keyword)),
+                    None if name == "null" && !has_escapes => (TK::Null, TV::None),
+                    None => (TK::Identifier, TV::None),
+                }
+            }
+            // A private class member, like `#count`
+            '#' if self
+                .peek_char(0)
+                .is_some_and(|ch| ch == '\\' || is_identifier_start(ch)) =>
+            {
+                self.advance(); // Skip "#"
+                self.parse_identifier();
+                (TK::PrivateName, TV::None)
+            }
+            quote @ ('"' | '\'') => {
+                let mut cooked = std::mem::take(&mut self.string_buffer);
+                self.parse_string_literal(quote, &mut cooked);
+                self.string_buffer = cooked;
+                (TK::String, TV::None)
+            }
+            '`' => {
+                self.advance(); // Skip "`"
+                match self.scan_template_part(start) {
+                    true => (TK::NoSubstitutionTemplate, TV::None),
+                    false => (TK::TemplateHead, TV::None),
                 }
             }
-            '"' | '\'' | '`' => (
-                TK::String,
-                TV::String(self.parse_string_literal(false).into()),
-            ),
             '!' => {
                 self.advance();
                 match self.curr_char {
@@ -189,8 +381,15 @@ impl<'a> Lexer<'a> {
                 }
             }
             '/' => {
+                if self.regex_allowed && self.after_close_brace {
+                    self.regex_after_brace = Some(self.checkpoint());
+                }
                 self.advance();
                 match self.curr_char {
+                    _ if self.regex_allowed => {
+                        self.parse_regex(start);
+                        (TK::RegexLiteral, TV::None)
+                    }
                     Some('=') => {
                         self.advance();
                         (
@@ -198,44 +397,7 @@ impl<'a> Lexer<'a> {
                             TV::Operator(AssignmentOperator::DivEquals.into()),
                         )
                     }
-                    Some('/') => {
-                        // Single-line comment
-                        self.advance();
-                        while self.curr_char.is_some_and(|ch| ch != '\n') {
-                            self.advance();
-                        }
-                        (TK::SingleLineComment, TokenValue::None)
-                    }
-                    Some('*') => {
-                        // Multi-line comments
-                        let mut is_escaped = false;
-                        loop {
-                            match self.curr_char {
-                                Some('\\') => {
-                                    is_escaped = !is_escaped;
-                                }
-                                Some('*') => {
-                                    self.advance();
-                                    if self.curr_char == Some('/') {
-                                        self.advance();
-                                        break;
-                                    }
-                                }
-                                Some(_) => self.advance(),
-                                None => break,
-                            }
-                        }
-                        (TokenKind::MultiLineComment, TokenValue::None)
-                    }
-                    None => (TK::Operator, TV::Operator(BinaryOperator::Div.into())),
-                    _ => {
-                        if let Some(regex_val) = self.maybe_consume_regex(start) {
-                            (TK::RegexLiteral, regex_val)
-                        } else {
-                            self.advance();
-                            (TK::Operator, TV::Operator(BinaryOperator::Div.into()))
-                        }
-                    }
+                    _ => (TK::Operator, TV::Operator(BinaryOperator::Div.into())),
                 }
             }
             '%' => {
@@ -258,6 +420,13 @@ impl<'a> Lexer<'a> {
                         self.advance();
                         (TK::Operator, TV::Operator(LogicalOperator::And.into()))
                     }
+                    Some('=') => {
+                        self.advance();
+                        (
+                            TK::Operator,
+                            TV::Operator(AssignmentOperator::BitwiseAndEquals.into()),
+                        )
+                    }
                     _ => (
                         TK::Operator,
                         TV::Operator(BinaryOperator::BitwiseAnd.into()),
@@ -271,9 +440,36 @@ impl<'a> Lexer<'a> {
                         self.advance();
                         (TK::Operator, TV::Operator(LogicalOperator::Or.into()))
                     }
+                    Some('=') => {
+                        self.advance();
+                        (
+                            TK::Operator,
+                            TV::Operator(AssignmentOperator::BitwiseOrEquals.into()),
+                        )
+                    }
                     _ => (TK::Operator, TV::Operator(BinaryOperator::BitwiseOr.into())),
                 }
             }
+            '^' => {
+                self.advance();
+                match self.curr_char {
+                    Some('=') => {
+                        self.advance();
+                        (
+                            TK::Operator,
+                            TV::Operator(AssignmentOperator::BitwiseXorEquals.into()),
+                        )
+                    }
+                    _ => (
+                        TK::Operator,
+                        TV::Operator(BinaryOperator::BitwiseXor.into()),
+                    ),
+                }
+            }
+            '~' => {
+                self.advance();
+                (TK::Operator, TV::Operator(UnaryOperator::BitwiseNot.into()))
+            }
             '?' => {
                 self.advance();
                 match self.curr_char {
@@ -294,11 +490,20 @@ impl<'a> Lexer<'a> {
             }
             '{' => {
                 self.advance();
+                self.braces.push(false);
                 (TK::OpenBrace, TV::None)
             }
             '}' => {
                 self.advance();
-                (TK::CloseBrace, TV::None)
+                if self.braces.pop() == Some(true) {
+                    // The end of a template substitution
+                    match self.scan_template_part(start) {
+                        true => (TK::TemplateTail, TV::None),
+                        false => (TK::TemplateMiddle, TV::None),
+                    }
+                } else {
+                    (TK::CloseBrace, TV::None)
+                }
             }
             '[' => {
                 self.advance();
@@ -309,8 +514,17 @@ impl<'a> Lexer<'a> {
                 (TK::CloseBracket, TV::None)
             }
             '.' => {
-                self.advance();
-                (TK::Dot, TV::None)
+                if self.peek_char(0).is_some_and(|ch| ch.is_ascii_digit()) {
+                    self.parse_number()
+                } else if self.peek_char(0) == Some('.') && self.peek_char(1) == Some('.') {
+                    self.advance();
+                    self.advance();
+                    self.advance();
+                    (TK::Ellipsis, TV::None)
+                } else {
+                    self.advance();
+                    (TK::Dot, TV::None)
+                }
             }
             ',' => {
                 self.advance();
@@ -350,6 +564,8 @@ impl<'a> Lexer<'a> {
                     ),
                 }
             }
+            // `>>` and `>>>` are lexed as separate `>` tokens, and joined by the parser in expressions,
+            // since the `>>` of `Array<Array<T>>` closes two type argument lists
             '>' => {
                 self.advance();
                 match self.curr_char {
@@ -376,179 +592,611 @@ impl<'a> Lexer<'a> {
                             TV::Operator(BinaryOperator::LessOrEquals.into()),
                         )
                     }
+                    Some('<') => {
+                        self.advance();
+                        if self.curr_char == Some('=') {
+                            self.advance();
+                            (
+                                TK::Operator,
+                                TV::Operator(AssignmentOperator::LeftShiftEquals.into()),
+                            )
+                        } else {
+                            (TK::Operator, TV::Operator(BinaryOperator::LeftShift.into()))
+                        }
+                    }
                     _ => (TK::Operator, TV::Operator(BinaryOperator::LessThan.into())),
                 }
             }
-            _ => (TK::Invalid, TV::None),
+            _ => {
+                self.advance(); // Skip the unexpected character
+                self.error(LexErrorKind::UnexpectedCharacter, start);
+                (TK::Invalid, TV::None)
+            }
         };
 
-        if matches!(token_kind, TK::SingleLineComment | TK::MultiLineComment) {
-            return self.lex_next_token();
-        }
+        self.update_regex_allowed(&token_kind, &token_value);
 
         Token {
             kind: token_kind,
             value: token_value,
             start,
             end: self.position,
+            newline_before,
         }
     }
 
-    fn parse_identifier(&mut self) -> &str {
+    /// Parses an identifier or keyword, which can contain Unicode letters and `\u` escapes.
+    /// Returns its cooked name, and whether it contained any escapes.
+    fn parse_identifier(&mut self) -> (Cow<'a, str>, bool) {
+        let source = self.source;
         let start_pos = self.position;
+        // Only allocated once an escape is found
+        let mut cooked: Option<String> = None;
 
         loop {
             match self.curr_char {
-                Some('a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '$') => self.advance(),
+                Some('\\') => {
+                    let escape_start = self.position;
+                    let name =
+                        cooked.get_or_insert_with(|| source[start_pos..escape_start].to_owned());
+
+                    self.advance(); // Skip "\"
+                    let ch = if self.curr_char == Some('u') {
+                        self.advance(); // Skip "u"
+                        self.parse_unicode_escape().and_then(char::from_u32)
+                    } else {
+                        None
+                    };
+
+                    match ch {
+                        Some(ch) if escape_start == start_pos && is_identifier_start(ch) => {
+                            name.push(ch)
+                        }
+                        Some(ch) if escape_start != start_pos && is_identifier_part(ch) => {
+                            name.push(ch)
+                        }
+                        _ => self.error(LexErrorKind::InvalidIdentifierEscape, escape_start),
+                    }
+                }
+                Some(ch) if is_identifier_part(ch) => {
+                    if let Some(name) = cooked.as_mut() {
+                        name.push(ch);
+                    }
+                    self.advance();
+                }
                 _ => break,
             }
         }
 
-        &self.source[start_pos..self.position]
+        match cooked {
+            Some(name) => (Cow::Owned(name), true),
+            None => (Cow::Borrowed(&source[start_pos..self.position]), false),
+        }
     }
 
-    fn parse_number(&mut self) -> f64 {
+    /// Parses a numeric literal: decimal (`1_000.5e-3`, `.5`), hexadecimal, octal or binary (`0xFF`),
+    /// or a BigInt (`10n`). Malformed literals are reported, and still produce a best-effort value.
+    fn parse_number(&mut self) -> (TokenKind, TokenValue) {
         let start_pos = self.position;
-        let mut has_decimal = false;
 
-        loop {
-            match self.curr_char {
-                Some('0'..='9') => self.advance(),
-                Some('.') => {
-                    if has_decimal
-                        || self.peek_char(0).is_none()
-                        || self.peek_char(0).is_some_and(|ch| ch < '0' || ch > '9')
-                    {
-                        break;
-                    }
+        let radix = match (self.curr_char, self.peek_char(0)) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('o' | 'O')) => 8,
+            (Some('0'), Some('b' | 'B')) => 2,
+            _ => 10,
+        };
 
-                    has_decimal = true;
-                    self.advance();
-                    self.advance();
-                }
-                Some('n') => {
-                    self.advance();
-                    break;
-                }
-                _ => break,
+        let (digits_start, (digits_end, is_integer)) = if radix == 10 {
+            (start_pos, self.parse_decimal_digits())
+        } else {
+            self.advance(); // Skip "0"
+            self.advance(); // Skip radix prefix
+            let digits_start = self.position;
+            if !self.parse_digits(radix) {
+                self.error(LexErrorKind::MissingDigits, start_pos);
             }
+            (digits_start, (self.position, true))
+        };
+
+        let is_bigint = self.curr_char == Some('n');
+        if is_bigint {
+            self.advance(); // Skip "n" suffix
         }
 
-        self.source[start_pos..self.position]
-            .parse::<f64>()
-            .unwrap()
-    }
+        self.consume_number_suffix();
 
-    fn parse_string_literal(&mut self, strip_quotes: bool) -> &str {
-        if self.curr_char.unwrap() == '`' {
-            return self.parse_template_string();
+        // The digits of a BigInt are decoded on demand, by `Token::bigint_value`
+        if is_bigint {
+            if !is_integer {
+                self.error(LexErrorKind::InvalidBigInt, start_pos);
+            }
+            return (TokenKind::BigInt, TokenValue::None);
         }
 
-        let quote_type = self.curr_char.unwrap();
+        // Separators are rare, so the digits are only copied to remove them
+        let digits = &self.source[digits_start..digits_end];
+        let digits = match digits.contains('_') {
+            true => Cow::Owned(digits.replace('_', "")),
+            false => Cow::Borrowed(digits),
+        };
+
+        let value = if radix == 10 {
+            digits.parse::<f64>().unwrap_or(f64::NAN)
+        } else {
+            match u64::from_str_radix(&digits, radix) {
+                Ok(value) => value as f64,
+                Err(_) => digits
+                    .chars()
+                    .filter_map(|ch| ch.to_digit(radix))
+                    .fold(0.0, |acc, digit| acc * radix as f64 + digit as f64),
+            }
+        };
+
+        (TokenKind::Number, TokenValue::Number(value))
+    }
+
+    /// Parses the integer, fraction and exponent parts of a decimal literal.
+    /// Returns where its digits end, leaving out an exponent without digits, and whether it is an integer.
+    fn parse_decimal_digits(&mut self) -> (usize, bool) {
         let start_pos = self.position;
+        self.parse_digits(10);
+        let mut is_integer = true;
 
-        while let Some(ch) = self.curr_char {
-            if ch == '\\' {
-                self.advance(); // Skip \ character
-                self.advance(); // Skip escaped character
-                continue;
+        // Legacy octal literals like `017` are not supported
+        let integer = &self.source[start_pos..self.position];
+        if integer.len() > 1 && integer.starts_with('0') {
+            self.error(LexErrorKind::LeadingZero, start_pos);
+        }
+
+        if self.curr_char == Some('.') {
+            is_integer = false;
+            self.advance(); // Skip "."
+            self.parse_digits(10);
+        }
+
+        let mut digits_end = self.position;
+
+        if let Some('e' | 'E') = self.curr_char {
+            is_integer = false;
+            self.advance(); // Skip "e"
+
+            if let Some('+' | '-') = self.curr_char {
+                self.advance(); // Skip sign
+            }
+
+            if self.parse_digits(10) {
+                digits_end = self.position;
+            } else {
+                self.error(LexErrorKind::MissingDigits, start_pos);
             }
+        }
+
+        (digits_end, is_integer)
+    }
+
+    /// Parses a run of digits in the given radix, which may be separated by `_`.
+    /// Returns whether there were any digits.
+    fn parse_digits(&mut self, radix: u32) -> bool {
+        let mut has_digits = false;
 
-            if ch == quote_type && self.position != start_pos {
+        while let Some(ch) = self.curr_char {
+            if ch == '_' {
+                // A separator must be between two digits
+                let sep_start = self.position;
+                let next_is_digit = self.peek_char(0).is_some_and(|ch| ch.is_digit(radix));
+                self.advance(); // Skip "_"
+                if !has_digits || !next_is_digit {
+                    self.error(LexErrorKind::InvalidNumericSeparator, sep_start);
+                }
+            } else if ch.is_digit(radix) {
+                has_digits = true;
                 self.advance();
+            } else {
                 break;
             }
+        }
+
+        has_digits
+    }
+
+    /// A numeric literal can't be directly followed by an identifier or a digit, like `3in` or `0b12`.
+    /// The rest of the word is consumed as part of the literal, so that it's only reported once.
+    fn consume_number_suffix(&mut self) {
+        let suffix_start = self.position;
 
+        while self.curr_char.is_some_and(is_identifier_part) {
             self.advance();
         }
 
-        if strip_quotes {
-            &self.source[start_pos + 1..self.position - 1]
-        } else {
-            &self.source[start_pos..self.position]
+        if self.position > suffix_start {
+            self.error(LexErrorKind::InvalidNumberSuffix, suffix_start);
         }
     }
 
-    fn parse_template_string(&mut self) -> &str {
+    /// Records an error that spans from `start` to the current position
+    fn error(&mut self, kind: LexErrorKind, start: usize) {
+        self.errors.push(LexError::new(kind, start, self.position));
+    }
+
+    /// Parses a single or double quoted string, and writes its cooked value to `value`,
+    /// with every escape sequence decoded. The raw source text is the token's span.
+    fn parse_string_literal(&mut self, quote: char, value: &mut String) {
         let start_pos = self.position;
-        let mut brace_depth = 0;
+        value.clear();
 
-        self.advance(); // Skip initial ` character
+        self.advance(); // Skip opening quote
 
-        while let Some(ch) = self.curr_char {
-            if ch == '`' && brace_depth == 0 {
-                self.advance(); // Skip the closing ` character
-                break;
-            } else if ch == '\\' {
-                self.advance(); // Skip '\'
-                self.advance(); // Skip escaped character
-            } else if ch == '$' && self.peek_char(0) == Some('{') {
-                self.advance(); // Skip '&'
-                self.advance(); // Skip '{'
-                brace_depth += 1;
-            } else if ch == '}' && brace_depth > 0 {
-                self.advance(); // Skip '}'
-                brace_depth -= 1;
-            } else {
-                self.advance();
+        loop {
+            match self.curr_char {
+                Some(ch) if ch == quote => {
+                    self.advance(); // Skip closing quote
+                    break;
+                }
+                Some('\\') => {
+                    let escape_start = self.position;
+                    if let Err(kind) = self.parse_escape_sequence(value) {
+                        self.error(kind, escape_start);
+                    }
+                }
+                // Strings can contain U+2028 and U+2029, but no other line terminators
+                Some('\n' | '\r') | None => {
+                    self.error(LexErrorKind::UnterminatedString, start_pos);
+                    break;
+                }
+                Some(ch) => {
+                    value.push(ch);
+                    self.advance();
+                }
             }
         }
+    }
+
+    /// Parses an escape sequence that starts at the current `\`, and pushes the character it stands for to `out`.
+    /// Line continuations don't push anything.
+    fn parse_escape_sequence(&mut self, out: &mut String) -> Result<(), LexErrorKind> {
+        self.advance(); // Skip "\"
 
-        &self.source[start_pos..self.position]
+        let Some(ch) = self.curr_char else {
+            return Ok(());
+        };
+        self.advance(); // Skip escaped character
+
+        match ch {
+            'b' => out.push('\u{8}'),
+            'f' => out.push('\u{c}'),
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            'v' => out.push('\u{b}'),
+            '0' if !self.curr_char.is_some_and(|ch| ch.is_ascii_digit()) => out.push('\0'),
+            '0'..='9' => return Err(LexErrorKind::OctalEscape),
+            'x' => {
+                let code = self
+                    .parse_hex_digits(2)
+                    .ok_or(LexErrorKind::InvalidHexEscape)?;
+                out.extend(char::from_u32(code));
+            }
+            'u' => {
+                let mut code_point = self
+                    .parse_unicode_escape()
+                    .ok_or(LexErrorKind::InvalidUnicodeEscape)?;
+
+                // A surrogate pair written as two escapes, like `\uD83D\uDE00`
+                if (0xD800..0xDC00).contains(&code_point)
+                    && self.curr_char == Some('\\')
+                    && self.peek_char(0) == Some('u')
+                {
+                    if let Some(low) = self
+                        .peek_hex_digits(2, 4)
+                        .filter(|low| (0xDC00..0xE000).contains(low))
+                    {
+                        for _ in 0..6 {
+                            self.advance();
+                        }
+                        code_point = 0x10000 + ((code_point - 0xD800) << 10) + (low - 0xDC00);
+                    }
+                }
+
+                // Lone surrogates can't be represented in a Rust string
+                out.push(char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            // Line continuations
+            '\r' => {
+                if self.curr_char == Some('\n') {
+                    self.advance();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            _ => out.push(ch),
+        }
+
+        Ok(())
     }
 
-    fn maybe_consume_regex(&mut self, start_pos: usize) -> Option<TokenValue> {
-        let mut i = 0;
-        let mut is_escaped = false;
-        let pattern_end: usize;
+    /// Parses the code point of a `\u` escape, after the `\u`: either `XXXX`, or `{X...}` up to `{10FFFF}`.
+    fn parse_unicode_escape(&mut self) -> Option<u32> {
+        if self.curr_char != Some('{') {
+            return self.parse_hex_digits(4);
+        }
 
-        loop {
-            let Some(ch) = self.peek_char(i) else {
-                return None;
+        self.advance(); // Skip "{"
+
+        let mut code_point: u32 = 0;
+        let mut has_digits = false;
+        while let Some(digit) = self.curr_char.and_then(|ch| ch.to_digit(16)) {
+            code_point = code_point.saturating_mul(16).saturating_add(digit);
+            has_digits = true;
+            self.advance();
+        }
+
+        let is_closed = self.curr_char == Some('}');
+        if is_closed {
+            self.advance(); // Skip "}"
+        }
+
+        Some(code_point).filter(|&cp| has_digits && is_closed && cp <= 0x10FFFF)
+    }
+
+    /// Consumes exactly `count` hexadecimal digits, and returns their value.
+    /// Nothing is consumed if there aren't enough digits.
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let value = self.peek_hex_digits(0, count)?;
+        for _ in 0..count {
+            self.advance();
+        }
+        Some(value)
+    }
+
+    /// Reads `count` hexadecimal digits, starting `offset` characters after the current one, without consuming them
+    fn peek_hex_digits(&mut self, offset: usize, count: usize) -> Option<u32> {
+        (offset..offset + count).try_fold(0, |value, i| {
+            let ch = if i == 0 {
+                self.curr_char
+            } else {
+                self.peek_char(i - 1)
             };
+            Some(value * 16 + ch?.to_digit(16)?)
+        })
+    }
+
+    /// Parses the text of a template after its opening `` ` ``, or after the `}` of a substitution,
+    /// up to and including the closing `` ` `` or the next `${`.
+    /// Writes its cooked and raw text to `value`, and returns whether the template ends here.
+    fn parse_template_part(&mut self, start_pos: usize, value: &mut TemplateValue) -> bool {
+        let TemplateValue { cooked, raw } = value;
+        cooked.get_or_insert_with(String::new).clear();
+        raw.clear();
 
-            match ch {
-                '\n' => return None,
-                '\\' => {
-                    is_escaped = !is_escaped;
+        let is_tail = loop {
+            let next_char = self.peek_char(0);
+            match self.curr_char {
+                Some('`') => {
+                    self.advance(); // Skip "`"
+                    break true;
                 }
-                '/' if !is_escaped => {
-                    // Enf of regex pattern
-                    i += 1;
-                    pattern_end = start_pos + i + 1;
-                    break;
+                Some('$') if next_char == Some('{') => {
+                    self.advance(); // Skip "$"
+                    self.advance(); // Skip "{"
+                    self.braces.push(true);
+                    break false;
                 }
-                _ => {
-                    is_escaped = false;
+                Some('\\') => {
+                    let escape_start = self.position;
+                    let mut escaped = String::new();
+
+                    // Tagged templates can contain invalid escapes, which leave the cooked text undefined
+                    match self.parse_escape_sequence(&mut escaped) {
+                        Ok(()) => cooked
+                            .iter_mut()
+                            .for_each(|cooked| cooked.push_str(&escaped)),
+                        Err(_) => *cooked = None,
+                    }
+
+                    let escape = &self.source[escape_start..self.position];
+                    raw.push_str(&escape.replace("\r\n", "\n").replace('\r', "\n"));
+                }
+                // Line terminators are normalized to `\n`, in both the cooked and raw text
+                Some('\r') => {
+                    self.advance();
+                    if self.curr_char == Some('\n') {
+                        self.advance();
+                    }
+                    cooked.iter_mut().for_each(|cooked| cooked.push('\n'));
+                    raw.push('\n');
+                }
+                Some(ch) => {
+                    self.advance();
+                    cooked.iter_mut().for_each(|cooked| cooked.push(ch));
+                    raw.push(ch);
+                }
+                None => {
+                    self.error(LexErrorKind::UnterminatedTemplate, start_pos);
+                    break true;
                 }
             }
+        };
 
-            i += 1;
-        }
+        is_tail
+    }
+
+    /// Same as `parse_template_part`, for when only the errors in the text matter
+    fn scan_template_part(&mut self, start_pos: usize) -> bool {
+        let mut value = std::mem::take(&mut self.template_buffer);
+        let is_tail = self.parse_template_part(start_pos, &mut value);
+        self.template_buffer = value;
+        is_tail
+    }
+
+    /// Parses a regular expression literal, after its opening `/`.
+    /// A `/` inside a character class, like `[/]`, doesn't end the pattern.
+    /// Returns the pattern and the flags.
+    fn parse_regex(&mut self, start_pos: usize) -> (&'a str, &'a str) {
+        let source = self.source;
+        let pattern_start = self.position;
+        let mut in_class = false;
 
-        // We found a regex pattern
-        // Now we find the flags
-        let flags_start = start_pos + i + 2;
-        let mut flags_end: usize = flags_start;
-        while let Some(ch) = self.peek_char(i) {
-            match ch {
-                'd' | 'g' | 'i' | 'm' | 's' | 'u' | 'v' | 'y' => {
-                    i += 1;
-                    flags_end += 1;
+        loop {
+            match self.curr_char {
+                None | Some('\n' | '\r' | '\u{2028}' | '\u{2029}') => {
+                    self.error(LexErrorKind::UnterminatedRegex, start_pos);
+                    return (&source[pattern_start..self.position], "");
                 }
-                _ => break,
+                Some('\\') => {
+                    // An escaped line terminator still ends the line
+                    self.advance(); // Skip "\"
+                    if !matches!(
+                        self.curr_char,
+                        None | Some('\n' | '\r' | '\u{2028}' | '\u{2029}')
+                    ) {
+                        self.advance();
+                    }
+                }
+                Some('[') => {
+                    in_class = true;
+                    self.advance();
+                }
+                Some(']') => {
+                    in_class = false;
+                    self.advance();
+                }
+                Some('/') if !in_class => break,
+                Some(_) => self.advance(),
             }
         }
 
-        // Consume the whole char queue
-        self.position += i + 1;
-        self.curr_char = self.char_queue.pop_back();
-        self.char_queue.clear();
+        let pattern = &source[pattern_start..self.position];
+        self.advance(); // Skip closing "/"
+
+        let flags_start = self.position;
+        while self.curr_char.is_some_and(is_identifier_part) {
+            self.advance();
+        }
+        let flags = &source[flags_start..self.position];
 
-        Some(TokenValue::Regex(RegexValue {
-            pattern: self.source[start_pos + 1..pattern_end].to_owned(),
-            flags: self.source[flags_start..flags_end].to_owned(),
-        }))
+        let is_valid_flag =
+            |(i, flag): (usize, char)| "dgimsuvy".contains(flag) && !flags[..i].contains(flag);
+        if !flags.char_indices().all(is_valid_flag) {
+            self.error(LexErrorKind::InvalidRegexFlags, flags_start);
+        }
+
+        (pattern, flags)
+    }
+
+    /// A regex can only start where an expression can, so a `/` after an operand is a division
+    fn update_regex_allowed(&mut self, kind: &TokenKind, value: &TokenValue) {
+        use TokenKind as TK;
+
+        self.regex_allowed = match kind {
+            TK::OpenParen => {
+                let is_condition = matches!(
+                    self.last_keyword,
+                    Some(Keyword::If | Keyword::For | Keyword::While)
+                );
+                self.parens.push(is_condition);
+                true
+            }
+            // Like in `if (a) /b/.test(c)`
+            TK::CloseParen => self.parens.pop().unwrap_or(false),
+            // A property name, like `a.default / 2`
+            TK::Keyword if self.after_dot => false,
+            TK::Keyword => match value {
+                TokenValue::Keyword(Keyword::This | Keyword::Super) => false,
+                // Most likely used as an identifier, like in `let type = 4; type / 2`
+                TokenValue::Keyword(keyword) => !keyword.is_contextual(),
+                _ => true,
+            },
+            TK::Operator => !matches!(value, TokenValue::Operator(Operator::Update(_))),
+            TK::Identifier
+            | TK::PrivateName
+            | TK::Number
+            | TK::BigInt
+            | TK::String
+            | TK::Boolean
+            | TK::Null
+            | TK::RegexLiteral
+            | TK::NoSubstitutionTemplate
+            | TK::TemplateTail
+            | TK::CloseBracket => false,
+            _ => true,
+        };
+
+        self.last_keyword = match value {
+            TokenValue::Keyword(keyword) if !self.after_dot => Some(*keyword),
+            _ => None,
+        };
+        self.after_dot = *kind == TK::Dot;
+        self.after_close_brace = *kind == TK::CloseBrace;
+    }
+}
+
+/// The values that aren't stored in tokens, decoded on demand from the source code they were lexed from.
+/// Malformed tokens still produce a best-effort value, since their errors were reported while lexing.
+impl Token {
+    /// The source code of the token
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        source.get(self.start..self.end).unwrap_or_default()
+    }
+
+    /// The name of an identifier, with any `\u` escapes decoded.
+    /// The name of a private name doesn't include its `#`.
+    pub fn identifier_name<'a>(&self, source: &'a str) -> Cow<'a, str> {
+        let text = self.text(source);
+        let text = match self.kind {
+            TokenKind::PrivateName => text.strip_prefix('#').unwrap_or(text),
+            _ => text,
+        };
+        if !text.contains('\\') {
+            return Cow::Borrowed(text);
+        }
+
+        Lexer::new(text).parse_identifier().0
+    }
+
+    /// The cooked value of a string literal, with every escape sequence decoded
+    pub fn string_value<'a>(&self, source: &'a str) -> Cow<'a, str> {
+        let text = self.text(source);
+        let Some(quote) = text.chars().next() else {
+            return Cow::Borrowed("");
+        };
+
+        // Without escapes, the value is the text between the quotes
+        if !text.contains('\\') {
+            let value = &text[1..];
+            return Cow::Borrowed(value.strip_suffix(quote).unwrap_or(value));
+        }
+
+        let mut value = String::new();
+        Lexer::new(text).parse_string_literal(quote, &mut value);
+        Cow::Owned(value)
+    }
+
+    /// The cooked and raw text of a template token, between its delimiters
+    pub fn template_value(&self, source: &str) -> TemplateValue {
+        let mut lexer = Lexer::new(self.text(source));
+        lexer.advance(); // Skip "`" or "}"
+
+        let mut value = TemplateValue::default();
+        lexer.parse_template_part(0, &mut value);
+        value
+    }
+
+    /// The pattern and flags of a regex literal
+    pub fn regex_value(&self, source: &str) -> RegexValue {
+        let mut lexer = Lexer::new(self.text(source));
+        lexer.advance(); // Skip "/"
+
+        let (pattern, flags) = lexer.parse_regex(0);
+        RegexValue {
+            pattern: pattern.to_owned(),
+            flags: flags.to_owned(),
+        }
+    }
+
+    /// The digits of a BigInt literal, without separators and the `n` suffix, like `0xFF` for `0xF_Fn`
+    pub fn bigint_value(&self, source: &str) -> String {
+        let text = self.text(source);
+        let digits = &text[..text.find('n').unwrap_or(text.len())];
+        digits.replace('_', "")
     }
 }
 
@@ -564,3 +1212,24 @@ impl<'a> Iterator for Lexer<'a> {
         }
     }
 }
+
+/// LF, CR, and the Unicode line and paragraph separators
+fn is_line_terminator(ch: char) -> bool {
+    matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// `ID_Start`, plus `$` and `_`
+fn is_identifier_start(ch: char) -> bool {
+    match ch {
+        'a'..='z' | 'A'..='Z' | '$' | '_' => true,
+        _ => !ch.is_ascii() && unicode_ident::is_xid_start(ch),
+    }
+}
+
+/// `ID_Continue`, plus `$`, and the zero width joiners (ZWNJ and ZWJ)
+fn is_identifier_part(ch: char) -> bool {
+    match ch {
+        'a'..='z' | 'A'..='Z' | '0'..='9' | '$' | '_' | '\u{200C}' | '\u{200D}' => true,
+        _ => !ch.is_ascii() && unicode_ident::is_xid_continue(ch),
+    }
+}
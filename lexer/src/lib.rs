@@ -1,9 +1,15 @@
+mod comment;
+mod error;
 mod keywords;
 mod lexer;
 mod operators;
+mod source_file;
 mod token;
 
+pub use comment::{Comment, CommentKind};
+pub use error::{LexError, LexErrorKind};
 pub use keywords::{Keyword, TypeKeyword};
-pub use lexer::Lexer;
+pub use lexer::{Lexer, LexerCheckpoint};
 pub use operators::*;
-pub use token::{RegexValue, Token, TokenKind, TokenValue};
+pub use source_file::{LineCol, LineIndex, SourceFile};
+pub use token::{RegexValue, TemplateValue, Token, TokenKind, TokenValue};
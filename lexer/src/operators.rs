@@ -90,7 +90,7 @@ pub enum AssignmentOperator {
     PowerEquals, // **=
 
     LeftShiftEquals,          // <<=
-    RightShiftEquals,         // <<=
+    RightShiftEquals,         // >>=
     ZeroFillRightShiftEquals, // >>>=
 
     BitwiseOrEquals,  // |=
@@ -240,41 +240,128 @@ impl Operator {
     }
 }
 
-// Optional Display impl: useful for debug / pretty printing
-impl std::fmt::Display for Operator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// Implements `as_str`, returning the operator as it is written in the source code,
+/// and serializes the operator as that string (as ESTree does)
+macro_rules! impl_as_str {
+    ($name:ident { $($variant:ident => $str:literal,)* }) => {
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $str,)*
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+    };
+}
+
+impl_as_str!(UnaryOperator {
+    LogicalNot => "!",
+    BitwiseNot => "~",
+    Plus => "+",
+    Minus => "-",
+    Typeof => "typeof",
+    Void => "void",
+    Delete => "delete",
+});
+
+impl_as_str!(UpdateOperator {
+    Increment => "++",
+    Decrement => "--",
+});
+
+impl_as_str!(BinaryOperator {
+    Equals => "==",
+    NotEquals => "!=",
+    StrictEquals => "===",
+    StrictNotEquals => "!==",
+    LessThan => "<",
+    LessOrEquals => "<=",
+    GreaterThan => ">",
+    GreaterOrEquals => ">=",
+    LeftShift => "<<",
+    RightShift => ">>",
+    ZeroFillRightShift => ">>>",
+    Plus => "+",
+    Minus => "-",
+    Mult => "*",
+    Div => "/",
+    Power => "**",
+    Mod => "%",
+    BitwiseOr => "|",
+    BitwiseXor => "^",
+    BitwiseAnd => "&",
+    In => "in",
+    Instanceof => "instanceof",
+});
+
+impl_as_str!(AssignmentOperator {
+    Assign => "=",
+    PlusEquals => "+=",
+    MinusEquals => "-=",
+    TimesEquals => "*=",
+    DivEquals => "/=",
+    ModEquals => "%=",
+    PowerEquals => "**=",
+    LeftShiftEquals => "<<=",
+    RightShiftEquals => ">>=",
+    ZeroFillRightShiftEquals => ">>>=",
+    BitwiseOrEquals => "|=",
+    BitwiseXorEquals => "^=",
+    BitwiseAndEquals => "&=",
+});
+
+impl_as_str!(LogicalOperator {
+    Or => "||",
+    And => "&&",
+    Nullish => "??",
+});
+
+impl Operator {
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Operator::Binary(b) => write!(f, "{:?}", b),
-            Operator::Unary(u) => write!(f, "{:?}", u),
-            Operator::Update(u) => write!(f, "{:?}", u),
-            Operator::Assignment(a) => write!(f, "{:?}", a),
-            Operator::Logical(l) => write!(f, "{:?}", l),
+            Operator::Binary(b) => b.as_str(),
+            Operator::Unary(u) => u.as_str(),
+            Operator::Update(u) => u.as_str(),
+            Operator::Assignment(a) => a.as_str(),
+            Operator::Logical(l) => l.as_str(),
         }
     }
 }
 
-impl Into<Operator> for UnaryOperator {
-    fn into(self) -> Operator {
-        Operator::Unary(self)
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<UnaryOperator> for Operator {
+    fn from(val: UnaryOperator) -> Self {
+        Operator::Unary(val)
     }
 }
-impl Into<Operator> for UpdateOperator {
-    fn into(self) -> Operator {
-        Operator::Update(self)
+impl From<UpdateOperator> for Operator {
+    fn from(val: UpdateOperator) -> Self {
+        Operator::Update(val)
     }
 }
-impl Into<Operator> for BinaryOperator {
-    fn into(self) -> Operator {
-        Operator::Binary(self)
+impl From<BinaryOperator> for Operator {
+    fn from(val: BinaryOperator) -> Self {
+        Operator::Binary(val)
     }
 }
-impl Into<Operator> for AssignmentOperator {
-    fn into(self) -> Operator {
-        Operator::Assignment(self)
+impl From<AssignmentOperator> for Operator {
+    fn from(val: AssignmentOperator) -> Self {
+        Operator::Assignment(val)
     }
 }
-impl Into<Operator> for LogicalOperator {
-    fn into(self) -> Operator {
-        Operator::Logical(self)
+impl From<LogicalOperator> for Operator {
+    fn from(val: LogicalOperator) -> Self {
+        Operator::Logical(val)
     }
 }
@@ -0,0 +1,181 @@
+/// A position in the source code, as used by editors and the language server protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineCol {
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column, counted in UTF-16 code units
+    pub column: u32,
+}
+
+/// A character on a line that is not encoded as a single byte
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct WideChar {
+    /// Byte offset from the start of the line
+    start: u32,
+    utf8_len: u8,
+    utf16_len: u8,
+}
+
+/// Maps byte offsets to lines and columns, and back.
+/// Lines are terminated by `\n`, `\r\n` or a lone `\r`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineIndex {
+    /// The byte offset of the first character of each line
+    line_starts: Vec<u32>,
+    /// The offset of the first character of each line, in UTF-16 code units
+    utf16_line_starts: Vec<u32>,
+    /// The multi-byte characters of each line, in order
+    wide_chars: Vec<Vec<WideChar>>,
+    len: u32,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut utf16_line_starts = vec![0];
+        let mut utf16_offset = 0;
+        let mut wide_chars = vec![Vec::new()];
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let line_start = *line_starts.last().unwrap();
+            utf16_offset += c.len_utf16() as u32;
+
+            match c {
+                '\r' if chars.peek().is_some_and(|(_, next)| *next == '\n') => {
+                    chars.next();
+                    utf16_offset += 1;
+                    line_starts.push(i as u32 + 2);
+                    utf16_line_starts.push(utf16_offset);
+                    wide_chars.push(Vec::new());
+                }
+                '\r' | '\n' => {
+                    line_starts.push(i as u32 + 1);
+                    utf16_line_starts.push(utf16_offset);
+                    wide_chars.push(Vec::new());
+                }
+                _ if !c.is_ascii() => wide_chars.last_mut().unwrap().push(WideChar {
+                    start: i as u32 - line_start,
+                    utf8_len: c.len_utf8() as u8,
+                    utf16_len: c.len_utf16() as u8,
+                }),
+                _ => {}
+            }
+        }
+
+        Self {
+            line_starts,
+            utf16_line_starts,
+            wide_chars,
+            len: text.len() as u32,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Converts a byte offset to a line and column.
+    /// Offsets past the end of the text are clamped to the end,
+    /// and offsets inside a multi-byte character point at its start.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let offset = (offset as u32).min(self.len);
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let byte_col = offset - self.line_starts[line];
+        let mut column = byte_col;
+
+        for wide in self.wide_chars[line].iter() {
+            if wide.start >= byte_col {
+                break;
+            }
+            if wide.start + wide.utf8_len as u32 > byte_col {
+                // The offset is in the middle of the character
+                column -= byte_col - wide.start;
+                break;
+            }
+            column -= wide.utf8_len as u32 - wide.utf16_len as u32;
+        }
+
+        LineCol {
+            line: line as u32 + 1,
+            column: column + 1,
+        }
+    }
+
+    /// Converts a byte offset to an offset in UTF-16 code units, as used by JavaScript tools.
+    /// Offsets are clamped in the same way as in `line_col`.
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        let pos = self.line_col(offset);
+        (self.utf16_line_starts[pos.line as usize - 1] + pos.column - 1) as usize
+    }
+
+    /// Converts a line and column back to a byte offset.
+    /// Returns `None` if the position is outside of the text or inside a surrogate pair.
+    pub fn offset(&self, pos: LineCol) -> Option<usize> {
+        let line = (pos.line as usize).checked_sub(1)?;
+        let line_start = *self.line_starts.get(line)?;
+        // The start of the next line, or one past the end of the text
+        let line_end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.len + 1);
+        let mut remaining = pos.column.checked_sub(1)?;
+        let mut byte_col = 0;
+
+        for wide in self.wide_chars[line].iter() {
+            let ascii_len = wide.start - byte_col;
+            if remaining <= ascii_len {
+                break;
+            }
+            remaining -= ascii_len;
+            if remaining < wide.utf16_len as u32 {
+                return None;
+            }
+            remaining -= wide.utf16_len as u32;
+            byte_col = wide.start + wide.utf8_len as u32;
+        }
+
+        let offset = line_start + byte_col + remaining;
+        (offset < line_end).then_some(offset as usize)
+    }
+}
+
+/// The text of a source file, together with its line index
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub name: String,
+    text: String,
+    line_index: LineIndex,
+}
+
+impl SourceFile {
+    pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self {
+            name: name.into(),
+            line_index: LineIndex::new(&text),
+            text,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn line_index(&self) -> &LineIndex {
+        &self.line_index
+    }
+
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        self.line_index.line_col(offset)
+    }
+
+    pub fn offset(&self, pos: LineCol) -> Option<usize> {
+        self.line_index.offset(pos)
+    }
+
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        self.line_index.utf16_offset(offset)
+    }
+}
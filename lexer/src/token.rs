@@ -1,14 +1,17 @@
 use super::keywords::Keyword;
 use crate::operators::Operator;
 
-use string_cache::DefaultAtom as Atom;
-
-#[derive(Debug, PartialEq, Clone, Default)]
+/// A token is only a span of the source code, together with the values that are cheap to copy.
+/// Strings, identifiers, templates, regexes and BigInts are decoded from the source code on demand.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub struct Token {
     pub kind: TokenKind,
     pub value: TokenValue,
     pub start: usize,
     pub end: usize,
+    /// Whether there's a line terminator between the previous token and this one,
+    /// which matters for automatic semicolon insertion
+    pub newline_before: bool,
 }
 
 impl Token {
@@ -22,6 +25,7 @@ impl Token {
             value: TokenValue::None,
             start: 0,
             end: 0,
+            newline_before: false,
         }
     }
 
@@ -38,118 +42,86 @@ impl Token {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum TokenValue {
     #[default]
     None,
-    Consumed,
     Number(f64),
-    String(String),
     Boolean(bool),
     Keyword(Keyword),
-    Identifier(Atom),
-    Regex(RegexValue),
     Operator(Operator),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub struct RegexValue {
     pub pattern: String,
     pub flags: String,
 }
 
-impl TokenValue {
-    pub fn expect_none(&self) {
-        match self {
-            TokenValue::None => {}
-            _ => unreachable!("Expected a None value"),
-        }
-    }
-
-    pub fn expect_number(&self) -> f64 {
-        match self {
-            TokenValue::Number(num) => *num,
-            _ => unreachable!("Expected a Number token"),
-        }
-    }
-
-    pub fn expect_boolean(&self) -> bool {
-        match self {
-            TokenValue::Boolean(b) => *b,
-            _ => unreachable!("Expected a Boolean token"),
-        }
-    }
-
-    pub fn expect_string(&self) -> &str {
-        match self {
-            TokenValue::String(s) => &s,
-            _ => unreachable!("Expected a String token"),
-        }
-    }
-
-    /// Consumes the token's string value
-    pub fn consume_string(&mut self) -> String {
-        match std::mem::replace(self, Self::Consumed) {
-            Self::String(s) => s,
-            _ => unreachable!("Expected a String token"),
-        }
-    }
+/// The text of a template between its delimiters, like `a` and `b` in `` `a${x}b` ``
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize)]
+pub struct TemplateValue {
+    /// The text with escape sequences decoded, or `None` if it contains an invalid escape,
+    /// which is only allowed in tagged templates
+    pub cooked: Option<String>,
+    pub raw: String,
+}
 
-    pub fn expect_keyword(&self) -> Keyword {
+/// Accessors for the value of a token of a known kind.
+/// They return `None` when the value doesn't match, instead of panicking.
+impl TokenValue {
+    pub fn as_number(&self) -> Option<f64> {
         match self {
-            TokenValue::Keyword(kw) => *kw,
-            _ => unreachable!("Expected a Keyword token"),
+            Self::Number(num) => Some(*num),
+            _ => None,
         }
     }
 
-    pub fn expect_identifier(&self) -> &Atom {
+    pub fn as_boolean(&self) -> Option<bool> {
         match self {
-            TokenValue::Identifier(atom) => atom,
-            _ => unreachable!("Expected an Identifier token"),
+            Self::Boolean(b) => Some(*b),
+            _ => None,
         }
     }
 
-    pub fn expect_regex(&self) -> &RegexValue {
+    pub fn as_keyword(&self) -> Option<Keyword> {
         match self {
-            TokenValue::Regex(r) => &r,
-            _ => unreachable!("Expected a Regex token"),
-        }
-    }
-
-    pub fn consume_regex(&mut self) -> RegexValue {
-        match std::mem::replace(self, Self::Consumed) {
-            TokenValue::Regex(r) => r,
-            _ => unreachable!("Expected a Regex token"),
+            Self::Keyword(kw) => Some(*kw),
+            _ => None,
         }
     }
 
-    pub fn expect_operator(&self) -> Operator {
+    pub fn as_operator(&self) -> Option<Operator> {
         match self {
-            Self::Operator(o) => o.to_owned(),
-            _ => unreachable!("Expected an Operator token"),
+            Self::Operator(o) => Some(*o),
+            _ => None,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub enum TokenKind {
     // Special tokens
     #[default]
     Invalid,
     Consumed,
     Eof,
-    MultiLineComment,
-    SingleLineComment,
 
     // Identifiers and literals
     Keyword,
     Identifier,
+    PrivateName, // #name
     String,
     Number,
+    BigInt,
     Boolean,
     Null,
     RegexLiteral,
     Operator,
+    NoSubstitutionTemplate, // `abc`
+    TemplateHead,           // `abc${
+    TemplateMiddle,         // }abc${
+    TemplateTail,           // }abc`
 
     // Punctuation
     OpenParen,    // (
@@ -159,6 +131,7 @@ pub enum TokenKind {
     OpenBracket,  // [
     CloseBracket, // ]
     Dot,          // .
+    Ellipsis,     // ...
     Comma,        // ,
     Colon,        // :
     SemiColon,    // ;
@@ -167,3 +140,39 @@ pub enum TokenKind {
     // Other
     ArrowFn,
 }
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out: &'static str = match self {
+            Self::Invalid => "an invalid token",
+            Self::Consumed => "a consumed token",
+            Self::Eof => "end of file",
+            Self::Keyword => "a keyword",
+            Self::Identifier => "an identifier",
+            Self::PrivateName => "a private name",
+            Self::String => "a string",
+            Self::Number => "a number",
+            Self::BigInt => "a BigInt",
+            Self::Boolean => "a boolean",
+            Self::Null => "`null`",
+            Self::RegexLiteral => "a regular expression",
+            Self::Operator => "an operator",
+            Self::NoSubstitutionTemplate | Self::TemplateHead => "a template literal",
+            Self::TemplateMiddle | Self::TemplateTail => "`}`",
+            Self::OpenParen => "`(`",
+            Self::CloseParen => "`)`",
+            Self::OpenBrace => "`{`",
+            Self::CloseBrace => "`}`",
+            Self::OpenBracket => "`[`",
+            Self::CloseBracket => "`]`",
+            Self::Dot => "`.`",
+            Self::Ellipsis => "`...`",
+            Self::Comma => "`,`",
+            Self::Colon => "`:`",
+            Self::SemiColon => "`;`",
+            Self::QuestionMark => "`?`",
+            Self::ArrowFn => "`=>`",
+        };
+        write!(f, "{}", out)
+    }
+}
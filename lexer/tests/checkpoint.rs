@@ -0,0 +1,66 @@
+use lexer::{LexErrorKind, Lexer, TokenKind};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn rewind_relexes_the_same_tokens() {
+    let source_code = "a /* b */ (c) / 2; /d/g";
+    let mut lexer = Lexer::new(source_code);
+    lexer.next_token();
+
+    let checkpoint = lexer.checkpoint();
+    let tokens = lexer.by_ref().collect::<Vec<_>>();
+    assert_eq!(tokens.len(), 7);
+
+    lexer.rewind(checkpoint);
+    assert_eq!(lexer.by_ref().collect::<Vec<_>>(), tokens);
+    assert_eq!(lexer.take_comments().len(), 1);
+}
+
+#[test]
+fn rewind_forgets_errors_and_comments() {
+    let mut lexer = Lexer::new("a // b\n0x c");
+    lexer.next_token();
+
+    let checkpoint = lexer.checkpoint();
+    lexer.next_token();
+    lexer.next_token();
+    assert_eq!(lexer.errors().len(), 1);
+
+    lexer.rewind(checkpoint);
+    assert_eq!(lexer.errors(), []);
+    assert_eq!(lexer.take_comments(), []);
+
+    assert_eq!(lexer.next_token().kind, TokenKind::Number);
+    assert_eq!(lexer.errors()[0].kind, LexErrorKind::MissingDigits);
+}
+
+#[test]
+fn rewind_restores_peeked_tokens() {
+    let mut lexer = Lexer::new("a b c");
+    let a = lexer.next_token();
+    let b = *lexer.peek_token();
+
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(lexer.next_token(), b);
+    lexer.next_token();
+
+    lexer.rewind(checkpoint);
+    assert_eq!(lexer.next_token(), b);
+    assert_eq!(lexer.next_token().start, 4);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn rewind_restores_template_state() {
+    let source_code = "`a${b}c`";
+    let mut lexer = Lexer::new(source_code);
+    assert_eq!(lexer.next_token().kind, TokenKind::TemplateHead);
+
+    let checkpoint = lexer.checkpoint();
+    lexer.next_token();
+    assert_eq!(lexer.next_token().kind, TokenKind::TemplateTail);
+
+    lexer.rewind(checkpoint);
+    lexer.next_token();
+    assert_eq!(lexer.next_token().kind, TokenKind::TemplateTail);
+}
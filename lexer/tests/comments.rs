@@ -0,0 +1,80 @@
+use lexer::{Comment, CommentKind, LexErrorKind, Lexer, TokenKind};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn collects_comments() {
+    let source_code = "// line\na /* block */ b /**/\n/* multi\n line */";
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer.by_ref().map(|tok| tok.kind).collect::<Vec<_>>();
+
+    assert_eq!(tokens, [TokenKind::Identifier, TokenKind::Identifier]);
+    assert_eq!(
+        lexer.take_comments(),
+        [
+            Comment {
+                kind: CommentKind::Line,
+                text: " line".into(),
+                start: 0,
+                end: 7,
+            },
+            Comment {
+                kind: CommentKind::Block,
+                text: " block ".into(),
+                start: 10,
+                end: 21,
+            },
+            Comment {
+                kind: CommentKind::Block,
+                text: "".into(),
+                start: 24,
+                end: 28,
+            },
+            Comment {
+                kind: CommentKind::Block,
+                text: " multi\n line ".into(),
+                start: 29,
+                end: 46,
+            },
+        ]
+    );
+    assert_eq!(lexer.take_errors(), []);
+}
+
+#[test]
+fn comment_delimiters_inside_comments() {
+    let mut lexer = Lexer::new("/*/ a */ b // c /* d\ne");
+    let tokens = lexer.by_ref().map(|tok| tok.start).collect::<Vec<_>>();
+
+    assert_eq!(tokens, [9, 21]);
+    assert_eq!(lexer.take_comments().len(), 2);
+}
+
+#[test]
+fn unterminated_comment() {
+    let mut lexer = Lexer::new("a /* b");
+    assert_eq!(lexer.by_ref().count(), 1);
+
+    let errors = lexer.take_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        (errors[0].kind, errors[0].start, errors[0].end),
+        (LexErrorKind::UnterminatedComment, 2, 6)
+    );
+    assert_eq!(lexer.take_comments()[0].text, " b");
+}
+
+#[test]
+fn newline_before() {
+    let lexer = Lexer::new("a b\nc /* \n */ d /* */ e // f\ng");
+    let newlines = lexer.map(|tok| tok.newline_before).collect::<Vec<_>>();
+
+    assert_eq!(newlines, [false, false, true, true, false, true]);
+}
+
+#[test]
+fn newline_before_eof() {
+    let mut lexer = Lexer::new("a\n");
+    lexer.next_token();
+
+    assert!(lexer.next_token().newline_before);
+}
@@ -0,0 +1,112 @@
+use lexer::{LexError, LexErrorKind, Lexer, TokenKind};
+use pretty_assertions::assert_eq;
+
+fn lex(source_code: &str) -> (Vec<(TokenKind, String)>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| (tok.kind, tok.identifier_name(source_code).into_owned()))
+        .collect::<Vec<_>>();
+
+    (tokens, lexer.take_errors())
+}
+
+fn expect_identifier(source_code: &str, expected: &str) {
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens, [(TokenKind::Identifier, expected.into())]);
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn ascii() {
+    expect_identifier("foo_bar$1", "foo_bar$1");
+    expect_identifier("_", "_");
+    expect_identifier("$", "$");
+}
+
+#[test]
+fn unicode() {
+    expect_identifier("café", "café");
+    expect_identifier("π", "π");
+    expect_identifier("変数", "変数");
+    expect_identifier("ᾩ", "ᾩ");
+    // Combining marks and digits can only continue an identifier
+    expect_identifier("a\u{0301}١", "a\u{0301}١");
+}
+
+#[test]
+fn zero_width_joiners() {
+    expect_identifier("a\u{200C}b\u{200D}c", "a\u{200C}b\u{200D}c");
+}
+
+#[test]
+fn unicode_escapes() {
+    expect_identifier(r"\u0061bc", "abc");
+    expect_identifier(r"a\u{62}c", "abc");
+    expect_identifier(r"\u{3C0}", "π");
+}
+
+#[test]
+fn escaped_keywords_are_identifiers() {
+    let (tokens, _) = lex(r"if \u0069f");
+
+    assert_eq!(
+        tokens,
+        [
+            (TokenKind::Keyword, "if".into()),
+            (TokenKind::Identifier, "if".into()),
+        ]
+    );
+}
+
+#[test]
+fn invalid_escapes() {
+    let errors = |source_code| {
+        lex(source_code)
+            .1
+            .into_iter()
+            .map(|err| (err.kind, err.start, err.end))
+            .collect::<Vec<_>>()
+    };
+
+    // Escapes must stand for an identifier character
+    assert_eq!(
+        errors(r"a\u002Db"),
+        [(LexErrorKind::InvalidIdentifierEscape, 1, 7)]
+    );
+    assert_eq!(
+        errors(r"\u0031a"),
+        [(LexErrorKind::InvalidIdentifierEscape, 0, 6)]
+    );
+    assert_eq!(
+        errors(r"a\x41"),
+        [(LexErrorKind::InvalidIdentifierEscape, 1, 2)]
+    );
+    assert_eq!(
+        errors(r"a\u{zz}"),
+        [(LexErrorKind::InvalidIdentifierEscape, 1, 4)]
+    );
+}
+
+#[test]
+fn private_names() {
+    let (tokens, errors) = lex(r"#count this.#café #\u0061b");
+
+    assert_eq!(
+        tokens,
+        [
+            (TokenKind::PrivateName, "count".into()),
+            (TokenKind::Keyword, "this".into()),
+            (TokenKind::Dot, ".".into()),
+            (TokenKind::PrivateName, "café".into()),
+            (TokenKind::PrivateName, "ab".into()),
+        ]
+    );
+    assert_eq!(errors, []);
+
+    // A `#` must be followed by a name
+    let (tokens, errors) = lex("# a");
+    assert_eq!(tokens[0].0, TokenKind::Invalid);
+    assert_eq!(errors[0].kind, LexErrorKind::UnexpectedCharacter);
+}
@@ -1,9 +1,48 @@
-use lexer::{AssignmentOperator, BinaryOperator, Keyword, Lexer, TokenKind, TokenValue};
+use lexer::{
+    AssignmentOperator, BinaryOperator, Keyword, Lexer, Operator, TemplateValue, Token, TokenKind,
+    TokenValue, UnaryOperator,
+};
 use pretty_assertions::assert_eq;
 
-fn expect_tokens(source_code: &str, expected_tokens: &[(TokenKind, TokenValue)]) {
+/// The value of a token, including the values that are decoded from the source code on demand
+#[derive(Debug, PartialEq)]
+enum Value {
+    None,
+    Number(f64),
+    Boolean(bool),
+    Keyword(Keyword),
+    Operator(Operator),
+    Identifier(String),
+    String(String),
+    Template(TemplateValue),
+}
+
+impl Value {
+    fn of(tok: &Token, source_code: &str) -> Self {
+        match (tok.kind, tok.value) {
+            (TokenKind::Identifier, _) => Self::Identifier(tok.identifier_name(source_code).into()),
+            (TokenKind::String, _) => Self::String(tok.string_value(source_code).into()),
+            (
+                TokenKind::NoSubstitutionTemplate
+                | TokenKind::TemplateHead
+                | TokenKind::TemplateMiddle
+                | TokenKind::TemplateTail,
+                _,
+            ) => Self::Template(tok.template_value(source_code)),
+            (_, TokenValue::None) => Self::None,
+            (_, TokenValue::Number(num)) => Self::Number(num),
+            (_, TokenValue::Boolean(b)) => Self::Boolean(b),
+            (_, TokenValue::Keyword(kw)) => Self::Keyword(kw),
+            (_, TokenValue::Operator(op)) => Self::Operator(op),
+        }
+    }
+}
+
+fn expect_tokens(source_code: &str, expected_tokens: &[(TokenKind, Value)]) {
     let lexer = Lexer::new(source_code);
-    let tokens = lexer.map(|tok| (tok.kind, tok.value)).collect::<Vec<_>>();
+    let tokens = lexer
+        .map(|tok| (tok.kind, Value::of(&tok, source_code)))
+        .collect::<Vec<_>>();
 
     assert_eq!(tokens, expected_tokens);
 }
@@ -11,17 +50,17 @@ fn expect_tokens(source_code: &str, expected_tokens: &[(TokenKind, TokenValue)])
 #[test]
 fn empty() {
     let source_code = " ";
-    expect_tokens(&source_code, &vec![]);
+    expect_tokens(source_code, &[]);
 }
 
 #[test]
 fn numbers() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "8 + 5 - 2 / 2";
     expect_tokens(
-        &source_code,
-        &vec![
+        source_code,
+        &[
             (TK::Number, TV::Number(8.0)),
             (TK::Operator, TV::Operator(BinaryOperator::Plus.into())),
             (TK::Number, TV::Number(5.0)),
@@ -36,11 +75,11 @@ fn numbers() {
 #[test]
 fn let_statement() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "let x = 123.0 + 456.0;";
     expect_tokens(
-        &source_code,
-        &vec![
+        source_code,
+        &[
             (TK::Keyword, TV::Keyword(Keyword::Let)),
             (TK::Identifier, TV::Identifier("x".into())),
             (
@@ -58,10 +97,10 @@ fn let_statement() {
 #[test]
 fn function() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "function sum(n1: number, n2: number): number {}";
     expect_tokens(
-        &source_code,
+        source_code,
         &vec![
             (TK::Keyword, TV::Keyword(Keyword::Function)),
             (TK::Identifier, TV::Identifier("sum".into())),
@@ -85,45 +124,56 @@ fn function() {
 #[test]
 fn string_literal() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "let x = 'This is a string literal';";
     expect_tokens(
-        &source_code,
-        &vec![
+        source_code,
+        &[
             (TK::Keyword, TV::Keyword(Keyword::Let)),
             (TK::Identifier, TV::Identifier("x".into())),
             (
                 TK::Operator,
                 TV::Operator(AssignmentOperator::Assign.into()),
             ),
-            (TK::String, TV::String("'This is a string literal'".into())),
+            (TK::String, TV::String("This is a string literal".into())),
             (TK::SemiColon, TV::None),
         ],
     );
 }
 
+fn template(text: &str) -> Value {
+    Value::Template(TemplateValue {
+        cooked: Some(text.into()),
+        raw: text.into(),
+    })
+}
+
 #[test]
 fn template_string_literal() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code =
         "let x = `A ${string_type} string with ${is_nested ? `${nested_level} nestings` : ''}`;";
     expect_tokens(
-        &source_code,
-        &vec![
+        source_code,
+        &[
             (TK::Keyword, TV::Keyword(Keyword::Let)),
             (TK::Identifier, TV::Identifier("x".into())),
             (
                 TK::Operator,
                 TV::Operator(AssignmentOperator::Assign.into()),
             ),
-            (
-                TK::String,
-                TV::String(
-                    "`A ${string_type} string with ${is_nested ? `${nested_level} nestings` : ''}`"
-                        .into(),
-                ),
-            ),
+            (TK::TemplateHead, template("A ")),
+            (TK::Identifier, TV::Identifier("string_type".into())),
+            (TK::TemplateMiddle, template(" string with ")),
+            (TK::Identifier, TV::Identifier("is_nested".into())),
+            (TK::QuestionMark, TV::None),
+            (TK::TemplateHead, template("")),
+            (TK::Identifier, TV::Identifier("nested_level".into())),
+            (TK::TemplateTail, template(" nestings")),
+            (TK::Colon, TV::None),
+            (TK::String, TV::String("".into())),
+            (TK::TemplateTail, template("")),
             (TK::SemiColon, TV::None),
         ],
     );
@@ -132,10 +182,10 @@ fn template_string_literal() {
 #[test]
 fn if_statement_with_boolean() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "if(false) {} else if(true) {} else {}";
     expect_tokens(
-        &source_code,
+        source_code,
         &vec![
             (TK::Keyword, TV::Keyword(Keyword::If)),
             (TK::OpenParen, TV::None),
@@ -160,10 +210,10 @@ fn if_statement_with_boolean() {
 #[test]
 fn if_statement_with_variables() {
     use TokenKind as TK;
-    use TokenValue as TV;
+    use Value as TV;
     let source_code = "if (this.pos.x > window.innerWidth) {}";
     expect_tokens(
-        &source_code,
+        source_code,
         &vec![
             (TK::Keyword, TV::Keyword(Keyword::If)),
             (TK::OpenParen, TV::None),
@@ -185,3 +235,124 @@ fn if_statement_with_variables() {
         ],
     );
 }
+
+#[test]
+fn import_declaration() {
+    use TokenKind as TK;
+    use Value as TV;
+    // `as` and `from` are only keywords in import and export declarations
+    let source_code = "import { a as b } from 'c';";
+    expect_tokens(
+        source_code,
+        &[
+            (TK::Keyword, TV::Keyword(Keyword::Import)),
+            (TK::OpenBrace, TV::None),
+            (TK::Identifier, TV::Identifier("a".into())),
+            (TK::Identifier, TV::Identifier("as".into())),
+            (TK::Identifier, TV::Identifier("b".into())),
+            (TK::CloseBrace, TV::None),
+            (TK::Identifier, TV::Identifier("from".into())),
+            (TK::String, TV::String("c".into())),
+            (TK::SemiColon, TV::None),
+        ],
+    );
+}
+
+#[test]
+fn spread_and_rest() {
+    use TokenKind as TK;
+    use Value as TV;
+    let source_code = "[a, ...b] = [...c, .5];";
+    expect_tokens(
+        source_code,
+        &vec![
+            (TK::OpenBracket, TV::None),
+            (TK::Identifier, TV::Identifier("a".into())),
+            (TK::Comma, TV::None),
+            (TK::Ellipsis, TV::None),
+            (TK::Identifier, TV::Identifier("b".into())),
+            (TK::CloseBracket, TV::None),
+            (
+                TK::Operator,
+                TV::Operator(AssignmentOperator::Assign.into()),
+            ),
+            (TK::OpenBracket, TV::None),
+            (TK::Ellipsis, TV::None),
+            (TK::Identifier, TV::Identifier("c".into())),
+            (TK::Comma, TV::None),
+            (TK::Number, TV::Number(0.5)),
+            (TK::CloseBracket, TV::None),
+            (TK::SemiColon, TV::None),
+        ],
+    );
+}
+
+#[test]
+fn shift_operators() {
+    use TokenKind as TK;
+    use Value as TV;
+    // `>>` stays two `>` tokens, which the parser joins in expressions
+    let source_code = "a << b <<= c >> d";
+    expect_tokens(
+        source_code,
+        &[
+            (TK::Identifier, TV::Identifier("a".into())),
+            (TK::Operator, TV::Operator(BinaryOperator::LeftShift.into())),
+            (TK::Identifier, TV::Identifier("b".into())),
+            (
+                TK::Operator,
+                TV::Operator(AssignmentOperator::LeftShiftEquals.into()),
+            ),
+            (TK::Identifier, TV::Identifier("c".into())),
+            (
+                TK::Operator,
+                TV::Operator(BinaryOperator::GreaterThan.into()),
+            ),
+            (
+                TK::Operator,
+                TV::Operator(BinaryOperator::GreaterThan.into()),
+            ),
+            (TK::Identifier, TV::Identifier("d".into())),
+        ],
+    );
+}
+
+#[test]
+fn bitwise_operators() {
+    use TokenKind as TK;
+    use Value as TV;
+    let source_code = "~a ^ b; c &= d; e |= f; g ^= h";
+    expect_tokens(
+        source_code,
+        &[
+            (TK::Operator, TV::Operator(UnaryOperator::BitwiseNot.into())),
+            (TK::Identifier, TV::Identifier("a".into())),
+            (
+                TK::Operator,
+                TV::Operator(BinaryOperator::BitwiseXor.into()),
+            ),
+            (TK::Identifier, TV::Identifier("b".into())),
+            (TK::SemiColon, TV::None),
+            (TK::Identifier, TV::Identifier("c".into())),
+            (
+                TK::Operator,
+                TV::Operator(AssignmentOperator::BitwiseAndEquals.into()),
+            ),
+            (TK::Identifier, TV::Identifier("d".into())),
+            (TK::SemiColon, TV::None),
+            (TK::Identifier, TV::Identifier("e".into())),
+            (
+                TK::Operator,
+                TV::Operator(AssignmentOperator::BitwiseOrEquals.into()),
+            ),
+            (TK::Identifier, TV::Identifier("f".into())),
+            (TK::SemiColon, TV::None),
+            (TK::Identifier, TV::Identifier("g".into())),
+            (
+                TK::Operator,
+                TV::Operator(AssignmentOperator::BitwiseXorEquals.into()),
+            ),
+            (TK::Identifier, TV::Identifier("h".into())),
+        ],
+    );
+}
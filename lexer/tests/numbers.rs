@@ -0,0 +1,122 @@
+use lexer::{LexError, LexErrorKind, Lexer, TokenKind, TokenValue};
+use pretty_assertions::assert_eq;
+
+fn lex(source_code: &str) -> (Vec<(TokenKind, TokenValue)>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| (tok.kind, tok.value))
+        .collect::<Vec<_>>();
+
+    (tokens, lexer.take_errors())
+}
+
+fn expect_number(source_code: &str, expected: f64) {
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens, [(TokenKind::Number, TokenValue::Number(expected))]);
+    assert_eq!(errors, []);
+}
+
+fn expect_errors(source_code: &str, expected: &[(LexErrorKind, usize, usize)]) {
+    let (_, errors) = lex(source_code);
+    let errors = errors
+        .into_iter()
+        .map(|err| (err.kind, err.start, err.end))
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors, expected);
+}
+
+#[test]
+fn decimal() {
+    expect_number("0", 0.0);
+    expect_number("42", 42.0);
+    expect_number("3.25", 3.25);
+    expect_number(".5", 0.5);
+    expect_number("5.", 5.0);
+    expect_number("1e3", 1000.0);
+    expect_number("1E+3", 1000.0);
+    expect_number("1e-9", 1e-9);
+    expect_number("2.5e2", 250.0);
+    expect_number("0.1", 0.1);
+}
+
+#[test]
+fn radix() {
+    expect_number("0xFF", 255.0);
+    expect_number("0Xff", 255.0);
+    expect_number("0o17", 15.0);
+    expect_number("0b101", 5.0);
+    expect_number("0xFFFFFFFFFFFFFFFFFF", 4722366482869645213696.0);
+}
+
+#[test]
+fn numeric_separators() {
+    expect_number("1_000_000", 1_000_000.0);
+    expect_number("1_0.0_1e1_0", 10.01e10);
+    expect_number("0xFF_FF", 65535.0);
+    expect_number("0b1010_0101", 165.0);
+}
+
+#[test]
+fn bigint() {
+    let source_code = "10n 0xF_Fn 1_000n";
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| (tok.kind, tok.bigint_value(source_code)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tokens,
+        [
+            (TokenKind::BigInt, "10".into()),
+            (TokenKind::BigInt, "0xFF".into()),
+            (TokenKind::BigInt, "1000".into()),
+        ]
+    );
+    assert_eq!(lexer.take_errors(), []);
+}
+
+#[test]
+fn member_access_after_number() {
+    use TokenKind as TK;
+    use TokenValue as TV;
+    let (tokens, errors) = lex("1..toString");
+
+    assert_eq!(
+        tokens,
+        [
+            (TK::Number, TV::Number(1.0)),
+            (TK::Dot, TV::None),
+            (TK::Identifier, TV::None),
+        ]
+    );
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn malformed_numbers() {
+    use LexErrorKind as E;
+    expect_errors("0x", &[(E::MissingDigits, 0, 2)]);
+    expect_errors("1e+", &[(E::MissingDigits, 0, 3)]);
+    expect_errors("1__0", &[(E::InvalidNumericSeparator, 1, 2)]);
+    expect_errors("1_", &[(E::InvalidNumericSeparator, 1, 2)]);
+    expect_errors("1._5", &[(E::InvalidNumericSeparator, 2, 3)]);
+    expect_errors("1.5n", &[(E::InvalidBigInt, 0, 4)]);
+    expect_errors("1e3n", &[(E::InvalidBigInt, 0, 4)]);
+    expect_errors("017", &[(E::LeadingZero, 0, 3)]);
+    expect_errors("3in", &[(E::InvalidNumberSuffix, 1, 3)]);
+    expect_errors("0b12", &[(E::InvalidNumberSuffix, 3, 4)]);
+}
+
+#[test]
+fn malformed_numbers_still_produce_one_token() {
+    let (tokens, _) = lex("0b12 + 3in");
+
+    assert_eq!(
+        tokens.iter().map(|(kind, _)| kind).collect::<Vec<_>>(),
+        [&TokenKind::Number, &TokenKind::Operator, &TokenKind::Number]
+    );
+}
@@ -0,0 +1,251 @@
+use lexer::{
+    BinaryOperator, LexError, LexErrorKind, Lexer, RegexValue, Token, TokenKind, TokenValue,
+};
+use pretty_assertions::assert_eq;
+
+fn lex(source_code: &str) -> (Vec<(TokenKind, TokenValue)>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| (tok.kind, tok.value))
+        .collect::<Vec<_>>();
+
+    (tokens, lexer.take_errors())
+}
+
+fn kinds(source_code: &str) -> Vec<TokenKind> {
+    lex(source_code)
+        .0
+        .into_iter()
+        .map(|(kind, _)| kind)
+        .collect()
+}
+
+/// The decoded value of every regex in the source code
+fn regexes(source_code: &str) -> Vec<RegexValue> {
+    Lexer::new(source_code)
+        .filter(|tok| tok.is(TokenKind::RegexLiteral))
+        .map(|tok| tok.regex_value(source_code))
+        .collect()
+}
+
+fn regex(pattern: &str, flags: &str) -> RegexValue {
+    RegexValue {
+        pattern: pattern.into(),
+        flags: flags.into(),
+    }
+}
+
+#[test]
+fn regex_literal() {
+    let source_code = "x = /ab+c/gi;";
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens[2].0, TokenKind::RegexLiteral);
+    assert_eq!(regexes(source_code), [regex("ab+c", "gi")]);
+    assert_eq!(tokens[3].0, TokenKind::SemiColon);
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn division_after_operand() {
+    use TokenKind as TK;
+    let div = (
+        TK::Operator,
+        TokenValue::Operator(BinaryOperator::Div.into()),
+    );
+    let (tokens, _) = lex("a / b / c");
+
+    assert_eq!(tokens[1], div);
+    assert_eq!(tokens[3], div);
+    assert_eq!(
+        kinds("(a) / 2; a[0] / 2; 1 / 2; a++ / 2; this / 2"),
+        [
+            TK::OpenParen,
+            TK::Identifier,
+            TK::CloseParen,
+            TK::Operator,
+            TK::Number,
+            TK::SemiColon,
+            TK::Identifier,
+            TK::OpenBracket,
+            TK::Number,
+            TK::CloseBracket,
+            TK::Operator,
+            TK::Number,
+            TK::SemiColon,
+            TK::Number,
+            TK::Operator,
+            TK::Number,
+            TK::SemiColon,
+            TK::Identifier,
+            TK::Operator,
+            TK::Operator,
+            TK::Number,
+            TK::SemiColon,
+            TK::Keyword,
+            TK::Operator,
+            TK::Number,
+        ]
+    );
+}
+
+#[test]
+fn division_after_property_name() {
+    let div = TokenValue::Operator(BinaryOperator::Div.into());
+    for source_code in [
+        "a.type / b / c",
+        "x.of / 2 / 3",
+        "a.default / 2 / 3",
+        "a.if / 2 / 3",
+    ] {
+        let (tokens, errors) = lex(source_code);
+
+        assert_eq!(tokens[3].1, div, "{source_code}");
+        assert_eq!(tokens[5].1, div, "{source_code}");
+        assert_eq!(errors, [], "{source_code}");
+    }
+}
+
+#[test]
+fn division_after_contextual_keyword() {
+    let div = TokenValue::Operator(BinaryOperator::Div.into());
+    let (tokens, errors) = lex("let number = 10; number / 2 / 3");
+
+    assert_eq!(tokens[6].1, div);
+    assert_eq!(tokens[8].1, div);
+    assert_eq!(errors, []);
+
+    for source_code in [
+        "type / 2 / 3",
+        "of / 2 / 3",
+        "async / 2 / 3",
+        "string / 2 / 3",
+    ] {
+        assert_eq!(lex(source_code).0[1].1, div, "{source_code}");
+    }
+}
+
+#[test]
+fn regex_where_an_expression_starts() {
+    use TokenKind as TK;
+    assert_eq!(kinds("/a/"), [TK::RegexLiteral]);
+    assert_eq!(kinds("f(/a/, /b/)")[2], TK::RegexLiteral);
+    assert_eq!(kinds("f(/a/, /b/)")[4], TK::RegexLiteral);
+    assert_eq!(kinds("return /a/")[1], TK::RegexLiteral);
+    assert_eq!(kinds("x = a ? /b/ : /c/")[4], TK::RegexLiteral);
+    assert_eq!(kinds("if (a) /b/.test(c)")[4], TK::RegexLiteral);
+    assert_eq!(kinds("x = /=/")[2], TK::RegexLiteral);
+}
+
+/// Lexes the source code like the parser does when every `}` ends an expression
+fn lex_after_expression_brace(source_code: &str) -> (Vec<Token>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let mut tokens = Vec::new();
+
+    loop {
+        let mut token = lexer.next_token();
+        if let Some(division) = lexer.relex_as_division(&token) {
+            token = division;
+        }
+        if token.is(TokenKind::Eof) {
+            break;
+        }
+        tokens.push(token);
+    }
+
+    (tokens, lexer.take_errors())
+}
+
+#[test]
+fn division_after_expression_brace() {
+    use TokenKind as TK;
+    let div = TokenValue::Operator(BinaryOperator::Div.into());
+
+    // Without the parser, a `/` after a `}` starts a regex
+    assert_eq!(kinds("f(function(){} / 2 / 3)")[7], TK::RegexLiteral);
+    assert_eq!(kinds("let a = {}\n/foo/g.test(x)")[5], TK::RegexLiteral);
+
+    let (tokens, errors) = lex_after_expression_brace("f(function(){} / 2 / 3)");
+    assert_eq!(tokens[7].value, div);
+    assert_eq!(tokens[8].kind, TK::Number);
+    assert_eq!(tokens[9].value, div);
+    assert_eq!(tokens[10].kind, TK::Number);
+    assert_eq!(tokens[11].kind, TK::CloseParen);
+    assert_eq!(errors, []);
+
+    let (tokens, errors) = lex_after_expression_brace("let a = {}\n/foo/g.test(x)");
+    assert_eq!(tokens[5].value, div);
+    assert!(tokens[5].newline_before);
+    assert_eq!(tokens[6].kind, TK::Identifier);
+    assert_eq!(tokens[7].value, div);
+    assert_eq!(tokens[8].kind, TK::Identifier);
+    assert_eq!(tokens[9].kind, TK::Dot);
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn division_after_expression_brace_drops_regex_errors() {
+    // As a regex, `/ 2;` is unterminated
+    let source_code = "x = {} / 2;\ny";
+    let mut lexer = Lexer::new(source_code);
+    for _ in 0..4 {
+        lexer.next_token();
+    }
+    assert!(lexer.peek_token().is(TokenKind::RegexLiteral));
+    let regex = lexer.next_token();
+    assert_eq!(lexer.errors().len(), 1);
+
+    let division = lexer.relex_as_division(&regex).unwrap();
+    assert_eq!(
+        division.value,
+        TokenValue::Operator(BinaryOperator::Div.into())
+    );
+    assert_eq!(lexer.errors(), []);
+    assert!(lexer.next_token().is(TokenKind::Number));
+    assert!(lexer.next_token().is(TokenKind::SemiColon));
+    assert!(lexer.next_token().is(TokenKind::Identifier));
+}
+
+#[test]
+fn slash_in_character_class() {
+    let source_code = r"/[/\]]+\//";
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(regexes(source_code), [regex(r"[/\]]+\/", "")]);
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn unterminated_regex() {
+    let source_code = "x = /abc\ny";
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens[2].0, TokenKind::RegexLiteral);
+    assert_eq!(regexes(source_code), [regex("abc", "")]);
+    assert_eq!(tokens[3].0, TokenKind::Identifier);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| (err.kind, err.start, err.end))
+            .collect::<Vec<_>>(),
+        [(LexErrorKind::UnterminatedRegex, 4, 8)]
+    );
+}
+
+#[test]
+fn invalid_flags() {
+    let (_, errors) = lex("/a/gg; /b/x");
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| (err.kind, err.start, err.end))
+            .collect::<Vec<_>>(),
+        [
+            (LexErrorKind::InvalidRegexFlags, 3, 5),
+            (LexErrorKind::InvalidRegexFlags, 10, 11)
+        ]
+    );
+}
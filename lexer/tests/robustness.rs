@@ -0,0 +1,84 @@
+use lexer::{LexErrorKind, Lexer, TokenKind};
+use pretty_assertions::assert_eq;
+use proptest::prelude::*;
+
+/// Lexes the whole source code, checking that every token and error has a valid span,
+/// and that every token value can be decoded
+fn lex_all(source_code: &str) {
+    let mut lexer = Lexer::new(source_code);
+    let mut last_end = 0;
+
+    loop {
+        let token = lexer.next_token();
+        assert!(token.start >= last_end && token.end <= source_code.len());
+        assert!(source_code.is_char_boundary(token.start));
+        assert!(source_code.is_char_boundary(token.end));
+        last_end = token.end;
+
+        if token.kind == TokenKind::Eof {
+            break;
+        }
+        assert!(token.end > token.start, "empty token at {}", token.start);
+
+        // Decoding values on demand must not panic either, even for malformed tokens
+        match token.kind {
+            TokenKind::Identifier => drop(token.identifier_name(source_code)),
+            TokenKind::String => drop(token.string_value(source_code)),
+            TokenKind::BigInt => drop(token.bigint_value(source_code)),
+            TokenKind::RegexLiteral => drop(token.regex_value(source_code)),
+            TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateHead
+            | TokenKind::TemplateMiddle
+            | TokenKind::TemplateTail => drop(token.template_value(source_code)),
+            _ => {}
+        }
+    }
+
+    for error in lexer.take_errors() {
+        assert!(error.start <= error.end && error.end <= source_code.len());
+    }
+}
+
+#[test]
+fn unexpected_characters() {
+    let mut lexer = Lexer::new("a # b \u{200D}");
+    let tokens = lexer.by_ref().map(|tok| tok.kind).collect::<Vec<_>>();
+
+    assert_eq!(
+        tokens,
+        [
+            TokenKind::Identifier,
+            TokenKind::Invalid,
+            TokenKind::Identifier,
+            TokenKind::Invalid,
+        ]
+    );
+
+    let errors = lexer.take_errors();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].kind, LexErrorKind::UnexpectedCharacter);
+    assert_eq!(errors[0].kind.code(), "L0015");
+    assert_eq!((errors[0].start, errors[0].end), (2, 3));
+    assert_eq!((errors[1].start, errors[1].end), (6, 9));
+}
+
+#[test]
+fn unterminated_literals() {
+    for source_code in [
+        "'abc", "\"abc\\", "`abc${", "/* abc", "/abc", "0x", "1e", "\\u{",
+    ] {
+        lex_all(source_code);
+    }
+}
+
+proptest! {
+    #[test]
+    fn never_panics_on_random_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        lex_all(&String::from_utf8_lossy(&bytes));
+    }
+
+    #[test]
+    fn never_panics_on_random_code(source_code in r#"[a-z0-9_$\\/*`'"{}()\[\].,;:?!=+\-<>&|^%~#@ \n\ru{}xn]{0,64}"#) {
+        lex_all(&source_code);
+    }
+}
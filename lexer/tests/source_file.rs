@@ -0,0 +1,75 @@
+use lexer::{LineCol, SourceFile};
+use pretty_assertions::assert_eq;
+
+fn pos(line: u32, column: u32) -> LineCol {
+    LineCol { line, column }
+}
+
+#[test]
+fn ascii() {
+    let file = SourceFile::new("main.ts", "let a = 1;\nlet b = 2;\n");
+    assert_eq!(file.line_index().line_count(), 3);
+    assert_eq!(file.line_col(0), pos(1, 1));
+    assert_eq!(file.line_col(4), pos(1, 5));
+    assert_eq!(file.line_col(10), pos(1, 11));
+    assert_eq!(file.line_col(11), pos(2, 1));
+    assert_eq!(file.line_col(22), pos(3, 1));
+    assert_eq!(file.line_col(100), pos(3, 1));
+
+    assert_eq!(file.offset(pos(2, 5)), Some(15));
+    assert_eq!(file.offset(pos(3, 1)), Some(22));
+    assert_eq!(file.offset(pos(1, 12)), None);
+    assert_eq!(file.offset(pos(4, 1)), None);
+    assert_eq!(file.offset(pos(0, 1)), None);
+}
+
+#[test]
+fn crlf() {
+    let file = SourceFile::new("main.ts", "a;\r\nb;\rc;");
+    assert_eq!(file.line_col(4), pos(2, 1));
+    assert_eq!(file.line_col(7), pos(3, 1));
+    assert_eq!(file.offset(pos(2, 1)), Some(4));
+    assert_eq!(file.offset(pos(3, 2)), Some(8));
+}
+
+#[test]
+fn multibyte() {
+    // `å` is 2 bytes and 1 UTF-16 unit, `😀` is 4 bytes and 2 UTF-16 units
+    let text = "let å = \"😀\";\nx";
+    let file = SourceFile::new("main.ts", text);
+
+    let semi = text.find(';').unwrap();
+    assert_eq!(file.line_col(semi), pos(1, 13));
+    assert_eq!(file.offset(pos(1, 13)), Some(semi));
+
+    let emoji = text.find('😀').unwrap();
+    assert_eq!(file.line_col(emoji), pos(1, 10));
+    assert_eq!(file.line_col(emoji + 2), pos(1, 10));
+    assert_eq!(file.offset(pos(1, 10)), Some(emoji));
+    assert_eq!(file.offset(pos(1, 11)), None);
+    assert_eq!(file.offset(pos(1, 12)), Some(emoji + 4));
+
+    assert_eq!(file.line_col(text.len() - 1), pos(2, 1));
+}
+
+#[test]
+fn utf16_offsets() {
+    let text = "let å = \"😀\";\r\nx";
+    let file = SourceFile::new("main.ts", text);
+
+    assert_eq!(file.utf16_offset(text.find('å').unwrap()), 4);
+    assert_eq!(file.utf16_offset(text.find('=').unwrap()), 6);
+    assert_eq!(file.utf16_offset(text.find(';').unwrap()), 12);
+    assert_eq!(file.utf16_offset(text.find('x').unwrap()), 15);
+    assert_eq!(file.utf16_offset(text.len()), 16);
+}
+
+#[test]
+fn round_trip() {
+    let text = "const ü = 'ß';\r\n// 😀 ∑\nfoo(ü);";
+    let file = SourceFile::new("main.ts", text);
+
+    for (offset, _) in text.char_indices() {
+        assert_eq!(file.offset(file.line_col(offset)), Some(offset));
+    }
+}
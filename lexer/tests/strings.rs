@@ -0,0 +1,95 @@
+use lexer::{LexError, LexErrorKind, Lexer, TokenKind};
+use pretty_assertions::assert_eq;
+
+fn lex(source_code: &str) -> (Vec<(TokenKind, String)>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| (tok.kind, tok.string_value(source_code).into_owned()))
+        .collect::<Vec<_>>();
+
+    (tokens, lexer.take_errors())
+}
+
+fn expect_string(source_code: &str, expected: &str) {
+    let (tokens, errors) = lex(source_code);
+
+    assert_eq!(tokens, [(TokenKind::String, expected.into())]);
+    assert_eq!(errors, []);
+}
+
+fn expect_errors(source_code: &str, expected: &[(LexErrorKind, usize, usize)]) {
+    let (_, errors) = lex(source_code);
+    let errors = errors
+        .into_iter()
+        .map(|err| (err.kind, err.start, err.end))
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors, expected);
+}
+
+#[test]
+fn quotes() {
+    expect_string(r#""double""#, "double");
+    expect_string("'single'", "single");
+    expect_string(r#"'a "quoted" word'"#, "a \"quoted\" word");
+    expect_string(r#""it's""#, "it's");
+    expect_string("''", "");
+}
+
+#[test]
+fn single_character_escapes() {
+    expect_string(r#""\n\t\r\b\f\v\0""#, "\n\t\r\u{8}\u{c}\u{b}\0");
+    expect_string(r#"'\'\"\\'"#, "'\"\\");
+    // Any other character escapes itself
+    expect_string(r#""\a\$""#, "a$");
+}
+
+#[test]
+fn hex_and_unicode_escapes() {
+    expect_string(r#""\x41\x7a""#, "Az");
+    expect_string(r#""\u0041\u00e9""#, "Aé");
+    expect_string(r#""\u{1F600}\u{41}""#, "😀A");
+    expect_string(r#""\uD83D\uDE00""#, "😀");
+    expect_string(r#""\uD83D""#, "\u{FFFD}");
+}
+
+#[test]
+fn line_continuations() {
+    expect_string("'a\\\nb'", "ab");
+    expect_string("'a\\\r\nb'", "ab");
+    expect_string("'a\\\u{2028}b'", "ab");
+    expect_string("'a\u{2028}b'", "a\u{2028}b");
+}
+
+#[test]
+fn raw_text_is_the_token_span() {
+    let source_code = r#"x = "a\nb";"#;
+    let mut lexer = Lexer::new(source_code);
+    let string = lexer.find(|tok| tok.is(TokenKind::String)).unwrap();
+
+    assert_eq!(string.text(source_code), r#""a\nb""#);
+    assert_eq!(string.string_value(source_code), "a\nb");
+}
+
+#[test]
+fn invalid_escapes() {
+    use LexErrorKind as E;
+    expect_errors(r#""\x4""#, &[(E::InvalidHexEscape, 1, 3)]);
+    expect_errors(r#""\u12""#, &[(E::InvalidUnicodeEscape, 1, 3)]);
+    expect_errors(r#""\u{110000}""#, &[(E::InvalidUnicodeEscape, 1, 11)]);
+    expect_errors(r#""\u{}""#, &[(E::InvalidUnicodeEscape, 1, 5)]);
+    expect_errors(r#""\1""#, &[(E::OctalEscape, 1, 3)]);
+    expect_errors(r#""\08""#, &[(E::OctalEscape, 1, 3)]);
+}
+
+#[test]
+fn unterminated_strings() {
+    use LexErrorKind as E;
+    expect_errors("'abc", &[(E::UnterminatedString, 0, 4)]);
+    expect_errors(
+        "'abc\n'",
+        &[(E::UnterminatedString, 0, 4), (E::UnterminatedString, 5, 6)],
+    );
+    expect_errors(r#""abc\"#, &[(E::UnterminatedString, 0, 5)]);
+}
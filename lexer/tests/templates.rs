@@ -0,0 +1,98 @@
+use lexer::{LexError, LexErrorKind, Lexer, TemplateValue, TokenKind};
+use pretty_assertions::assert_eq;
+
+/// Lexes the source code, decoding the value of every template token
+fn lex(source_code: &str) -> (Vec<(TokenKind, Option<TemplateValue>)>, Vec<LexError>) {
+    let mut lexer = Lexer::new(source_code);
+    let tokens = lexer
+        .by_ref()
+        .map(|tok| match tok.kind {
+            TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateHead
+            | TokenKind::TemplateMiddle
+            | TokenKind::TemplateTail => (tok.kind, Some(tok.template_value(source_code))),
+            _ => (tok.kind, None),
+        })
+        .collect::<Vec<_>>();
+
+    (tokens, lexer.take_errors())
+}
+
+fn template(cooked: Option<&str>, raw: &str) -> Option<TemplateValue> {
+    Some(TemplateValue {
+        cooked: cooked.map(|cooked| cooked.into()),
+        raw: raw.into(),
+    })
+}
+
+#[test]
+fn no_substitution() {
+    let (tokens, errors) = lex("`hello`");
+
+    assert_eq!(
+        tokens,
+        [(
+            TokenKind::NoSubstitutionTemplate,
+            template(Some("hello"), "hello")
+        )]
+    );
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn substitutions() {
+    use TokenKind as TK;
+    let (tokens, errors) = lex("`a${b}c${ {d} }e`");
+
+    assert_eq!(
+        tokens,
+        [
+            (TK::TemplateHead, template(Some("a"), "a")),
+            (TK::Identifier, None),
+            (TK::TemplateMiddle, template(Some("c"), "c")),
+            (TK::OpenBrace, None),
+            (TK::Identifier, None),
+            (TK::CloseBrace, None),
+            (TK::TemplateTail, template(Some("e"), "e")),
+        ]
+    );
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn cooked_and_raw() {
+    let (tokens, _) = lex(r"`a\n\u{41}\`$`");
+    assert_eq!(tokens[0].1, template(Some("a\nA`$"), r"a\n\u{41}\`$"));
+
+    // Line terminators are normalized in both values
+    let (tokens, _) = lex("`a\r\nb\\\r\nc`");
+    assert_eq!(tokens[0].1, template(Some("a\nbc"), "a\nb\\\nc"));
+}
+
+#[test]
+fn invalid_escapes_are_not_errors() {
+    let (tokens, errors) = lex(r"`\unicode and \1`");
+
+    assert_eq!(
+        tokens,
+        [(
+            TokenKind::NoSubstitutionTemplate,
+            template(None, r"\unicode and \1")
+        )]
+    );
+    assert_eq!(errors, []);
+}
+
+#[test]
+fn unterminated_template() {
+    let (tokens, errors) = lex("`abc${x}def");
+
+    assert_eq!(tokens.last().unwrap().0, TokenKind::TemplateTail);
+    assert_eq!(
+        errors
+            .into_iter()
+            .map(|err| (err.kind, err.start, err.end))
+            .collect::<Vec<_>>(),
+        [(LexErrorKind::UnterminatedTemplate, 7, 11)]
+    );
+}
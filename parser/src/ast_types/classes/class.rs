@@ -1,7 +1,12 @@
 use crate::ast_types::{
     classes::{class_declaration::ClassDeclaration, class_expression::ClassExpression},
-    expressions::{Expression, FunctionExpression},
+    expressions::{types::TypeAnnotation, Expression, FunctionExpression},
+    node_objects::Node,
+    statements::Statement,
 };
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
+use string_cache::DefaultAtom as Atom;
 
 // es2015
 // interface Class <: Node {
@@ -19,9 +24,25 @@ pub enum Class {
 //     type: "ClassBody";
 //     body: [ MethodDefinition ];
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// es2022
+// extend interface ClassBody {
+//     body: [ MethodDefinition | PropertyDefinition | StaticBlock ];
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ClassBody {
-    pub body: Vec<MethodDefinition>,
+    #[serde(flatten)]
+    pub node: Node,
+    pub body: Vec<ClassElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
+pub enum ClassElement {
+    MethodDefinition(Box<MethodDefinition>),
+    PropertyDefinition(Box<PropertyDefinition>),
+    StaticBlock(Box<StaticBlock>),
 }
 
 // es2015
@@ -33,19 +54,117 @@ pub struct ClassBody {
 //     computed: boolean;
 //     static: boolean;
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// es2022
+// extend interface MethodDefinition {
+//     key: Expression | PrivateIdentifier;
+// }
+//
+// The TypeScript modifiers follow typescript-estree
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct MethodDefinition {
-    pub key: Expression,
+    #[serde(flatten)]
+    pub node: Node,
+    pub key: ClassElementKey,
     pub value: FunctionExpression,
     pub kind: MethodDefinitionKind,
     pub computed: bool,
+    #[serde(rename = "static")]
     pub _static: bool,
+    pub accessibility: Option<Accessibility>,
+    #[serde(rename = "abstract")]
+    pub _abstract: bool,
+    #[serde(rename = "override")]
+    pub _override: bool,
+    pub optional: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MethodDefinitionKind {
     Constructor,
     Method,
     Get,
     Set,
 }
+
+// es2022
+// interface PropertyDefinition <: Node {
+//     type: "PropertyDefinition";
+//     key: Expression | PrivateIdentifier;
+//     value: Expression | null;
+//     computed: boolean;
+//     static: boolean;
+// }
+//
+// The TypeScript modifiers and type annotation follow typescript-estree
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct PropertyDefinition {
+    #[serde(flatten)]
+    pub node: Node,
+    pub key: ClassElementKey,
+    pub value: Option<Expression>,
+    pub computed: bool,
+    #[serde(rename = "static")]
+    pub _static: bool,
+    pub accessibility: Option<Accessibility>,
+    #[serde(rename = "abstract")]
+    pub _abstract: bool,
+    #[serde(rename = "override")]
+    pub _override: bool,
+    pub readonly: bool,
+    /// A field that's only declared for its type, like `declare x: number;`
+    pub declare: bool,
+    pub optional: bool,
+    /// A field that's known to be assigned, like `y!: string;`
+    pub definite: bool,
+    pub type_annotation: Option<TypeAnnotation>,
+}
+
+// es2022
+// interface StaticBlock <: BlockStatement {
+//     type: "StaticBlock";
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct StaticBlock {
+    #[serde(flatten)]
+    pub node: Node,
+    pub body: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
+pub enum ClassElementKey {
+    Expression(Expression),
+    PrivateIdentifier(PrivateIdentifier),
+}
+
+// es2022
+// interface PrivateIdentifier <: Node {
+//     type: "PrivateIdentifier";
+//     name: string;
+// }
+//
+// extend interface MemberExpression {
+//     property: Expression | PrivateIdentifier;
+// }
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct PrivateIdentifier {
+    #[serde(flatten)]
+    pub node: Node,
+    pub name: Atom,
+}
+
+// typescript
+// type Accessibility = "public" | "private" | "protected";
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Accessibility {
+    Public,
+    Private,
+    Protected,
+}
@@ -1,16 +1,35 @@
 use crate::ast_types::{
-    classes::class::ClassBody, expressions::Expression, identifier::Identifier, node_objects::Node,
+    classes::class::ClassBody,
+    expressions::{
+        types::{AstType, TypeParameterDeclaration, TypeReference},
+        Expression,
+    },
+    identifier::Identifier,
+    node_objects::Node,
+    statements::Statement,
 };
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ClassDeclaration <: Class, Declaration {
 //     type: "ClassDeclaration";
 //     id: Identifier;
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// The TypeScript fields follow typescript-estree
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ClassDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Identifier,
+    pub type_parameters: Option<TypeParameterDeclaration>,
     pub super_class: Option<Expression>,
+    /// The type arguments of the parent class, like `<T>` in `extends B<T>`
+    pub super_type_arguments: Option<Vec<AstType>>,
+    pub implements: Vec<TypeReference>,
     pub body: ClassBody,
+    #[serde(rename = "abstract")]
+    pub _abstract: bool,
 }
@@ -1,15 +1,31 @@
 use crate::ast_types::{
-    classes::class::ClassBody, expressions::Expression, identifier::Identifier, node_objects::Node,
+    classes::class::ClassBody,
+    expressions::{
+        types::{AstType, TypeParameterDeclaration, TypeReference},
+        Expression,
+    },
+    identifier::Identifier,
+    node_objects::Node,
 };
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ClassExpression <: Class, Expression {
 //     type: "ClassExpression";
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// The TypeScript fields follow typescript-estree
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ClassExpression {
+    #[serde(flatten)]
     pub node: Node,
-    pub id: Identifier,
+    pub id: Option<Identifier>,
+    pub type_parameters: Option<TypeParameterDeclaration>,
     pub super_class: Option<Expression>,
+    /// The type arguments of the parent class, like `<T>` in `extends B<T>`
+    pub super_type_arguments: Option<Vec<AstType>>,
+    pub implements: Vec<TypeReference>,
     pub body: ClassBody,
 }
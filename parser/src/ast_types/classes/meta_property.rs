@@ -1,5 +1,6 @@
 use crate::ast_types::{expressions::Expression, identifier::Identifier, node_objects::Node};
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface MetaProperty <: Expression {
@@ -7,8 +8,10 @@ use parser_derive::Expr;
 //     meta: Identifier;
 //     property: Identifier;
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct MetaProperty {
+    #[serde(flatten)]
     pub node: Node,
     pub meta: Identifier,
     pub property: Identifier,
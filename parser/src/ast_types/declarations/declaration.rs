@@ -1,16 +1,24 @@
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Walk};
 
 use crate::ast_types::{
+    classes::class_declaration::ClassDeclaration,
     declarations::{
-        function_declaration::FunctionDeclaration, variable_declaration::VariableDeclaration,
+        function_declaration::FunctionDeclaration, interface_declaration::InterfaceDeclaration,
+        type_alias_declaration::TypeAliasDeclaration, variable_declaration::VariableDeclaration,
     },
-    statements::Statement,
+    statements::EnumStatement,
 };
+use serde::Serialize;
 
 // es5
 // interface Declaration <: Statement { }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum Declaration {
+    ClassDeclaration(Box<ClassDeclaration>),
+    EnumStatement(Box<EnumStatement>),
     FunctionDeclaration(Box<FunctionDeclaration>),
+    InterfaceDeclaration(Box<InterfaceDeclaration>),
+    TypeAliasDeclaration(Box<TypeAliasDeclaration>),
     VariableDeclaration(Box<VariableDeclaration>),
 }
@@ -1,31 +1,46 @@
 use crate::ast_types::{
+    classes::class::Accessibility,
+    expressions::types::{TypeAnnotation, TypeParameterDeclaration},
     identifier::Identifier,
     node_objects::Node,
     patterns::pattern::Pattern,
     statements::{FunctionBody, Statement},
 };
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface FunctionDeclaration <: Function, Declaration {
 //     type: "FunctionDeclaration";
 //     id: Identifier;
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct FunctionDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Identifier,
     pub generator: bool,
+    #[serde(rename = "async")]
+    pub _async: bool,
     pub type_parameters: Option<TypeParameterDeclaration>,
-    pub params: Vec<Pattern>,
+    pub params: Vec<Parameter>,
     pub return_type: Option<TypeAnnotation>,
     pub body: FunctionBody,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Parameter {
+    #[serde(flatten)]
     pub node: Node,
-    pub identifier: Identifier,
+    pub pattern: Pattern,
     pub type_annotation: Option<TypeAnnotation>,
     pub optional: bool,
+    /// The modifiers of a parameter property, like `private readonly a: number` in a constructor,
+    /// which also declares a field of the class
+    pub accessibility: Option<Accessibility>,
+    pub readonly: bool,
+    #[serde(rename = "override")]
+    pub _override: bool,
 }
@@ -0,0 +1,40 @@
+use crate::ast_types::{
+    expressions::types::{TypeAnnotation, TypeParameterDeclaration},
+    identifier::Identifier,
+    node_objects::Node,
+    statements::Statement,
+};
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
+
+// typescript
+// interface Id<T> { a: number; b?: string; }
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct InterfaceDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    pub type_parameters: Option<TypeParameterDeclaration>,
+    pub body: InterfaceBody,
+}
+
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct InterfaceBody {
+    #[serde(flatten)]
+    pub node: Node,
+    pub body: Vec<PropertySignature>,
+}
+
+/// A member of an interface, like `a: number` or `readonly b?: string`
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct PropertySignature {
+    #[serde(flatten)]
+    pub node: Node,
+    pub key: Identifier,
+    pub optional: bool,
+    pub readonly: bool,
+    pub type_annotation: Option<TypeAnnotation>,
+}
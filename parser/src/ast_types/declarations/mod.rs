@@ -1,3 +1,5 @@
 pub mod declaration;
 pub mod function_declaration;
+pub mod interface_declaration;
+pub mod type_alias_declaration;
 pub mod variable_declaration;
@@ -0,0 +1,20 @@
+use crate::ast_types::{
+    expressions::types::{AstType, TypeParameterDeclaration},
+    identifier::Identifier,
+    node_objects::Node,
+    statements::Statement,
+};
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
+
+// typescript
+// type Id<T> = TypeAnnotation;
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct TypeAliasDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    pub type_parameters: Option<TypeParameterDeclaration>,
+    pub type_annotation: AstType,
+}
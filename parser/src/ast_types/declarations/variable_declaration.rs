@@ -1,6 +1,12 @@
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
 
-use crate::ast_types::{expressions::Expression, node_objects::Node, patterns::pattern::Pattern};
+use crate::ast_types::{
+    expressions::{types::TypeAnnotation, Expression},
+    node_objects::Node,
+    patterns::pattern::Pattern,
+    statements::Statement,
+};
+use serde::Serialize;
 
 // es5
 // interface VariableDeclaration <: Declaration {
@@ -13,14 +19,17 @@ use crate::ast_types::{expressions::Expression, node_objects::Node, patterns::pa
 // extend interface VariableDeclaration {
 //     kind: "var" | "let" | "const";
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct VariableDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub declarations: Vec<VariableDeclarator>,
     pub kind: VariableDeclarationKind,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum VariableDeclarationKind {
     Var,
     Let,
@@ -33,8 +42,10 @@ pub enum VariableDeclarationKind {
 //     id: Pattern;
 //     init: Expression | null;
 // }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct VariableDeclarator {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Pattern,
     pub type_annotation: Option<TypeAnnotation>,
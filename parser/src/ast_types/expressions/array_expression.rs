@@ -1,7 +1,9 @@
 use crate::ast_types::{node_objects::Node, spread_element::SpreadElement};
+use crate::impl_from;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface ArrayExpression <: Expression {
@@ -13,14 +15,20 @@ use parser_derive::Expr;
 // extend interface ArrayExpression {
 //     elements: [ Expression | SpreadElement | null ];
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ArrayExpression {
+    #[serde(flatten)]
     pub node: Node,
-    pub elements: Vec<Option<Expression>>,
+    pub elements: Vec<Option<ArrayElement>>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ArrayElement {
     Expression(Expression),
     SpreadElement(SpreadElement),
 }
+
+impl_from!(ArrayElement, Expression);
+impl_from!(ArrayElement, SpreadElement);
@@ -1,9 +1,13 @@
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
 
 use crate::ast_types::{
-    declarations::function_declaration::Parameter, expressions::Expression, identifier::Identifier,
-    node_objects::Node, statements::FunctionBody,
+    declarations::function_declaration::Parameter,
+    expressions::{types::TypeAnnotation, Expression},
+    identifier::Identifier,
+    node_objects::Node,
+    statements::FunctionBody,
 };
+use serde::Serialize;
 
 // es2015
 // interface ArrowFunctionExpression <: Function, Expression {
@@ -12,17 +16,22 @@ use crate::ast_types::{
 //     expression: boolean;
 //     generator: false;
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ArrowFunctionExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Option<Identifier>,
     pub params: Vec<Parameter>,
     pub return_type: Option<TypeAnnotation>,
     pub body: ArrowFunctionExpressionBody,
     pub expression: bool,
+    #[serde(rename = "async")]
+    pub _async: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ArrowFunctionExpressionBody {
     FunctionBody(FunctionBody),
     Expression(Expression),
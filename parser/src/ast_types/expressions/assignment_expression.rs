@@ -2,7 +2,8 @@ use crate::ast_types::{node_objects::Node, patterns::pattern::Pattern};
 
 use super::Expression;
 use lexer::AssignmentOperator;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface AssignmentExpression <: Expression {
@@ -16,8 +17,10 @@ use parser_derive::Expr;
 // extend interface AssignmentExpression {
 //     left: Pattern;
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct AssignmentExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub operator: AssignmentOperator,
     pub left: Pattern,
@@ -0,0 +1,18 @@
+use crate::ast_types::node_objects::Node;
+
+use super::Expression;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
+
+// es2017
+// interface AwaitExpression <: Expression {
+//     type: "AwaitExpression";
+//     argument: Expression;
+// }
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct AwaitExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub argument: Expression,
+}
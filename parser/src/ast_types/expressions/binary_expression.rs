@@ -2,7 +2,8 @@ use crate::ast_types::node_objects::Node;
 
 use super::Expression;
 use lexer::BinaryOperator;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface BinaryExpression <: Expression {
@@ -11,8 +12,10 @@ use parser_derive::Expr;
 //     left: Expression;
 //     right: Expression;
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct BinaryExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub operator: BinaryOperator,
     pub left: Expression,
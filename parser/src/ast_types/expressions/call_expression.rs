@@ -1,7 +1,9 @@
-use crate::ast_types::{expressions::Super, node_objects::Node};
+use crate::ast_types::{expressions::Super, node_objects::Node, spread_element::SpreadElement};
+use crate::impl_from;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface CallExpression <: Expression {
@@ -15,21 +17,28 @@ use parser_derive::Expr;
 //     callee: Expression | Super;
 //     arguments: [ Expression | SpreadElement ];
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct CallExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub callee: CallExpressionCallee,
-    pub arguments: Vec<Expression>,
+    pub arguments: Vec<CallExpressionArgument>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum CallExpressionCallee {
     Expression(Expression),
     Super(Super),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum CallExpressionArgument {
     Expression(Expression),
     SpreadElement(SpreadElement),
 }
+
+impl_from!(CallExpressionArgument, Expression);
+impl_from!(CallExpressionArgument, SpreadElement);
@@ -1,7 +1,8 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface ConditionalExpression <: Expression {
@@ -10,10 +11,13 @@ use parser_derive::Expr;
 //     alternate: Expression;
 //     consequent: Expression;
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ConditionalExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub test: Expression,
-    pub alternate: Expression,
+    // The fields are walked in order, so keep them in source order
     pub consequent: Expression,
+    pub alternate: Expression,
 }
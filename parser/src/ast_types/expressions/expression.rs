@@ -1,8 +1,51 @@
-#[derive(Debug, PartialEq, Clone)]
-pub enum Expression {}
+use crate::ast_types::{
+    classes::{
+        class::PrivateIdentifier, class_expression::ClassExpression, meta_property::MetaProperty,
+    },
+    expressions::{
+        ArrayExpression, ArrowFunctionExpression, AssignmentExpression, AwaitExpression,
+        BinaryExpression, CallExpression, ConditionalExpression, FunctionExpression, Literal,
+        LogicalExpression, MemberExpression, NewExpression, ObjectExpression,
+        ParenthesisExpression, SequenceExpression, Super, TaggedTemplateExpression,
+        TemplateLiteral, TernaryExpression, ThisExpression, TypeofExpression, UnaryExpression,
+        UpdateExpression, YieldExpression,
+    },
+    identifier::Identifier,
+};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
-impl Expression {
-    pub fn node(&self) -> &Node {
-        match self {}
-    }
+// es5
+// interface Expression <: Node { }
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
+pub enum Expression {
+    ArrayExpression(Box<ArrayExpression>),
+    ArrowFunctionExpression(Box<ArrowFunctionExpression>),
+    AssignmentExpression(Box<AssignmentExpression>),
+    AwaitExpression(Box<AwaitExpression>),
+    BinaryExpression(Box<BinaryExpression>),
+    CallExpression(Box<CallExpression>),
+    ClassExpression(Box<ClassExpression>),
+    ConditionalExpression(Box<ConditionalExpression>),
+    FunctionExpression(Box<FunctionExpression>),
+    Identifier(Box<Identifier>),
+    Literal(Box<Literal>),
+    LogicalExpression(Box<LogicalExpression>),
+    MemberExpression(Box<MemberExpression>),
+    MetaProperty(Box<MetaProperty>),
+    NewExpression(Box<NewExpression>),
+    ObjectExpression(Box<ObjectExpression>),
+    ParenthesisExpression(Box<ParenthesisExpression>),
+    PrivateIdentifier(Box<PrivateIdentifier>),
+    SequenceExpression(Box<SequenceExpression>),
+    Super(Box<Super>),
+    TaggedTemplateExpression(Box<TaggedTemplateExpression>),
+    TemplateLiteral(Box<TemplateLiteral>),
+    TernaryExpression(Box<TernaryExpression>),
+    ThisExpression(Box<ThisExpression>),
+    TypeofExpression(Box<TypeofExpression>),
+    UnaryExpression(Box<UnaryExpression>),
+    UpdateExpression(Box<UpdateExpression>),
+    YieldExpression(Box<YieldExpression>),
 }
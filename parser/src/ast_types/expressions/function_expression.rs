@@ -1,20 +1,33 @@
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
 
 use crate::ast_types::{
-    identifier::Identifier, node_objects::Node, patterns::pattern::Pattern,
+    declarations::function_declaration::Parameter,
+    expressions::{
+        types::{TypeAnnotation, TypeParameterDeclaration},
+        Expression,
+    },
+    identifier::Identifier,
+    node_objects::Node,
     statements::FunctionBody,
 };
+use serde::Serialize;
 
 // es5
 // interface FunctionExpression <: Function, Expression {
 //     type: "FunctionExpression";
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct FunctionExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Option<Identifier>,
     pub generator: bool,
-    pub params: Vec<Pattern>,
+    #[serde(rename = "async")]
+    pub _async: bool,
+    pub type_parameters: Option<TypeParameterDeclaration>,
+    pub params: Vec<Parameter>,
     pub return_type: Option<TypeAnnotation>,
-    pub body: FunctionBody,
+    /// `None` for a TypeScript method without a body, like `abstract area(): number;`
+    pub body: Option<FunctionBody>,
 }
@@ -1,14 +1,17 @@
+use crate::ast_types::expressions::Expression;
 use crate::ast_types::node_objects::Node;
 use lexer::{Keyword, RegexValue};
-use parser_derive::Expr;
-use string_cache::DefaultAtom as Atom;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum Literal {
     StringLiteral(StringLiteral),
     BooleanLiteral(BooleanLiteral),
     NullLiteral(NullLiteral),
     NumberLiteral(NumberLiteral),
+    BigIntLiteral(BigIntLiteral),
     RegexLiteral(RegexLiteral),
 }
 
@@ -28,45 +31,72 @@ macro_rules! init_literal {
     };
 }
 
-impl Literal {
-    pub fn node(&self) -> &Node {
-        match self {
-            Literal::StringLiteral(s) => &s.node,
-            Literal::BooleanLiteral(b) => &b.node,
-            Literal::NullLiteral(n) => &n.node,
-            Literal::NumberLiteral(n) => &n.node,
-            Literal::RegexLiteral(r) => &r.node,
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
 pub struct StringLiteral {
+    #[serde(flatten)]
     pub node: Node,
     pub value: String,
 }
 init_literal!(StringLiteral);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
 pub struct BooleanLiteral {
+    #[serde(flatten)]
     pub node: Node,
     pub value: bool,
 }
 init_literal!(BooleanLiteral);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
 pub struct NullLiteral {
+    #[serde(flatten)]
     pub node: Node,
 }
 init_literal!(NullLiteral);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
 pub struct NumberLiteral {
+    #[serde(flatten)]
     pub node: Node,
     pub value: f64,
 }
 init_literal!(NumberLiteral);
 
+// es2020
+// interface BigIntLiteral <: Literal {
+//   bigint: string;
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
+pub struct BigIntLiteral {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "bigint")]
+    pub value: String,
+}
+init_literal!(BigIntLiteral);
+
+// es5
+// interface RegExpLiteral <: Literal {
+//   regex: {
+//     pattern: string;
+//     flags: string;
+//   };
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename = "Literal", rename_all = "camelCase")]
+pub struct RegexLiteral {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "regex")]
+    pub value: RegexValue,
+}
+init_literal!(RegexLiteral);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VariableKind {
     Var,
@@ -1,7 +1,8 @@
 use crate::ast_types::{expressions::Super, node_objects::Node};
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface MemberExpression <: Expression, Pattern {
@@ -15,15 +16,18 @@ use parser_derive::Expr;
 // extend interface MemberExpression {
 //     object: Expression | Super;
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct MemberExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub object: Expression,
     pub property: Expression,
     pub computed: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(untagged)]
 pub enum MemberExpressionObject {
     Expression(Expression),
     Super(Super),
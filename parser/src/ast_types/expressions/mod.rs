@@ -1,11 +1,13 @@
 pub mod array_expression;
 pub mod arrow_function_expression;
 pub mod assignment_expression;
+pub mod await_expression;
 pub mod binary_expression;
 pub mod call_expression;
 pub mod conditional_expression;
 pub mod expression;
 pub mod function_expression;
+pub mod literal;
 pub mod logical_expression;
 pub mod member_expression;
 pub mod new_expression;
@@ -26,11 +28,13 @@ pub mod yield_expression;
 pub use array_expression::*;
 pub use arrow_function_expression::*;
 pub use assignment_expression::*;
+pub use await_expression::*;
 pub use binary_expression::*;
 pub use call_expression::*;
 pub use conditional_expression::*;
 pub use expression::*;
 pub use function_expression::*;
+pub use literal::*;
 pub use logical_expression::*;
 pub use member_expression::*;
 pub use new_expression::*;
@@ -1,7 +1,9 @@
 use crate::ast_types::{node_objects::Node, spread_element::SpreadElement};
+use crate::impl_from;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface NewExpression <: Expression {
@@ -14,15 +16,21 @@ use parser_derive::Expr;
 // extend interface NewExpression {
 //     arguments: [ Expression | SpreadElement ];
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct NewExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub callee: Expression,
     pub arguments: Vec<NewExpressionArgument>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum NewExpressionArgument {
     Expression(Expression),
     SpreadElement(SpreadElement),
 }
+
+impl_from!(NewExpressionArgument, Expression);
+impl_from!(NewExpressionArgument, SpreadElement);
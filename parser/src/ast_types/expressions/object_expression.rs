@@ -1,14 +1,35 @@
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
 
-use crate::ast_types::{expressions::Expression, node_objects::Node, property::Property};
+use crate::ast_types::{
+    expressions::Expression, node_objects::Node, property::Property, spread_element::SpreadElement,
+};
+use crate::impl_from;
+use serde::Serialize;
 
 // es5
 // interface ObjectExpression <: Expression {
 //     type: "ObjectExpression";
 //     properties: [ Property ];
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+//
+// es2018
+// extend interface ObjectExpression {
+//     properties: [ Property | SpreadElement ];
+// }
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ObjectExpression {
+    #[serde(flatten)]
     pub node: Node,
-    pub properties: Vec<Property>,
+    pub properties: Vec<ObjectExpressionProperty>,
+}
+
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
+pub enum ObjectExpressionProperty {
+    Property(Property),
+    SpreadElement(SpreadElement),
 }
+
+impl_from!(ObjectExpressionProperty, Property);
+impl_from!(ObjectExpressionProperty, SpreadElement);
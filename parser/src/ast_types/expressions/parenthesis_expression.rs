@@ -1,9 +1,16 @@
 use super::Expression;
-use crate::nodes::Node;
-use parser_derive::Expr;
+use crate::ast_types::node_objects::Node;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(
+    tag = "type",
+    rename = "ParenthesizedExpression",
+    rename_all = "camelCase"
+)]
 pub struct ParenthesisExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub expression: Expression,
 }
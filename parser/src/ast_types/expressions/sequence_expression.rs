@@ -1,15 +1,18 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface SequenceExpression <: Expression {
 //     type: "SequenceExpression";
 //     expressions: [ Expression ];
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct SequenceExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub expressions: Vec<Expression>,
 }
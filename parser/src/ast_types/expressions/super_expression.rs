@@ -1,13 +1,16 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface Super <: Node {
 //     type: "Super";
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Super {
+    #[serde(flatten)]
     pub node: Node,
 }
@@ -1,7 +1,8 @@
 use crate::ast_types::{expressions::TemplateLiteral, node_objects::Node};
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface TaggedTemplateExpression <: Expression {
@@ -9,8 +10,10 @@ use parser_derive::Expr;
 //     tag: Expression;
 //     quasi: TemplateLiteral;
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TaggedTemplateExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub tag: Expression,
     pub quasi: TemplateLiteral,
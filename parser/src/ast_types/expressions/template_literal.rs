@@ -1,7 +1,8 @@
 use crate::ast_types::{node_objects::Node, template_element::TemplateElement};
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface TemplateLiteral <: Expression {
@@ -9,9 +10,11 @@ use parser_derive::Expr;
 //     quasis: [ TemplateElement ];
 //     expressions: [ Expression ];
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TemplateLiteral {
+    #[serde(flatten)]
     pub node: Node,
     pub quasis: Vec<TemplateElement>,
-    pub expression: Vec<Expression>,
+    pub expressions: Vec<Expression>,
 }
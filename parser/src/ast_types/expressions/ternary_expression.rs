@@ -1,9 +1,12 @@
 use super::Expression;
-use crate::nodes::Node;
-use parser_derive::Expr;
+use crate::ast_types::node_objects::Node;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TernaryExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub truthy_expr: Box<Expression>,
     pub falsy_expr: Box<Expression>,
@@ -1,13 +1,16 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface ThisExpression <: Expression {
 //     type: "ThisExpression";
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ThisExpression {
+    #[serde(flatten)]
     pub node: Node,
 }
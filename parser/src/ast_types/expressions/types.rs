@@ -1,26 +1,38 @@
-use super::{Identifier, Literal};
-use crate::{impl_from, nodes::Node, statements::Parameter};
+use super::Literal;
+use crate::ast_types::{
+    declarations::function_declaration::Parameter, identifier::Identifier, node_objects::Node,
+};
+use crate::impl_from;
 use lexer::TypeKeyword;
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TypeParameterDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub parameters: Vec<TypeParameter>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TypeParameter {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Identifier,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TypeAnnotation {
+    #[serde(flatten)]
     pub node: Node,
     pub type_value: AstType,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum AstType {
     KeywordType(Box<KeywordType>),
     TypeReference(Box<TypeReference>),
@@ -30,16 +42,6 @@ pub enum AstType {
 }
 
 impl AstType {
-    pub fn node(&self) -> &Node {
-        match self {
-            Self::KeywordType(v) => &v.node,
-            Self::TypeReference(v) => &v.node,
-            Self::ArrayType(v) => &v.node,
-            Self::FnType(v) => &v.node,
-            Self::TypeLiteral(v) => &v.node,
-        }
-    }
-
     /// Checks if two `AstType`s structurally match, allowing for some leniency in optional type parameters
     pub fn matches(&self, other: &Self) -> bool {
         match (self, other) {
@@ -65,37 +67,47 @@ impl AstType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct KeywordType {
+    #[serde(flatten)]
     pub node: Node,
     pub kind: TypeKeyword,
 }
 impl_from!(AstType, KeywordType);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TypeReference {
+    #[serde(flatten)]
     pub node: Node,
     pub type_name: Identifier,
     pub type_params: Option<Vec<AstType>>,
 }
 impl_from!(AstType, TypeReference);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ArrayType {
+    #[serde(flatten)]
     pub node: Node,
     pub type_value: AstType,
 }
 impl_from!(AstType, ArrayType);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct FnType {
+    #[serde(flatten)]
     pub node: Node,
     pub params: Vec<Parameter>,
     pub return_type: TypeAnnotation,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TypeLiteral {
+    #[serde(flatten)]
     pub node: Node,
     pub literal: Literal,
 }
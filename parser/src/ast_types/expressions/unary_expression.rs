@@ -1,7 +1,8 @@
 use super::Expression;
 use crate::ast_types::node_objects::Node;
 use lexer::UnaryOperator;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface UnaryExpression <: Expression {
@@ -10,8 +11,10 @@ use parser_derive::Expr;
 //     prefix: boolean;
 //     argument: Expression;
 // }
-#[derive(Debug, PartialEq, Clone, Expr)]
+#[derive(Debug, PartialEq, Clone, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct UnaryExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub operator: UnaryOperator,
     pub prefix: bool,
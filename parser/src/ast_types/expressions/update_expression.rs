@@ -2,7 +2,8 @@ use crate::ast_types::node_objects::Node;
 
 use super::Expression;
 use lexer::UpdateOperator;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface UpdateExpression <: Expression {
@@ -11,8 +12,10 @@ use parser_derive::Expr;
 //     argument: Expression;
 //     prefix: boolean;
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct UpdateExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub operator: UpdateOperator,
     pub argument: Expression,
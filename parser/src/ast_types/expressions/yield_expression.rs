@@ -1,7 +1,8 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Expression;
-use parser_derive::Expr;
+use parser_derive::{Expr, Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface YieldExpression <: Expression {
@@ -9,8 +10,10 @@ use parser_derive::Expr;
 //     argument: Expression | null;
 //     delegate: boolean;
 // }
-#[derive(Debug, Clone, PartialEq, Expr)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct YieldExpression {
+    #[serde(flatten)]
     pub node: Node,
     pub argument: Option<Expression>,
     pub delegate: bool,
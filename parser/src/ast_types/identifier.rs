@@ -1,5 +1,7 @@
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
+use parser_derive::{Expr, Spanned, Walk};
 
+use serde::Serialize;
 use string_cache::DefaultAtom as Atom;
 
 /*
@@ -9,8 +11,10 @@ interface Identifier <: Expression, Pattern {
     name: string;
 }
 */
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Expr, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Identifier {
+    #[serde(flatten)]
     pub node: Node,
     pub name: Atom,
 }
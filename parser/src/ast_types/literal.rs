@@ -1,17 +1,22 @@
 use crate::ast_types::node_objects::Node;
+use parser_derive::Spanned;
+use serde::Serialize;
 
 // es5
 // interface Literal <: Expression {
 //     type: "Literal";
 //     value: string | boolean | null | number | RegExp;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Literal {
+    #[serde(flatten)]
     pub node: Node,
     pub value: LiteralValue,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum LiteralValue {
     String(String),
     Boolean(bool),
@@ -27,7 +32,8 @@ pub enum LiteralValue {
 //     flags: string;
 //   };
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct RegExpLiteral {
     pattern: String,
     flags: String,
@@ -1,13 +1,21 @@
 use crate::ast_types::{
     classes::{class::ClassBody, class_declaration::ClassDeclaration},
-    declarations::{declaration::Declaration, function_declaration::FunctionDeclaration},
-    expressions::Expression,
+    declarations::{
+        declaration::Declaration,
+        function_declaration::{FunctionDeclaration, Parameter},
+    },
+    expressions::{
+        types::{AstType, TypeAnnotation, TypeParameterDeclaration, TypeReference},
+        Expression,
+    },
     identifier::Identifier,
     literal::Literal,
+    modules::imports::ImportOrExportKind,
     node_objects::Node,
-    patterns::pattern::Pattern,
     statements::FunctionBody,
 };
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ExportNamedDeclaration <: ImportOrExportDeclaration {
@@ -16,12 +24,17 @@ use crate::ast_types::{
 //     specifiers: [ ExportSpecifier ];
 //     source: Literal | null;
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// The `exportKind` of `export type` follows typescript-estree
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ExportNamedDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub declaration: Option<Declaration>,
     pub specifiers: Vec<ExportSpecifier>,
     pub source: Option<Literal>,
+    pub export_kind: ImportOrExportKind,
 }
 
 // es2015
@@ -29,11 +42,14 @@ pub struct ExportNamedDeclaration {
 //     type: "ExportSpecifier";
 //     exported: Identifier;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ExportSpecifier {
+    #[serde(flatten)]
     pub node: Node,
     pub local: Identifier,
     pub exported: Identifier,
+    pub export_kind: ImportOrExportKind,
 }
 
 // es2015
@@ -41,12 +57,18 @@ pub struct ExportSpecifier {
 //     type: "FunctionDeclaration";
 //     id: null;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename = "FunctionDeclaration", rename_all = "camelCase")]
 pub struct AnonymousDefaultExportedFunctionDeclaration {
+    #[serde(flatten)]
     pub node: Node,
-    pub params: Vec<Pattern>,
+    pub type_parameters: Option<TypeParameterDeclaration>,
+    pub params: Vec<Parameter>,
+    pub return_type: Option<TypeAnnotation>,
     pub body: FunctionBody,
     pub generator: bool,
+    #[serde(rename = "async")]
+    pub _async: bool,
 }
 
 // es2015
@@ -54,10 +76,16 @@ pub struct AnonymousDefaultExportedFunctionDeclaration {
 //     type: "ClassDeclaration";
 //     id: null;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename = "ClassDeclaration", rename_all = "camelCase")]
 pub struct AnonymousDefaultExportedClassDeclaration {
+    #[serde(flatten)]
     pub node: Node,
+    pub type_parameters: Option<TypeParameterDeclaration>,
     pub super_class: Option<Expression>,
+    /// The type arguments of the parent class, like `<T>` in `extends B<T>`
+    pub super_type_arguments: Option<Vec<AstType>>,
+    pub implements: Vec<TypeReference>,
     pub body: ClassBody,
 }
 
@@ -66,13 +94,16 @@ pub struct AnonymousDefaultExportedClassDeclaration {
 //     type: "ExportDefaultDeclaration";
 //     declaration: AnonymousDefaultExportedFunctionDeclaration | FunctionDeclaration | AnonymousDefaultExportedClassDeclaration | ClassDeclaration | Expression;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ExportDefaultDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub declaration: ExportDefaultDeclarationDeclaration,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ExportDefaultDeclarationDeclaration {
     AnonymousDefaultExportedFunctionDeclaration(AnonymousDefaultExportedFunctionDeclaration),
     FunctionDeclaration(FunctionDeclaration),
@@ -86,8 +117,17 @@ pub enum ExportDefaultDeclarationDeclaration {
 //     type: "ExportAllDeclaration";
 //     source: Literal;
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// es2020
+// extend interface ExportAllDeclaration {
+//     exported: Identifier | null;
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ExportAllDeclaration {
+    #[serde(flatten)]
     pub node: Node,
+    pub exported: Option<Identifier>,
     pub source: Literal,
+    pub export_kind: ImportOrExportKind,
 }
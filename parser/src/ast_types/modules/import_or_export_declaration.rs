@@ -1,12 +1,17 @@
 use crate::ast_types::modules::{
-    exports::{ExportAllDeclaration, ExportNamedDeclaration},
+    exports::{ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration},
     imports::ImportDeclaration,
 };
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ImportOrExportDeclaration <: Node { }
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ImportOrExportDeclaration {
     ImportDeclaration(ImportDeclaration),
     ExportNamedDeclaration(ExportNamedDeclaration),
+    ExportDefaultDeclaration(ExportDefaultDeclaration),
     ExportAllDeclaration(ExportAllDeclaration),
 }
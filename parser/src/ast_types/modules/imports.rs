@@ -1,4 +1,6 @@
 use crate::ast_types::{identifier::Identifier, literal::Literal, node_objects::Node};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ImportDeclaration <: ImportOrExportDeclaration {
@@ -6,14 +8,29 @@ use crate::ast_types::{identifier::Identifier, literal::Literal, node_objects::N
 //     specifiers: [ ImportSpecifier | ImportDefaultSpecifier | ImportNamespaceSpecifier ];
 //     source: Literal;
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// The `importKind` of `import type` follows typescript-estree
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ImportDeclaration {
+    #[serde(flatten)]
     pub node: Node,
     pub specifiers: Vec<ImportDeclarationSpecifier>,
     pub source: Literal,
+    pub import_kind: ImportOrExportKind,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+// typescript
+// type ImportOrExportKind = "type" | "value";
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportOrExportKind {
+    Type,
+    Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ImportDeclarationSpecifier {
     ImportSpecifier(ImportSpecifier),
     ImportDefaultSpecifier(ImportDefaultSpecifier),
@@ -25,19 +42,24 @@ pub enum ImportDeclarationSpecifier {
 //     type: "ImportSpecifier";
 //     imported: Identifier;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ImportSpecifier {
+    #[serde(flatten)]
     pub node: Node,
     pub local: Identifier,
     pub imported: Identifier,
+    pub import_kind: ImportOrExportKind,
 }
 
 // es2015
 // interface ImportDefaultSpecifier <: ModuleSpecifier {
 //     type: "ImportDefaultSpecifier";
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ImportDefaultSpecifier {
+    #[serde(flatten)]
     pub node: Node,
     pub local: Identifier,
 }
@@ -46,8 +68,10 @@ pub struct ImportDefaultSpecifier {
 // interface ImportNamespaceSpecifier <: ModuleSpecifier {
 //     type: "ImportNamespaceSpecifier";
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ImportNamespaceSpecifier {
+    #[serde(flatten)]
     pub node: Node,
     pub local: Identifier,
 }
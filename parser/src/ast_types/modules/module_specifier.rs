@@ -2,12 +2,15 @@ use crate::ast_types::modules::{
     exports::ExportSpecifier,
     imports::{ImportDefaultSpecifier, ImportNamespaceSpecifier, ImportSpecifier},
 };
+use parser_derive::Spanned;
+use serde::Serialize;
 
 // es2015
 // interface ModuleSpecifier <: Node {
 //     local: Identifier;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[serde(untagged)]
 pub enum ModuleSpecifier {
     ImportSpecifier(Box<ImportSpecifier>),
     ImportDefaultSpecifier(Box<ImportDefaultSpecifier>),
@@ -1,3 +1,6 @@
+use serde::Serialize;
+use std::ops::Range;
+
 /*
 es5
 interface Node {
@@ -5,7 +8,7 @@ interface Node {
     loc: SourceLocation | null;
 }
 */
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct Node {
     pub start: usize,
     pub end: usize,
@@ -16,3 +19,21 @@ impl Node {
         Self { start, end }
     }
 }
+
+/// A part of the AST that has a position in the source code.
+/// Derived with `#[derive(Spanned)]`.
+pub trait Spanned {
+    fn node(&self) -> &Node;
+
+    /// The byte range of the source code that the node covers
+    fn span(&self) -> Range<usize> {
+        let node = self.node();
+        node.start..node.end
+    }
+}
+
+impl<T: Spanned + ?Sized> Spanned for Box<T> {
+    fn node(&self) -> &Node {
+        (**self).node()
+    }
+}
@@ -1,11 +1,16 @@
-use crate::ast_types::patterns::pattern::Pattern;
+use crate::ast_types::{node_objects::Node, patterns::pattern::Pattern};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ArrayPattern <: Pattern {
 //     type: "ArrayPattern";
 //     elements: [ Pattern | null ];
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ArrayPattern {
+    #[serde(flatten)]
+    pub node: Node,
     pub elements: Vec<Option<Pattern>>,
 }
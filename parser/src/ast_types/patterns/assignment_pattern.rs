@@ -1,4 +1,6 @@
-use crate::ast_types::{expressions::Expression, patterns::pattern::Pattern};
+use crate::ast_types::{expressions::Expression, node_objects::Node, patterns::pattern::Pattern};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface AssignmentPattern <: Pattern {
@@ -6,8 +8,11 @@ use crate::ast_types::{expressions::Expression, patterns::pattern::Pattern};
 //     left: Pattern;
 //     right: Expression;
 // }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct AssignmentPattern {
+    #[serde(flatten)]
+    pub node: Node,
     pub left: Pattern,
     pub right: Expression,
 }
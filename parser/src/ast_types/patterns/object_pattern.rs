@@ -1,13 +1,35 @@
-use crate::ast_types::{expressions::Expression, node_objects::Node, patterns::pattern::Pattern};
+use crate::ast_types::{
+    expressions::Expression,
+    node_objects::Node,
+    patterns::{pattern::Pattern, rest_element::RestElement},
+};
+use crate::impl_from;
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ObjectPattern <: Pattern {
 //     type: "ObjectPattern";
 //     properties: [ AssignmentProperty ];
 // }
-#[derive(Debug, Clone, PartialEq)]
+//
+// es2018
+// extend interface ObjectPattern {
+//     properties: [ AssignmentProperty | RestElement ];
+// }
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ObjectPattern {
-    pub properties: Vec<AssignmentProperty>,
+    #[serde(flatten)]
+    pub node: Node,
+    pub properties: Vec<ObjectPatternProperty>,
+}
+
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
+pub enum ObjectPatternProperty {
+    AssignmentProperty(Box<AssignmentProperty>),
+    RestElement(Box<RestElement>),
 }
 
 // es2015
@@ -17,11 +39,16 @@ pub struct ObjectPattern {
 //     kind: "init";
 //     method: false;
 // }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename = "Property", rename_all = "camelCase")]
 pub struct AssignmentProperty {
+    #[serde(flatten)]
     pub node: Node,
     pub key: Expression,
     pub value: Pattern,
     pub shorthand: bool,
     pub computed: bool,
 }
+
+impl_from!(ObjectPatternProperty, AssignmentProperty);
+impl_from!(ObjectPatternProperty, RestElement);
@@ -1,10 +1,30 @@
-use crate::ast_types::patterns::{
-    array_pattern::ArrayPattern, object_pattern::ObjectPattern, rest_element::RestElement,
+use crate::ast_types::{
+    expressions::MemberExpression,
+    identifier::Identifier,
+    patterns::{
+        array_pattern::ArrayPattern, assignment_pattern::AssignmentPattern,
+        object_pattern::ObjectPattern, rest_element::RestElement,
+    },
 };
+use crate::impl_from;
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum Pattern {
+    Identifier(Box<Identifier>),
     ArrayPattern(Box<ArrayPattern>),
     ObjectPattern(Box<ObjectPattern>),
     RestElement(Box<RestElement>),
+    AssignmentPattern(Box<AssignmentPattern>),
+    /// Only valid as an assignment target, like `[obj.a, obj.b] = [1, 2]`
+    MemberExpression(Box<MemberExpression>),
 }
+
+impl_from!(Pattern, Identifier);
+impl_from!(Pattern, ArrayPattern);
+impl_from!(Pattern, ObjectPattern);
+impl_from!(Pattern, RestElement);
+impl_from!(Pattern, AssignmentPattern);
+impl_from!(Pattern, MemberExpression);
@@ -1,3 +1,3 @@
 pub mod program;
 
-pub use program::Program;
+pub use program::{Program, ProgramBody, SourceType};
@@ -1,4 +1,10 @@
-use crate::ast_types::statements::Statement;
+use crate::ast_types::{
+    modules::import_or_export_declaration::ImportOrExportDeclaration, node_objects::Node,
+    statements::Statement,
+};
+use lexer::Comment;
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface Program <: Node {
@@ -11,19 +17,26 @@ use crate::ast_types::statements::Statement;
 //     sourceType: "script" | "module";
 //     body: [ Statement | ImportOrExportDeclaration ];
 // }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Program {
+    #[serde(flatten)]
+    pub node: Node,
     pub source_type: SourceType,
     pub body: Vec<ProgramBody>,
+    /// Every comment in the source code, in order
+    pub comments: Vec<Comment>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SourceType {
     Script,
     Module,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ProgramBody {
     Statement(Statement),
     ImportOrExportDeclaration(ImportOrExportDeclaration),
@@ -1,4 +1,6 @@
 use crate::ast_types::{expressions::Expression, node_objects::Node};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es5
 // interface Property <: Node {
@@ -15,8 +17,10 @@ use crate::ast_types::{expressions::Expression, node_objects::Node};
 //     shorthand: boolean;
 //     computed: boolean;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct Property {
+    #[serde(flatten)]
     pub node: Node,
     pub key: Expression,
     pub value: Expression,
@@ -26,7 +30,8 @@ pub struct Property {
     pub computed: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PropertyKind {
     Init,
     Get,
@@ -1,12 +1,16 @@
 use crate::ast_types::{expressions::Expression, node_objects::Node};
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface SpreadElement <: Node {
 //     type: "SpreadElement";
 //     argument: Expression;
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct SpreadElement {
+    #[serde(flatten)]
     pub node: Node,
     pub argument: Expression,
 }
@@ -1,15 +1,18 @@
 use crate::ast_types::{node_objects::Node, statements::Directive};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface BlockStatement <: Statement {
 //     type: "BlockStatement";
 //     body: [ Statement ];
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct BlockStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub body: Vec<Statement>,
 }
@@ -18,13 +21,16 @@ pub struct BlockStatement {
 // interface FunctionBody <: BlockStatement {
 //     body: [ Directive | Statement ];
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename = "BlockStatement", rename_all = "camelCase")]
 pub struct FunctionBody {
+    #[serde(flatten)]
     pub node: Node,
     pub body: Vec<FunctionBodyBody>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum FunctionBodyBody {
     Directive(Directive),
     Statement(Statement),
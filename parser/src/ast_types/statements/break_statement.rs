@@ -1,15 +1,18 @@
 use crate::ast_types::{identifier::Identifier, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface BreakStatement <: Statement {
 //     type: "BreakStatement";
 //     label: Identifier | null;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct BreakStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub label: Option<Identifier>,
 }
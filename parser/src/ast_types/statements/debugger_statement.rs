@@ -1,13 +1,16 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface DebuggerStatement <: Statement {
 //     type: "DebuggerStatement";
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct DebuggerStatement {
+    #[serde(flatten)]
     pub node: Node,
 }
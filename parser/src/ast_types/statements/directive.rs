@@ -1,14 +1,17 @@
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
 
 use crate::ast_types::{literal::Literal, node_objects::Node, statements::Statement};
+use serde::Serialize;
 
 // es5
 // interface Directive <: ExpressionStatement {
 //     expression: Literal;
 //     directive: string;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename = "ExpressionStatement", rename_all = "camelCase")]
 pub struct Directive {
+    #[serde(flatten)]
     pub node: Node,
     pub expression: Literal,
     pub directive: String,
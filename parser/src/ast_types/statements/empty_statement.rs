@@ -1,13 +1,16 @@
 use crate::ast_types::node_objects::Node;
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface EmptyStatement <: Statement {
 //     type: "EmptyStatement";
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct EmptyStatement {
+    #[serde(flatten)]
     pub node: Node,
 }
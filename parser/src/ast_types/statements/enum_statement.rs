@@ -1,12 +1,12 @@
 use super::Statement;
-use crate::{
-    expressions::{Expression, Identifier},
-    nodes::Node,
-};
-use parser_derive::Stmt;
+use crate::ast_types::{expressions::Expression, identifier::Identifier, node_objects::Node};
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct EnumStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub is_declare: bool,
     pub is_const: bool,
@@ -14,8 +14,10 @@ pub struct EnumStatement {
     pub members: Vec<EnumMember>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct EnumMember {
+    #[serde(flatten)]
     pub node: Node,
     pub id: Identifier,
     pub init: Option<Expression>,
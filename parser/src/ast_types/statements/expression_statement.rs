@@ -1,14 +1,17 @@
 use super::Statement;
-use crate::ast_types::node_objects::Node;
-use parser_derive::Stmt;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface ExpressionStatement <: Statement {
 //     type: "ExpressionStatement";
 //     expression: Expression;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ExpressionStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub expression: Expression,
 }
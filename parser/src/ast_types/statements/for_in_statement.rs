@@ -1,10 +1,12 @@
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
 
 use crate::ast_types::{
     declarations::variable_declaration::VariableDeclaration, expressions::Expression,
     node_objects::Node, patterns::pattern::Pattern,
 };
+use crate::impl_from;
+use serde::Serialize;
 
 // es5
 // interface ForInStatement <: Statement {
@@ -13,16 +15,22 @@ use crate::ast_types::{
 //     right: Expression;
 //     body: Statement;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ForInStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub left: ForInOrOfLeft,
     pub right: Expression,
     pub body: Statement,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ForInOrOfLeft {
     VariableDeclaration(VariableDeclaration),
     Pattern(Pattern),
 }
+
+impl_from!(ForInOrOfLeft, VariableDeclaration);
+impl_from!(ForInOrOfLeft, Pattern);
@@ -1,16 +1,26 @@
 use crate::ast_types::{expressions::Expression, node_objects::Node, statements::ForInOrOfLeft};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es2015
 // interface ForOfStatement <: ForInStatement {
 //     type: "ForOfStatement";
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+//
+// es2018
+// extend interface ForOfStatement {
+//     await: boolean;
+// }
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ForOfStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub left: ForInOrOfLeft,
     pub right: Expression,
     pub body: Statement,
+    #[serde(rename = "await")]
+    pub _await: bool,
 }
@@ -1,7 +1,12 @@
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
 
 use super::Statement;
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{
+    declarations::variable_declaration::VariableDeclaration, expressions::Expression,
+    node_objects::Node,
+};
+use crate::impl_from;
+use serde::Serialize;
 
 // es5
 // interface ForStatement <: Statement {
@@ -11,8 +16,10 @@ use crate::ast_types::node_objects::Node;
 //     update: Expression | null;
 //     body: Statement;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ForStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub init: Option<ForInit>,
     pub test: Option<Expression>,
@@ -20,8 +27,12 @@ pub struct ForStatement {
     pub body: Statement,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum ForInit {
     VariableDeclaration(VariableDeclaration),
     Expression(Expression),
 }
+
+impl_from!(ForInit, VariableDeclaration);
+impl_from!(ForInit, Expression);
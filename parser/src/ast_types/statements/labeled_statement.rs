@@ -1,7 +1,8 @@
 use crate::ast_types::{identifier::Identifier, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface LabeledStatement <: Statement {
@@ -9,8 +10,10 @@ use parser_derive::Stmt;
 //     label: Identifier;
 //     body: Statement;
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct LabeledStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub label: Identifier,
     pub body: Statement,
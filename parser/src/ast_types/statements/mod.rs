@@ -10,7 +10,6 @@ pub mod expression_statement;
 pub mod for_in_statement;
 pub mod for_of_statement;
 pub mod for_statement;
-pub mod function_declaration;
 pub mod if_statement;
 pub mod labeled_statement;
 pub mod return_statement;
@@ -18,7 +17,6 @@ pub mod statement;
 pub mod switch_statement;
 pub mod throw_statement;
 pub mod try_statement;
-pub mod variable_declaration;
 pub mod while_statement;
 pub mod with_statement;
 
@@ -34,7 +32,6 @@ pub use expression_statement::*;
 pub use for_in_statement::*;
 pub use for_of_statement::*;
 pub use for_statement::*;
-pub use function_declaration::*;
 pub use if_statement::*;
 pub use labeled_statement::*;
 pub use return_statement::*;
@@ -42,6 +39,5 @@ pub use statement::*;
 pub use switch_statement::*;
 pub use throw_statement::*;
 pub use try_statement::*;
-pub use variable_declaration::*;
 pub use while_statement::*;
 pub use with_statement::*;
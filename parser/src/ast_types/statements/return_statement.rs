@@ -1,15 +1,18 @@
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface ReturnStatement <: Statement {
 //     type: "ReturnStatement";
 //     argument: Expression | null;
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct ReturnStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub argument: Option<Expression>,
 }
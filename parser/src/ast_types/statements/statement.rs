@@ -1,16 +1,23 @@
-use crate::nodes::Node;
-use crate::statements::{
+use crate::ast_types::classes::class_declaration::ClassDeclaration;
+use crate::ast_types::declarations::{
+    function_declaration::FunctionDeclaration, interface_declaration::InterfaceDeclaration,
+    type_alias_declaration::TypeAliasDeclaration, variable_declaration::VariableDeclaration,
+};
+use crate::ast_types::statements::{
     BlockStatement, BreakStatement, ContinueStatement, DebuggerStatement, Directive,
     DoWhileStatement, EmptyStatement, EnumStatement, ExpressionStatement, ForInStatement,
-    ForOfStatement, ForStatement, FunctionDeclaration, IfStatement, LabeledStatement,
-    ReturnStatement, SwitchStatement, ThrowStatement, TryStatement, VariableDeclaration,
-    WhileStatement, WithStatement,
+    ForOfStatement, ForStatement, IfStatement, LabeledStatement, ReturnStatement, SwitchStatement,
+    ThrowStatement, TryStatement, WhileStatement, WithStatement,
 };
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(untagged)]
 pub enum Statement {
     BlockStatement(Box<BlockStatement>),
     BreakStatement(Box<BreakStatement>),
+    ClassDeclaration(Box<ClassDeclaration>),
     ContinueStatement(Box<ContinueStatement>),
     DebuggerStatement(Box<DebuggerStatement>),
     Directive(Box<Directive>),
@@ -23,41 +30,14 @@ pub enum Statement {
     ForStatement(Box<ForStatement>),
     FunctionDeclaration(Box<FunctionDeclaration>),
     IfStatement(Box<IfStatement>),
+    InterfaceDeclaration(Box<InterfaceDeclaration>),
     LabeledStatement(Box<LabeledStatement>),
     ReturnStatement(Box<ReturnStatement>),
     SwitchStatement(Box<SwitchStatement>),
     ThrowStatement(Box<ThrowStatement>),
     TryStatement(Box<TryStatement>),
+    TypeAliasDeclaration(Box<TypeAliasDeclaration>),
     VariableDeclaration(Box<VariableDeclaration>),
     WhileStatement(Box<WhileStatement>),
     WithStatement(Box<WithStatement>),
 }
-
-impl Statement {
-    pub fn node(&self) -> &Node {
-        match self {
-            Self::BlockStatement(s) => &s.node,
-            Self::BreakStatement(s) => &s.node,
-            Self::ContinueStatement(s) => &s.node,
-            Self::DebuggerStatement(s) => &s.node,
-            Self::Directive(s) => &s.node,
-            Self::DoWhileStatement(s) => &s.node,
-            Self::EmptyStatement(s) => &s.node,
-            Self::EnumStatement(s) => &s.node,
-            Self::ExpressionStatement(s) => &s.node,
-            Self::ForInStatement(s) => &s.node,
-            Self::ForOfStatement(s) => &s.node,
-            Self::ForStatement(s) => &s.node,
-            Self::FunctionDeclaration(s) => &s.node,
-            Self::IfStatement(s) => &s.node,
-            Self::LabeledStatement(s) => &s.node,
-            Self::ReturnStatement(s) => &s.node,
-            Self::SwitchStatement(s) => &s.node,
-            Self::ThrowStatement(s) => &s.node,
-            Self::TryStatement(s) => &s.node,
-            Self::VariableDeclaration(s) => &s.node,
-            Self::WhileStatement(s) => &s.node,
-            Self::WithStatement(s) => &s.node,
-        }
-    }
-}
@@ -1,7 +1,8 @@
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface SwitchStatement <: Statement {
@@ -9,16 +10,20 @@ use parser_derive::Stmt;
 //     discriminant: Expression;
 //     cases: [ SwitchCase ];
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct SwitchStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub discriminant: Expression,
     pub cases: Vec<SwitchCase>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct SwitchCase {
-    node: Node,
-    test: Option<Expression>,
-    consequent: Vec<Statement>,
+    #[serde(flatten)]
+    pub node: Node,
+    pub test: Option<Expression>,
+    pub consequent: Vec<Statement>,
 }
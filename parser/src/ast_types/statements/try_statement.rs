@@ -2,7 +2,8 @@ use super::Statement;
 use crate::ast_types::{
     node_objects::Node, patterns::pattern::Pattern, statements::BlockStatement,
 };
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface TryStatement <: Statement {
@@ -11,23 +12,27 @@ use parser_derive::Stmt;
 //     handler: CatchClause | null;
 //     finalizer: BlockStatement | null;
 // }
-#[derive(Debug, PartialEq, Clone, Stmt)]
+#[derive(Debug, PartialEq, Clone, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TryStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub block: BlockStatement,
     pub handler: Option<CatchClause>,
     pub finalizer: Option<BlockStatement>,
 }
 
-// es5
+// es2019
 // interface CatchClause <: Node {
 //     type: "CatchClause";
-//     param: Pattern;
+//     param: Pattern | null;
 //     body: BlockStatement;
 // }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct CatchClause {
+    #[serde(flatten)]
     pub node: Node,
-    pub param: Pattern,
+    pub param: Option<Pattern>,
     pub body: BlockStatement,
 }
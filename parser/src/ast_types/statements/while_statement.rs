@@ -1,7 +1,8 @@
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface WhileStatement <: Statement {
@@ -9,8 +10,10 @@ use parser_derive::Stmt;
 //     test: Expression;
 //     body: Statement;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct WhileStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub test: Expression,
     pub body: Statement,
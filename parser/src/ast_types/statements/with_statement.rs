@@ -1,7 +1,8 @@
-use crate::ast_types::node_objects::Node;
+use crate::ast_types::{expressions::Expression, node_objects::Node};
 
 use super::Statement;
-use parser_derive::Stmt;
+use parser_derive::{Spanned, Stmt, Walk};
+use serde::Serialize;
 
 // es5
 // interface WithStatement <: Statement {
@@ -9,8 +10,10 @@ use parser_derive::Stmt;
 //     object: Expression;
 //     body: Statement;
 // }
-#[derive(Debug, Clone, PartialEq, Stmt)]
+#[derive(Debug, Clone, PartialEq, Stmt, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct WithStatement {
+    #[serde(flatten)]
     pub node: Node,
     pub object: Expression,
     pub body: Statement,
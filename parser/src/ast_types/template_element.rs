@@ -1,23 +1,22 @@
 use crate::ast_types::node_objects::Node;
+use lexer::TemplateValue;
+use parser_derive::{Spanned, Walk};
+use serde::Serialize;
 
 // es2015
 // interface TemplateElement <: Node {
 //     type: "TemplateElement";
 //     tail: boolean;
 //     value: {
-//         cooked: string;
+//         cooked: string | null;
 //         raw: string;
 //     };
 // }
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Spanned, Walk, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
 pub struct TemplateElement {
+    #[serde(flatten)]
     pub node: Node,
     pub tail: bool,
-    pub value: TemplateElementValue,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-struct TemplateElementValue {
-    pub cooked: String,
-    pub raw: String,
+    pub value: TemplateValue,
 }
@@ -0,0 +1,98 @@
+use crate::ast_types::programs::Program;
+use lexer::SourceFile;
+use serde_json::{json, Map, Value};
+
+/// The largest integer that a JavaScript number can represent exactly
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/// Serializes the program to ESTree-compatible JSON, as produced by acorn.
+/// Every node gets `type`, `start`, `end` and `loc` fields.
+/// Like in acorn, `start` and `end` are counted in UTF-16 code units.
+pub fn to_estree_json(program: &Program, file: &SourceFile) -> String {
+    serde_json::to_string_pretty(&to_estree(program, file)).expect("the AST is always serializable")
+}
+
+/// Same as `to_estree_json`, but returns the JSON as a `serde_json::Value`
+pub fn to_estree(program: &Program, file: &SourceFile) -> Value {
+    let mut value = serde_json::to_value(program).expect("the AST is always serializable");
+    add_locations(&mut value, file);
+    value
+}
+
+fn add_locations(value: &mut Value, file: &SourceFile) {
+    match value {
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                add_locations(item, file);
+            }
+        }
+        Value::Object(obj) => {
+            for child in obj.values_mut() {
+                add_locations(child, file);
+            }
+
+            let start = obj.get("start").and_then(Value::as_u64);
+            let end = obj.get("end").and_then(Value::as_u64);
+            if let (Some(start), Some(end), true) = (start, end, obj.contains_key("type")) {
+                *obj = with_location(std::mem::take(obj), start as usize, end as usize, file);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rebuilds the node so that its fields are in the same order as in acorn's output:
+/// `type`, `start`, `end`, `loc`, followed by the rest of the fields
+fn with_location(
+    mut obj: Map<String, Value>,
+    start: usize,
+    end: usize,
+    file: &SourceFile,
+) -> Map<String, Value> {
+    let mut node = Map::new();
+
+    if let Some(kind) = obj.shift_remove("type") {
+        node.insert("type".to_owned(), kind);
+    }
+    obj.shift_remove("start");
+    obj.shift_remove("end");
+    node.insert("start".to_owned(), file.utf16_offset(start).into());
+    node.insert("end".to_owned(), file.utf16_offset(end).into());
+    node.insert(
+        "loc".to_owned(),
+        json!({
+            "start": position(file, start),
+            "end": position(file, end),
+        }),
+    );
+
+    node.extend(obj);
+
+    if node["type"] == "Literal" {
+        match node.get("value").and_then(Value::as_f64) {
+            // Numbers are stored as floats, but acorn prints integers without a fraction
+            Some(value) if value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER => {
+                node.insert("value".to_owned(), (value as i64).into());
+            }
+            Some(_) => {}
+            // Regex and null literals don't carry a value of their own
+            None => {
+                node.entry("value").or_insert(Value::Null);
+            }
+        }
+        if let Some(raw) = file.text().get(start..end) {
+            node.insert("raw".to_owned(), raw.into());
+        }
+    }
+
+    node
+}
+
+/// ESTree positions have a 1-based line, and a 0-based column
+fn position(file: &SourceFile, offset: usize) -> Value {
+    let pos = file.line_col(offset);
+    json!({
+        "line": pos.line,
+        "column": pos.column - 1,
+    })
+}
@@ -1,5 +1,7 @@
 pub mod ast_types;
+pub mod estree;
 pub mod parser;
 pub mod utils;
+pub mod visit;
 
 pub use parser::Parser;
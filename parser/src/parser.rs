@@ -1,30 +1,115 @@
-use crate::throw_error;
+use crate::{parser_error, throw_error};
 
-use crate::expressions::types::{
+use crate::ast_types::classes::class::{
+    Accessibility, ClassBody, ClassElement, ClassElementKey, MethodDefinition,
+    MethodDefinitionKind, PrivateIdentifier, PropertyDefinition, StaticBlock,
+};
+use crate::ast_types::classes::class_declaration::ClassDeclaration;
+use crate::ast_types::classes::class_expression::ClassExpression;
+use crate::ast_types::declarations::declaration::Declaration;
+use crate::ast_types::declarations::function_declaration::{FunctionDeclaration, Parameter};
+use crate::ast_types::declarations::interface_declaration::{
+    InterfaceBody, InterfaceDeclaration, PropertySignature,
+};
+use crate::ast_types::declarations::type_alias_declaration::TypeAliasDeclaration;
+use crate::ast_types::declarations::variable_declaration::{
+    VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+};
+use crate::ast_types::expressions::types::{
     ArrayType, AstType, KeywordType, TypeAnnotation, TypeParameter, TypeParameterDeclaration,
     TypeReference,
 };
-use crate::expressions::{
-    ArrayExpression, ArrowFunctionExpression, AssignmentExpression, BinaryExpression,
-    BooleanLiteral, CallExpression, Expression, FunctionExpression, Identifier, Literal,
-    MemberExpression, NewExpression, NullLiteral, NumberLiteral, ObjectExpression,
-    ParenthesisExpression, RegexLiteral, StringLiteral, TernaryExpression, ThisExpression,
-    TypeofExpression, UnaryExpression, UpdateExpression, VariableKind,
+use crate::ast_types::expressions::{
+    ArrayElement, ArrayExpression, ArrowFunctionExpression, ArrowFunctionExpressionBody,
+    AssignmentExpression, AwaitExpression, BigIntLiteral, BinaryExpression, BooleanLiteral,
+    CallExpression, CallExpressionCallee, ConditionalExpression, Expression, FunctionExpression,
+    Literal, LogicalExpression, MemberExpression, NewExpression, NullLiteral, NumberLiteral,
+    ObjectExpression, ObjectExpressionProperty, ParenthesisExpression, RegexLiteral,
+    SequenceExpression, StringLiteral, Super, TaggedTemplateExpression, TemplateLiteral,
+    ThisExpression, TypeofExpression, UnaryExpression, UpdateExpression, YieldExpression,
+};
+use crate::ast_types::identifier::Identifier;
+use crate::ast_types::literal::{self, LiteralValue};
+use crate::ast_types::modules::exports::{
+    AnonymousDefaultExportedClassDeclaration, AnonymousDefaultExportedFunctionDeclaration,
+    ExportAllDeclaration, ExportDefaultDeclaration, ExportDefaultDeclarationDeclaration,
+    ExportNamedDeclaration, ExportSpecifier,
 };
-use crate::nodes::{program::Program, Node};
-use crate::statements::{
-    BlockStatement, BreakStatement, ContinueStatement, EnumMember, EnumStatement,
-    ExpressionStatement, ForStatement, FunctionDeclaration, IfStatement, Parameter,
-    ReturnStatement, Statement, ThrowStatement, VariableDeclaration, VariableDeclarator,
-    WhileStatement,
+use crate::ast_types::modules::import_or_export_declaration::ImportOrExportDeclaration;
+use crate::ast_types::modules::imports::{
+    ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
+    ImportNamespaceSpecifier, ImportOrExportKind, ImportSpecifier,
 };
+use crate::ast_types::node_objects::Node;
+use crate::ast_types::node_objects::Spanned;
+use crate::ast_types::patterns::array_pattern::ArrayPattern;
+use crate::ast_types::patterns::assignment_pattern::AssignmentPattern;
+use crate::ast_types::patterns::object_pattern::{
+    AssignmentProperty, ObjectPattern, ObjectPatternProperty,
+};
+use crate::ast_types::patterns::pattern::Pattern;
+use crate::ast_types::patterns::rest_element::RestElement;
+use crate::ast_types::programs::{Program, ProgramBody, SourceType};
+use crate::ast_types::property::{Property, PropertyKind};
+use crate::ast_types::spread_element::SpreadElement;
+use crate::ast_types::statements::{
+    BlockStatement, BreakStatement, CatchClause, ContinueStatement, DoWhileStatement,
+    EmptyStatement, EnumMember, EnumStatement, ExpressionStatement, ForInOrOfLeft, ForInStatement,
+    ForInit, ForOfStatement, ForStatement, FunctionBody, FunctionBodyBody, IfStatement,
+    LabeledStatement, ReturnStatement, Statement, SwitchCase, SwitchStatement, ThrowStatement,
+    TryStatement, WhileStatement,
+};
+use crate::ast_types::template_element::TemplateElement;
 use crate::utils::parser_error::{ParserError, ParserErrorInfo};
-use lexer::{AssignmentOperator, Keyword, Lexer, Operator, Token, TokenKind};
+use lexer::{
+    AssignmentOperator, BinaryOperator, Keyword, Lexer, LexerCheckpoint, Operator, Token,
+    TokenKind, TokenValue, UnaryOperator,
+};
+use std::collections::HashSet;
+use string_cache::DefaultAtom as Atom;
+
+/// A saved state of the parser, to go back to with `Parser::rewind`
+struct ParserCheckpoint {
+    lexer: LexerCheckpoint,
+    current_token: Token,
+    previous_token_end: usize,
+    errors_len: usize,
+    cover_initializers_len: usize,
+}
+
+/// The type parameters, `extends` clause with its type arguments, and `implements` clause of a class
+type ClassHeritage = (
+    Option<TypeParameterDeclaration>,
+    Option<Expression>,
+    Option<Vec<AstType>>,
+    Vec<TypeReference>,
+);
+
+/// What the function being parsed allows in its body, like `await` and `yield`
+#[derive(Debug, Clone, Copy, Default)]
+struct FunctionContext {
+    is_async: bool,
+    is_generator: bool,
+}
 
 pub struct Parser<'a> {
     source: &'a str,
     lexer: Lexer<'a>,
     current_token: Token,
+    /// The end of the last consumed token, which is where a node without a closing token ends
+    previous_token_end: usize,
+    /// The innermost function being parsed, `None` at the top level
+    function_context: Option<FunctionContext>,
+    /// Whether `in` is a binary operator here. It isn't in the init of a `for` statement,
+    /// so that `for (a in b)` is a for-in loop rather than a for loop that starts with `a in b`.
+    in_allowed: bool,
+    /// The start of every `(` that was tried as the head of an arrow function and wasn't one,
+    /// so that it isn't tried again when the parser goes back to before it
+    failed_arrow_heads: HashSet<usize>,
+    /// The `=` of every shorthand property with a default value, like `{ a = 1 }`, which is only
+    /// valid once the object literal turns out to be a pattern, like in `({ a = 1 } = obj)`
+    cover_initializers: Vec<Token>,
+    errors: Vec<ParserError>,
 }
 
 impl<'a> Parser<'a> {
@@ -33,11 +118,32 @@ impl<'a> Parser<'a> {
             source,
             lexer: Lexer::new(source),
             current_token: Token::default(),
+            previous_token_end: 0,
+            function_context: None,
+            in_allowed: true,
+            failed_arrow_heads: HashSet::new(),
+            cover_initializers: Vec::new(),
+            errors: Vec::new(),
         }
     }
 
+    /// Parses the whole source code, and returns the first syntax error if there are any.
+    /// Use `parse_with_errors` to get every syntax error in the source code.
     pub fn parse(&mut self) -> Result<Program, ParserError> {
-        let mut body: Vec<Statement> = Vec::new();
+        let (program, mut errors) = self.parse_with_errors();
+
+        if errors.is_empty() {
+            Ok(program)
+        } else {
+            Err(errors.swap_remove(0))
+        }
+    }
+
+    /// Parses the whole source code, recovering from syntax errors at statement boundaries.
+    /// Returns the statements that could be parsed, together with every syntax error that was found.
+    pub fn parse_with_errors(&mut self) -> (Program, Vec<ParserError>) {
+        let mut body: Vec<ProgramBody> = Vec::new();
+        let mut source_type = SourceType::Script;
         let source_len = self.source.len();
 
         // Initialize tokens
@@ -48,56 +154,399 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            let statement = self.parse_statement(true);
+            let stmt_start = self.current_token.start;
+
+            // Any import or export makes the source code a module
+            let item = if self.is_import_or_export() {
+                source_type = SourceType::Module;
+                self.parse_import_or_export_declaration()
+                    .map(ProgramBody::ImportOrExportDeclaration)
+            } else {
+                self.parse_statement(true).map(ProgramBody::Statement)
+            };
 
-            match statement {
-                Ok(s) => body.push(s),
+            match item {
+                Ok(item) => body.push(item),
                 Err(err) => {
-                    return Err(ParserError {
-                        id: err.id,
-                        kind: err.kind,
-                        token: self.current_token.clone(),
-                    })
+                    self.recover(err, stmt_start);
+
+                    // There is no block for a "}" to close at the top level
+                    if self.current_token.is(TokenKind::CloseBrace) {
+                        self.advance();
+                    }
                 }
             }
         }
 
-        Ok(Program {
+        // The object literals with a default value that didn't turn out to be patterns
+        for token in std::mem::take(&mut self.cover_initializers) {
+            self.errors.push(ParserError::new(
+                parser_error!(ShorthandPropertyDefault),
+                token,
+            ));
+        }
+
+        // Lexical errors are reported together with the syntax errors, in source order
+        let lex_errors = self.lexer.take_errors();
+        self.errors
+            .extend(lex_errors.into_iter().map(ParserError::from));
+        self.errors.sort_by_key(|err| err.token.start);
+
+        let program = Program {
             node: Node::new(0, source_len),
+            source_type,
             body,
-        })
+            comments: self.lexer.take_comments(),
+        };
+
+        (program, std::mem::take(&mut self.errors))
+    }
+
+    /// Records a syntax error and skips ahead to the start of the next statement,
+    /// so that parsing can continue after it.
+    fn recover(&mut self, err: ParserErrorInfo, stmt_start: usize) {
+        self.errors.push(ParserError::new(err, self.current_token));
+
+        // Always make progress, to avoid reporting the same error forever
+        if self.current_token.start == stmt_start && !self.current_token.is(TokenKind::CloseBrace) {
+            self.advance();
+        }
+
+        self.synchronize();
+    }
+
+    /// Skips tokens until a statement boundary is reached: after a `;`, before a `}`,
+    /// or before a keyword that starts a new statement.
+    fn synchronize(&mut self) {
+        loop {
+            match self.current_token.kind {
+                TokenKind::Eof | TokenKind::CloseBrace => return,
+                TokenKind::SemiColon => {
+                    self.advance(); // Consume ";" token
+                    return;
+                }
+                TokenKind::Keyword
+                    if matches!(
+                        self.current_token.value.as_keyword(),
+                        Some(
+                            Keyword::Var
+                                | Keyword::Let
+                                | Keyword::Const
+                                | Keyword::Function
+                                | Keyword::Class
+                                | Keyword::Abstract
+                                | Keyword::If
+                                | Keyword::For
+                                | Keyword::While
+                                | Keyword::Do
+                                | Keyword::Switch
+                                | Keyword::Try
+                                | Keyword::Return
+                                | Keyword::Throw
+                                | Keyword::Break
+                                | Keyword::Continue
+                                | Keyword::Enum
+                                | Keyword::Declare
+                                | Keyword::Interface
+                                | Keyword::Type
+                                | Keyword::Import
+                                | Keyword::Export
+                        )
+                    ) =>
+                {
+                    return
+                }
+                _ => self.advance(),
+            }
+        }
     }
 
     fn advance(&mut self) {
+        self.previous_token_end = self.current_token.end;
         self.current_token = self.lexer.next_token();
     }
 
+    /// The lexer takes a `/` after a `}` as the start of a regex, since most `}`s end a block.
+    /// After the `}` of an object literal, or of a function or class expression, it's a division.
+    fn relex_division_after_brace(&mut self) {
+        if self.current_token.is(TokenKind::RegexLiteral) {
+            if let Some(token) = self.lexer.relex_as_division(&self.current_token) {
+                self.current_token = token;
+            }
+        }
+    }
+
+    /// Saves the current state, so that the parser can later go back to it with `rewind`
+    fn checkpoint(&self) -> ParserCheckpoint {
+        ParserCheckpoint {
+            lexer: self.lexer.checkpoint(),
+            current_token: self.current_token,
+            previous_token_end: self.previous_token_end,
+            errors_len: self.errors.len(),
+            cover_initializers_len: self.cover_initializers.len(),
+        }
+    }
+
+    /// Goes back to a saved state, forgetting the errors found since then
+    fn rewind(&mut self, checkpoint: ParserCheckpoint) {
+        self.lexer.rewind(checkpoint.lexer);
+        self.current_token = checkpoint.current_token;
+        self.previous_token_end = checkpoint.previous_token_end;
+        self.errors.truncate(checkpoint.errors_len);
+        self.cover_initializers
+            .truncate(checkpoint.cover_initializers_len);
+    }
+
+    /// Speculatively parses with `parse`, for syntax that can only be told apart by parsing it,
+    /// like an arrow function and a parenthesized expression.
+    /// If it fails, the parser goes back to where it was, as if nothing was parsed.
+    fn try_parse<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParserErrorInfo>,
+    ) -> Option<T> {
+        let checkpoint = self.checkpoint();
+        match parse(self) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.rewind(checkpoint);
+                None
+            }
+        }
+    }
+
+    /// Parses the body of a function with `parse`, so that `await` and `yield` are only
+    /// recognized where that function allows them. Nested functions don't inherit the context.
+    fn with_function_context<T>(
+        &mut self,
+        context: FunctionContext,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParserErrorInfo>,
+    ) -> Result<T, ParserErrorInfo> {
+        let outer = self.function_context.replace(context);
+        let result = parse(self);
+        self.function_context = outer;
+        result
+    }
+
+    /// Parses with `parse` while `in` is or isn't allowed as a binary operator,
+    /// then goes back to what was allowed before.
+    fn with_in_allowed<T>(
+        &mut self,
+        in_allowed: bool,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParserErrorInfo>,
+    ) -> Result<T, ParserErrorInfo> {
+        let outer = std::mem::replace(&mut self.in_allowed, in_allowed);
+        let result = parse(self);
+        self.in_allowed = outer;
+        result
+    }
+
+    /// Whether `await` can be used here: in async functions, and at the top level.
+    /// A program is only known to be a module once an import or export is found,
+    /// so like TypeScript, `await` is accepted at the top level of scripts too.
+    fn is_await_allowed(&self) -> bool {
+        self.function_context.is_none_or(|context| context.is_async)
+    }
+
+    /// Whether the current `await` starts an await expression, rather than being an identifier.
+    /// Outside async functions, `await` is only an operator if its operand follows on the same line,
+    /// like `await x`, since `await(x)` and `await + 1` are valid uses of a variable named `await`.
+    fn is_await_expression(&mut self) -> bool {
+        if self
+            .function_context
+            .is_some_and(|context| context.is_async)
+        {
+            return true;
+        }
+
+        let next = self.lexer.peek_token();
+        if next.newline_before {
+            return false;
+        }
+
+        match next.kind {
+            TokenKind::Identifier
+            | TokenKind::String
+            | TokenKind::Number
+            | TokenKind::BigInt
+            | TokenKind::Boolean
+            | TokenKind::Null => true,
+            TokenKind::Keyword => next.value.as_keyword() != Some(Keyword::In),
+            TokenKind::Operator => matches!(next.value, TokenValue::Operator(Operator::Unary(_))),
+            _ => false,
+        }
+    }
+
+    /// Whether `yield` is an expression here, rather than an identifier
+    fn is_yield_allowed(&self) -> bool {
+        self.function_context
+            .is_some_and(|context| context.is_generator)
+    }
+
+    /// Whether the current token is the `async` of an async function, like `async function a() {}`.
+    /// A line break after `async` makes it an identifier, followed by an inserted `;`.
+    fn is_async_function(&mut self) -> bool {
+        if self.current_token.value.as_keyword() != Some(Keyword::Async) {
+            return false;
+        }
+
+        let peek = self.lexer.peek_token();
+        peek.value.as_keyword() == Some(Keyword::Function) && !peek.newline_before
+    }
+
     fn expect_token_kind(&self, kind: TokenKind) -> Result<(), ParserErrorInfo> {
         if self.current_token.kind == kind {
             Ok(())
+        } else if kind == TokenKind::Identifier {
+            Err(parser_error!(ExpectedIdentifier).with_expected(&[kind]))
         } else {
-            throw_error!(InvalidToken);
+            Err(parser_error!(InvalidToken).with_expected(&[kind]))
         }
     }
 
     fn expect_and_consume_token(&mut self, kind: TokenKind) -> Result<(), ParserErrorInfo> {
+        self.expect_token_kind(kind)?;
+        self.advance();
+        Ok(())
+    }
+
+    /// Consumes the token that closes a `(`, `[` or `{` which started at `open_start`.
+    /// If it's missing, the error points at both the current token and the unmatched opening token.
+    fn expect_closing_token(
+        &mut self,
+        kind: TokenKind,
+        open_start: usize,
+        err: ParserErrorInfo,
+    ) -> Result<(), ParserErrorInfo> {
         if self.current_token.kind == kind {
             self.advance();
-            Ok(())
-        } else {
-            throw_error!(InvalidToken);
+            return Ok(());
+        }
+
+        let open = match kind {
+            TokenKind::CloseParen => TokenKind::OpenParen,
+            TokenKind::CloseBracket => TokenKind::OpenBracket,
+            _ => TokenKind::OpenBrace,
+        };
+
+        Err(err.with_expected(&[kind]).with_label(
+            Node::new(open_start, open_start + 1),
+            format!("unmatched {} here", open),
+        ))
+    }
+
+    /// Whether the current token is the operator `op`, like `=` in `let a = 1` or `<` in `Array<T>`
+    fn is_operator(&self, op: impl Into<Operator>) -> bool {
+        self.current_token.value == TokenValue::Operator(op.into())
+    }
+
+    /// Consumes the `;` that ends a statement, and returns the end of the statement.
+    /// A missing `;` is inserted automatically before a `}`, a line break, or the end of the file.
+    /// Otherwise it's reported, and parsing continues as if it was there.
+    fn consume_semicolon(&mut self, end_pos: usize) -> usize {
+        if self.current_token.is(TokenKind::SemiColon) {
+            let end_pos = self.current_token.end;
+            self.advance(); // Consume ";" token
+            return end_pos;
+        }
+
+        if !self.can_insert_semicolon() {
+            self.errors.push(ParserError::new(
+                parser_error!(MissingSemicolon).with_expected(&[TokenKind::SemiColon]),
+                self.current_token,
+            ));
+        }
+
+        end_pos
+    }
+
+    /// Whether a statement can end before the current token without a `;`
+    fn can_insert_semicolon(&self) -> bool {
+        self.current_token.newline_before
+            || matches!(
+                self.current_token.kind,
+                TokenKind::CloseBrace | TokenKind::Eof
+            )
+    }
+
+    /// The keyword of the current token, which must be a `Keyword` token
+    fn current_keyword(&self) -> Result<Keyword, ParserErrorInfo> {
+        self.current_token
+            .value
+            .as_keyword()
+            .ok_or_else(|| parser_error!(InternalError))
+    }
+
+    /// The name of the current token, which must be an `Identifier` token
+    fn current_identifier(&self) -> Result<Atom, ParserErrorInfo> {
+        if !self.is_identifier() {
+            return Err(parser_error!(ExpectedIdentifier).with_expected(&[TokenKind::Identifier]));
+        }
+
+        Ok(Atom::from(self.current_token.identifier_name(self.source)))
+    }
+
+    /// Whether the current token can be an identifier, which includes contextual keywords like `type`
+    fn is_identifier(&self) -> bool {
+        self.current_token.is(TokenKind::Identifier)
+            || self
+                .current_token
+                .value
+                .as_keyword()
+                .is_some_and(|keyword| keyword.is_contextual())
+    }
+
+    /// Whether the current token is the identifier `name`, which is only a keyword in some places,
+    /// like `from` in import declarations. An escaped identifier is never a keyword.
+    fn is_contextual_keyword(&self, name: &str) -> bool {
+        self.current_token.is(TokenKind::Identifier) && self.current_token.text(self.source) == name
+    }
+
+    /// Joins the current `>` with the `>` and `>=` tokens right after it into a single operator,
+    /// like `>>`, `>>>` or `>>=`, which the lexer leaves apart for type arguments
+    fn rescan_greater_than(&mut self) {
+        if !self.is_operator(BinaryOperator::GreaterThan) {
+            return;
+        }
+
+        let mut count = 1;
+        let mut token = self.current_token;
+        while count < 3 {
+            let next = *self.lexer.peek_token();
+            if next.start != token.end || next.kind != TokenKind::Operator {
+                break;
+            }
+
+            let is_assignment = match next.value {
+                TokenValue::Operator(Operator::Binary(BinaryOperator::GreaterThan)) => false,
+                TokenValue::Operator(Operator::Binary(BinaryOperator::GreaterOrEquals)) => true,
+                _ => break,
+            };
+            self.lexer.next_token(); // Consume the joined token
+            count += 1;
+            token.end = next.end;
+            token.value = TokenValue::Operator(match (count, is_assignment) {
+                (2, false) => BinaryOperator::RightShift.into(),
+                (3, false) => BinaryOperator::ZeroFillRightShift.into(),
+                (2, true) => AssignmentOperator::RightShiftEquals.into(),
+                _ => AssignmentOperator::ZeroFillRightShiftEquals.into(),
+            });
+            if is_assignment {
+                break;
+            }
         }
+
+        self.current_token = token;
     }
 
     /// Parses a single statement (e.g., variable declarations, control flow statements, function definitions).
     fn parse_statement(&mut self, include_basic_semi: bool) -> Result<Statement, ParserErrorInfo> {
         match self.current_token.kind {
-            TokenKind::Keyword => match self.current_token.value.expect_keyword() {
+            TokenKind::Keyword => match self.current_keyword()? {
                 Keyword::Var | Keyword::Let | Keyword::Const => {
-                    if self.current_token.value.expect_keyword() == Keyword::Const {
+                    if self.current_keyword()? == Keyword::Const {
                         let peek = self.lexer.peek_token();
                         if peek.is(TokenKind::Keyword)
-                            && peek.value.expect_keyword() == Keyword::Enum
+                            && peek.value.as_keyword() == Some(Keyword::Enum)
                         {
                             return Ok(self.parse_enum_declaration(true, false)?.into());
                         }
@@ -105,64 +554,71 @@ impl<'a> Parser<'a> {
                     Ok(self.parse_variable_declaration(include_basic_semi)?.into())
                 }
                 Keyword::Function => Ok(self.parse_function_declaration(false)?.into()),
+                Keyword::Async if self.is_async_function() => {
+                    Ok(self.parse_function_declaration(true)?.into())
+                }
+                Keyword::Class => Ok(self.parse_class_declaration()?.into()),
+                Keyword::Abstract
+                    if self.lexer.peek_token().value.as_keyword() == Some(Keyword::Class) =>
+                {
+                    Ok(self.parse_class_declaration()?.into())
+                }
                 Keyword::Return => Ok(self.parse_return_statement()?.into()),
                 Keyword::If => Ok(self.parse_if_statement()?.into()),
                 Keyword::While => Ok(self.parse_while_statement()?.into()),
-                Keyword::For => Ok(self.parse_for_statement()?.into()),
+                Keyword::Do => Ok(self.parse_do_while_statement()?.into()),
+                Keyword::For => self.parse_for_statement(),
+                Keyword::Try => Ok(self.parse_try_statement()?.into()),
+                Keyword::Switch => Ok(self.parse_switch_statement()?.into()),
                 Keyword::Enum => Ok(self.parse_enum_declaration(false, false)?.into()),
                 Keyword::Declare => Ok(self.parse_enum_declaration(false, true)?.into()),
-                Keyword::Typeof => {
-                    let expr = self.parse_expression()?;
-                    Ok(ExpressionStatement {
-                        node: *expr.node(),
-                        expression: expr,
-                    }
-                    .into())
+                Keyword::Interface if self.lexer.peek_token().is(TokenKind::Identifier) => {
+                    Ok(self.parse_interface_declaration()?.into())
                 }
+                Keyword::Type if self.lexer.peek_token().is(TokenKind::Identifier) => {
+                    Ok(self.parse_type_alias_declaration()?.into())
+                }
+                Keyword::Typeof
+                | Keyword::New
+                | Keyword::This
+                | Keyword::Super
+                | Keyword::Async
+                | Keyword::Await => Ok(self.parse_expression_statement(include_basic_semi)?.into()),
                 Keyword::Throw => {
                     let start_pos = self.current_token.start;
                     self.advance(); // Consume "throw" token
-                    let expr = self.parse_expression()?;
-                    if self.current_token.is(TokenKind::SemiColon) {
-                        self.advance(); // Consume ";" token
+
+                    // No `;` can be inserted after `throw`, so a line break here is always an error
+                    if self.current_token.newline_before {
+                        throw_error!(NewlineAfterThrow);
                     }
+
+                    let argument = self.parse_sequence_expression()?;
+                    let end_pos = self.consume_semicolon(argument.node().end);
+
                     Ok(ThrowStatement {
-                        node: Node::new(start_pos, expr.node().end),
-                        expr,
-                    }
-                    .into())
-                }
-                Keyword::New | Keyword::This => {
-                    let expr = self.parse_expression()?;
-                    Ok(ExpressionStatement {
-                        node: *expr.node(),
-                        expression: expr,
+                        node: Node::new(start_pos, end_pos),
+                        argument,
                     }
                     .into())
                 }
                 Keyword::Continue => {
                     let start_pos = self.current_token.start;
                     let mut end_pos = self.current_token.end;
-                    self.advance(); // Consume "continue" token;
-
-                    let id = if self.current_token.is(TokenKind::Identifier) {
-                        end_pos = self.current_token.end;
-                        Some(Identifier {
-                            node: Node::new(self.current_token.start, self.current_token.end),
-                            name: self.current_token.value.expect_identifier().to_owned(),
-                        })
-                    } else {
-                        None
-                    };
+                    self.advance(); // Consume "continue" token
+
+                    let label = self.parse_statement_label()?;
+                    if let Some(label) = &label {
+                        end_pos = label.node.end;
+                    }
 
-                    if include_basic_semi && self.current_token.is(TokenKind::SemiColon) {
-                        end_pos = self.current_token.end;
-                        self.advance(); // Consume ';' token
+                    if include_basic_semi {
+                        end_pos = self.consume_semicolon(end_pos);
                     }
 
                     Ok(ContinueStatement {
                         node: Node::new(start_pos, end_pos),
-                        id,
+                        label,
                     }
                     .into())
                 }
@@ -171,113 +627,242 @@ impl<'a> Parser<'a> {
                     let mut end_pos = self.current_token.end;
                     self.advance(); // Consume "break" token
 
-                    let id = if self.current_token.is(TokenKind::Identifier) {
-                        end_pos = self.current_token.end;
-                        Some(Identifier {
-                            node: Node::new(self.current_token.start, self.current_token.end),
-                            name: self.current_token.value.expect_identifier().to_owned(),
-                        })
-                    } else {
-                        None
-                    };
+                    let label = self.parse_statement_label()?;
+                    if let Some(label) = &label {
+                        end_pos = label.node.end;
+                    }
 
-                    if include_basic_semi && self.current_token.is(TokenKind::SemiColon) {
-                        end_pos = self.current_token.end;
-                        self.advance(); // Consume ';' token
+                    if include_basic_semi {
+                        end_pos = self.consume_semicolon(end_pos);
                     }
 
                     Ok(BreakStatement {
                         node: Node::new(start_pos, end_pos),
-                        id,
+                        label,
                     }
                     .into())
                 }
-                _ => {
-                    // throw_error!(InvalidToken);
-                    use crate::utils::parser_error::{ErrorKind, ParserErrorInfo};
-                    let err = ParserErrorInfo {
-                        kind: ErrorKind::InvalidToken,
-                        #[cfg(debug_assertions)]
-                        id: concat!(file!(), ":", line!()).to_owned(),
-                    };
-                    return Err(err);
+                // Like `type = 1`, where `type` is an identifier
+                keyword if keyword.is_contextual() => {
+                    Ok(self.parse_expression_statement(include_basic_semi)?.into())
                 }
+                _ => throw_error!(ExpectedStatement),
             },
             TokenKind::OpenBrace => Ok(self.parse_block_statement()?.into()),
-            _ => {
-                let expr = self.parse_expression()?;
-                let end_pos = if self.current_token.is(TokenKind::SemiColon) && include_basic_semi {
-                    let pos = self.current_token.end;
-                    self.advance();
-                    pos
-                } else {
-                    expr.node().end
-                };
-
-                Ok(ExpressionStatement {
-                    node: Node::new(expr.node().start, end_pos),
-                    expression: expr,
-                }
-                .into())
+            TokenKind::SemiColon => {
+                let node = Node::new(self.current_token.start, self.current_token.end);
+                self.advance(); // Consume ";" token
+                Ok(EmptyStatement { node }.into())
+            }
+            TokenKind::Identifier if self.lexer.peek_token().is(TokenKind::Colon) => {
+                Ok(self.parse_labeled_statement()?.into())
             }
+            _ => Ok(self.parse_expression_statement(include_basic_semi)?.into()),
+        }
+    }
+
+    /// Parses the label after `break` or `continue`, if there is one
+    fn parse_statement_label(&mut self) -> Result<Option<Identifier>, ParserErrorInfo> {
+        // A label must be on the same line, otherwise a `;` is inserted before it
+        if !self.current_token.is(TokenKind::Identifier) || self.current_token.newline_before {
+            return Ok(None);
         }
+
+        let label = Identifier {
+            node: Node::new(self.current_token.start, self.current_token.end),
+            name: self.current_identifier()?,
+        };
+        self.advance(); // Consume Identifier token
+
+        Ok(Some(label))
+    }
+
+    /// Parses a statement with a label, like `outer: for (;;) {}`, which `break` and `continue` can refer to
+    fn parse_labeled_statement(&mut self) -> Result<LabeledStatement, ParserErrorInfo> {
+        let label = self.parse_binding_identifier()?;
+        self.advance(); // Consume ":" token
+
+        let body = self.parse_statement(true)?;
+
+        Ok(LabeledStatement {
+            node: Node::new(label.node.start, body.node().end),
+            label,
+            body,
+        })
+    }
+
+    /// Parses an expression that is used as a statement, like a function call.
+    fn parse_expression_statement(
+        &mut self,
+        include_semi: bool,
+    ) -> Result<ExpressionStatement, ParserErrorInfo> {
+        let expr = self.parse_sequence_expression()?;
+        let end_pos = if include_semi {
+            self.consume_semicolon(expr.node().end)
+        } else {
+            expr.node().end
+        };
+
+        Ok(ExpressionStatement {
+            node: Node::new(expr.node().start, end_pos),
+            expression: expr,
+        })
     }
 
     /// Parses an expression (e.g., arithmetic operations, logical operations, or function calls).
+    /// A `,` ends the expression, since it separates the items of lists like arguments.
     fn parse_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
-        let mut lhs = self.parse_primary_expression()?;
+        let expr = self.parse_binary_expression(0)?;
+
+        if self.current_token.is(TokenKind::QuestionMark) {
+            return Ok(self.parse_conditional_expression(expr)?.into());
+        }
+
+        match self.current_token.value {
+            TokenValue::Operator(Operator::Assignment(operator)) => {
+                Ok(self.parse_assignment_expression(expr, operator)?.into())
+            }
+            _ => Ok(expr),
+        }
+    }
+
+    /// Parses an expression where a comma makes a sequence, like `a = 1, b = 2`.
+    /// This is used where the expression can't be confused with a list, like in statements and `()`.
+    fn parse_sequence_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
+        let expression = self.parse_expression()?;
+        if !self.current_token.is(TokenKind::Comma) {
+            return Ok(expression);
+        }
+
+        let start_pos = expression.node().start;
+        let mut expressions = vec![expression];
+        while self.current_token.is(TokenKind::Comma) {
+            self.advance(); // Consume "," token
+            expressions.push(self.parse_expression()?);
+        }
+
+        Ok(SequenceExpression {
+            node: Node::new(start_pos, self.previous_token_end),
+            expressions,
+        }
+        .into())
+    }
+
+    /// Parses the branches of a conditional expression, like `a ? b : c`, once its test is parsed
+    fn parse_conditional_expression(
+        &mut self,
+        test: Expression,
+    ) -> Result<ConditionalExpression, ParserErrorInfo> {
+        self.advance(); // Consume "?" token
+
+        let consequent = self.parse_expression()?;
+        self.expect_and_consume_token(TokenKind::Colon)?;
+        let alternate = self.parse_expression()?;
+
+        Ok(ConditionalExpression {
+            node: Node::new(test.node().start, alternate.node().end),
+            test,
+            consequent,
+            alternate,
+        })
+    }
+
+    /// Parses a prefix operation, like `!a`, `-a`, `++a` or `typeof a`
+    fn parse_unary_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        if self.current_token.value == TokenValue::Keyword(Keyword::Await)
+            && self.is_await_expression()
+        {
+            return Ok(self.parse_await_expression()?.into());
+        }
+
+        let operator = match self.current_token.value {
+            TokenValue::Operator(Operator::Update(operator)) => {
+                self.advance(); // Consume Update operator token
+
+                let argument = self.parse_unary_expression()?;
+                return Ok(UpdateExpression {
+                    node: Node::new(start_pos, argument.node().end),
+                    operator,
+                    argument,
+                    prefix: true,
+                }
+                .into());
+            }
+            TokenValue::Operator(Operator::Unary(operator)) => operator,
+            // `+` and `-` are lexed as binary operators, since only the parser can tell them apart
+            TokenValue::Operator(Operator::Binary(BinaryOperator::Plus)) => UnaryOperator::Plus,
+            TokenValue::Operator(Operator::Binary(BinaryOperator::Minus)) => UnaryOperator::Minus,
+            TokenValue::Keyword(Keyword::Typeof) => {
+                return Ok(self.parse_typeof_expression()?.into())
+            }
+            // `void` and `delete` are lexed as identifiers, like `instanceof`
+            _ if self.is_contextual_keyword("void") => UnaryOperator::Void,
+            _ if self.is_contextual_keyword("delete") => UnaryOperator::Delete,
+            _ => return self.parse_postfix_expression(),
+        };
+        self.advance(); // Consume unary operator token
+
+        let argument = self.parse_unary_expression()?;
+        Ok(UnaryExpression {
+            node: Node::new(start_pos, argument.node().end),
+            operator,
+            prefix: true,
+            argument,
+        }
+        .into())
+    }
+
+    /// Parses a postfix `++` or `--`, like `a++`
+    fn parse_postfix_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
+        let argument = self.parse_left_hand_side_expression()?;
+
+        let TokenValue::Operator(Operator::Update(operator)) = self.current_token.value else {
+            return Ok(argument);
+        };
+
+        // A `;` is inserted before a `++` or `--` on the next line, making it a prefix of what follows
+        if self.current_token.newline_before {
+            return Ok(argument);
+        }
+
+        let expr = UpdateExpression {
+            node: Node::new(argument.node().start, self.current_token.end),
+            operator,
+            argument,
+            prefix: false,
+        };
+        self.advance(); // Consume Update operator token
+
+        Ok(expr.into())
+    }
+
+    /// Parses calls, member accesses and tagged templates, like `a.b(c)[d]`
+    fn parse_left_hand_side_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
+        // `in` is an operator again inside brackets and functions, like in `for (let a = (b in c); ;)`
+        let mut lhs = self.with_in_allowed(true, |p| p.parse_primary_expression())?;
 
         loop {
             match self.current_token.kind {
                 TokenKind::OpenParen => {
-                    let call_exp = self.parse_call_expression(lhs)?;
-                    lhs = Expression::CallExpression(Box::new(call_exp));
+                    lhs = self
+                        .with_in_allowed(true, |p| p.parse_call_expression(lhs))?
+                        .into();
                 }
                 TokenKind::Dot | TokenKind::OpenBracket => {
-                    let mem_exp = self.parse_member_expression(lhs)?;
-                    lhs = Expression::MemberExpression(Box::new(mem_exp));
-                }
-                TokenKind::Increment | TokenKind::Decrement => {
-                    let op = self
-                        .current_token
-                        .kind
-                        .as_operator()
-                        .unwrap() // Safe unwrap because of match statement
-                        .as_update_operator()
-                        .unwrap(); // Safe unwrap because of match statement
-                    let expr = UpdateExpression {
-                        node: Node::new(lhs.node().start, self.current_token.end),
-                        argument: lhs,
-                        operator: op,
-                        prefix: false,
-                    };
-                    self.advance(); // Consume Update operator token
-                    lhs = expr.into();
+                    lhs = self
+                        .with_in_allowed(true, |p| p.parse_member_expression(lhs))?
+                        .into();
                 }
-                TokenKind::QuestionMark => {
-                    self.advance();
-                    let truthy_expr = self.parse_expression()?;
-                    self.expect_and_consume_token(TokenKind::Colon)?;
-                    let falsy_expr = self.parse_expression()?;
-                    lhs = TernaryExpression {
-                        node: Node::new(lhs.node().start, falsy_expr.node().end),
-                        truthy_expr: Box::new(truthy_expr),
-                        falsy_expr: Box::new(falsy_expr),
+                TokenKind::NoSubstitutionTemplate | TokenKind::TemplateHead => {
+                    let quasi = self.parse_template_literal(true)?;
+                    lhs = TaggedTemplateExpression {
+                        node: Node::new(lhs.node().start, quasi.node.end),
+                        tag: lhs,
+                        quasi,
                     }
                     .into();
-                    break;
-                }
-                // TokenKind::SemiColon => {
-                //     self.advance(); // Comsume ';' token
-                //     break;
-                // }
-                _ if self.current_token.kind.is_assignment_operator() => {
-                    let expr = self.parse_assignment_expression(lhs)?;
-                    lhs = Expression::AssignmentExpression(Box::new(expr));
-                }
-                _ if self.current_token.kind.is_operator() => {
-                    let bin_exp = self.parse_binary_expression(Some(lhs), 0)?;
-                    lhs = Expression::BinaryExpression(Box::new(bin_exp));
                 }
                 _ => break,
             }
@@ -286,69 +871,86 @@ impl<'a> Parser<'a> {
         Ok(lhs)
     }
 
-    /// Parses literal values, such as numbers, strings, booleans, null, arrays, objects, member expressions, and parenthesised expressions
+    /// Parses literal values, such as numbers, strings, booleans, null, arrays, objects, and parenthesised expressions
     fn parse_primary_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
         match self.current_token.kind {
-            TokenKind::String | TokenKind::Boolean | TokenKind::Number | TokenKind::Null => {
-                Ok(self.parse_literal()?.into())
+            TokenKind::String
+            | TokenKind::Boolean
+            | TokenKind::Number
+            | TokenKind::BigInt
+            | TokenKind::Null => Ok(self.parse_literal()?.into()),
+            TokenKind::Identifier
+                if self.is_yield_allowed() && self.is_contextual_keyword("yield") =>
+            {
+                Ok(self.parse_yield_expression()?.into())
             }
             TokenKind::Identifier => {
+                // A single parameter doesn't need parentheses, like in `x => x`
+                let peek = self.lexer.peek_token();
+                if peek.is(TokenKind::ArrowFn) && !peek.newline_before {
+                    let start_pos = self.current_token.start;
+                    let parameters = self.parse_arrow_function_parameter()?;
+                    let arr_fn_expr =
+                        self.parse_arrow_function(start_pos, parameters, None, false)?;
+                    return Ok(Expression::ArrowFunctionExpression(Box::new(arr_fn_expr)));
+                }
+
                 let identifier = Identifier {
                     node: Node::new(self.current_token.start, self.current_token.end),
-                    name: self.current_token.value.expect_identifier().clone(),
+                    name: self.current_identifier()?,
                 };
                 self.advance(); // Consume Identifier token
-
-                if self.current_token.is(TokenKind::Dot) {
-                    Ok(self.parse_member_expression(identifier.into())?.into())
-                } else {
-                    Ok(identifier.into())
-                }
+                Ok(identifier.into())
             }
             TokenKind::OpenParen => {
-                // Peek forward to determine if is an arrow function
-                // Only consumes tokens if it is an arrow function
-                if self.peek_is_arrow_function() {
-                    let arr_fn_expr = self.parse_arrow_function()?;
+                let start_pos = self.current_token.start;
+
+                // It's only known to be an arrow function once the `=>` is found,
+                // which can be arbitrarily far ahead, like in `(a: number, b?: string): void =>`
+                if let Some((parameters, return_type)) = self.try_parse_arrow_function_head(false) {
+                    let arr_fn_expr =
+                        self.parse_arrow_function(start_pos, parameters, return_type, false)?;
                     return Ok(Expression::ArrowFunctionExpression(Box::new(arr_fn_expr)));
                 }
 
-                let start_pos = self.current_token.start;
                 self.advance(); // Consume "(" token
 
-                let expression = self.parse_expression()?;
+                let expression = self.parse_sequence_expression()?;
 
                 let paren_expr = ParenthesisExpression {
                     node: Node::new(start_pos, self.current_token.end),
                     expression,
                 };
 
-                self.expect_and_consume_token(TokenKind::CloseParen)?;
+                self.expect_closing_token(
+                    TokenKind::CloseParen,
+                    start_pos,
+                    parser_error!(UnclosedParen),
+                )?;
 
                 Ok(paren_expr.into())
             }
             TokenKind::OpenBracket => Ok(self.parse_array_literal()?.into()),
-            TokenKind::OpenBrace => Ok(self.parse_object_literal()?.into()),
-            TokenKind::Exclamation | TokenKind::Plus | TokenKind::Minus => {
-                let unary_kind = match self.current_token.kind {
-                    TokenKind::Exclamation => UnaryKind::Not,
-                    TokenKind::Plus => UnaryKind::Plus,
-                    TokenKind::Minus => UnaryKind::Minus,
-                    _ => unreachable!(),
-                };
-                let start_pos = self.current_token.start;
-                self.advance(); // Consume unary token
-                let expr = self.parse_expression()?;
-                Ok(UnaryExpression {
-                    node: Node::new(start_pos, expr.node().end),
-                    kind: unary_kind,
-                    expression: Box::new(expr),
-                }
-                .into())
+            TokenKind::OpenBrace => {
+                let object = self.parse_object_literal()?;
+                self.relex_division_after_brace();
+                Ok(object.into())
+            }
+            TokenKind::NoSubstitutionTemplate | TokenKind::TemplateHead => {
+                Ok(self.parse_template_literal(false)?.into())
             }
-            TokenKind::Keyword => match self.current_token.value.expect_keyword() {
-                Keyword::Function => Ok(self.parse_function_expression(false)?.into()),
-                Keyword::Typeof => Ok(self.parse_typeof_expression()?.into()),
+            TokenKind::Keyword => match self.current_keyword()? {
+                Keyword::Function => {
+                    let function = self.parse_function_expression(false)?;
+                    self.relex_division_after_brace();
+                    Ok(function.into())
+                }
+                Keyword::Async => self.parse_async_expression(),
+                Keyword::Class => {
+                    let class = self.parse_class_expression()?;
+                    self.relex_division_after_brace();
+                    Ok(class.into())
+                }
                 Keyword::New => Ok(self.parse_new_expression()?.into()),
                 Keyword::This => {
                     let expr = ThisExpression {
@@ -357,29 +959,32 @@ impl<'a> Parser<'a> {
                     self.advance(); // Consume "this" token
                     Ok(expr.into())
                 }
-                _ => throw_error!(InvalidToken),
+                Keyword::Super => {
+                    let expr = Super {
+                        node: Node::new(self.current_token.start, self.current_token.end),
+                    };
+                    self.advance(); // Consume "super" token
+                    Ok(expr.into())
+                }
+                // Outside async functions, `await` can be a plain identifier, like in `await(x)`
+                Keyword::Await => {
+                    let identifier = Identifier {
+                        node: Node::new(self.current_token.start, self.current_token.end),
+                        name: Atom::from("await"),
+                    };
+                    self.advance(); // Consume "await" token
+                    Ok(identifier.into())
+                }
+                keyword if keyword.is_contextual() => Ok(self.parse_binding_identifier()?.into()),
+                _ => throw_error!(ExpectedExpression),
             },
             TokenKind::RegexLiteral => {
                 let node = Node::new(self.current_token.start, self.current_token.end);
-                let value = self.current_token.value.consume_regex();
+                let value = self.current_token.regex_value(self.source);
                 self.advance(); // Consume Regex token
-                Ok(Literal::RegexLiteral(RegexLiteral { node, value }).into())
+                Ok(RegexLiteral { node, value }.into())
             }
-            _ => throw_error!(InvalidToken),
-        }
-    }
-
-    /// Peeks forward in the token stream
-    fn peek_is_arrow_function(&mut self) -> bool {
-        match self.lexer.peek_token_at(0).kind {
-            TokenKind::Identifier => {}
-            TokenKind::CloseParen => return true,
-            _ => return false,
-        }
-        match self.lexer.peek_token_at(1).kind {
-            TokenKind::Colon | TokenKind::Comma => true,
-            TokenKind::CloseParen => self.lexer.peek_token_at(2).is(TokenKind::ArrowFn),
-            _ => false,
+            _ => throw_error!(ExpectedExpression),
         }
     }
 
@@ -388,16 +993,26 @@ impl<'a> Parser<'a> {
         let start_pos = self.current_token.start;
         self.expect_and_consume_token(TokenKind::OpenBrace)?;
 
-        let mut statements: Vec<Statement> = Vec::new();
+        let mut body: Vec<Statement> = Vec::new();
 
         while self.current_token.kind != TokenKind::CloseBrace {
-            let stmt = self.parse_statement(true)?;
-            statements.push(stmt);
+            if self.current_token.is(TokenKind::Eof) {
+                return Err(parser_error!(UnclosedBrace)
+                    .with_expected(&[TokenKind::CloseBrace])
+                    .with_label(Node::new(start_pos, start_pos + 1), "unmatched `{` here"));
+            }
+
+            let stmt_start = self.current_token.start;
+
+            match self.parse_statement(true) {
+                Ok(stmt) => body.push(stmt),
+                Err(err) => self.recover(err, stmt_start),
+            }
         }
 
         let block = BlockStatement {
             node: Node::new(start_pos, self.current_token.end),
-            statements,
+            body,
         };
 
         self.advance(); // Consume "}" token
@@ -405,6 +1020,20 @@ impl<'a> Parser<'a> {
         Ok(block)
     }
 
+    /// Parses the body of a function, which is a block of statements
+    fn parse_function_body(&mut self) -> Result<FunctionBody, ParserErrorInfo> {
+        let block = self.parse_block_statement()?;
+
+        Ok(FunctionBody {
+            node: block.node,
+            body: block
+                .body
+                .into_iter()
+                .map(FunctionBodyBody::Statement)
+                .collect(),
+        })
+    }
+
     /// Parses a variable declaration, including `let`, `const`, or `var` keywords.
     fn parse_variable_declaration(
         &mut self,
@@ -412,10 +1041,10 @@ impl<'a> Parser<'a> {
     ) -> Result<VariableDeclaration, ParserErrorInfo> {
         let start_pos = self.current_token.start;
 
-        let kind = match self.current_token.value.expect_keyword() {
-            Keyword::Var => VariableKind::Var,
-            Keyword::Let => VariableKind::Let,
-            Keyword::Const => VariableKind::Const,
+        let kind = match self.current_keyword()? {
+            Keyword::Var => VariableDeclarationKind::Var,
+            Keyword::Let => VariableDeclarationKind::Let,
+            Keyword::Const => VariableDeclarationKind::Const,
             _ => unreachable!(),
         };
         self.advance();
@@ -425,14 +1054,9 @@ impl<'a> Parser<'a> {
 
         loop {
             let start = self.current_token.start;
-            end_pos = self.current_token.end;
 
-            self.expect_token_kind(TokenKind::Identifier)?;
-            let identifier = Identifier {
-                node: Node::new(start, self.current_token.end),
-                name: self.current_token.value.expect_identifier().clone(),
-            };
-            self.advance(); // Consume Identifier token
+            let id = self.parse_binding_pattern()?;
+            end_pos = id.node().end;
 
             let type_annotation = if self.current_token.is(TokenKind::Colon) {
                 let ann = self.parse_type_annotation()?;
@@ -442,18 +1066,23 @@ impl<'a> Parser<'a> {
                 None
             };
 
-            let init = if self.current_token.is(TokenKind::Equals) {
+            let init = if self.is_operator(AssignmentOperator::Assign) {
                 self.advance(); // Consume "=" token
                 let expr = self.parse_expression()?;
                 end_pos = expr.node().end;
                 Some(expr)
+            } else if include_semi && !matches!(id, Pattern::Identifier(_)) {
+                // The value to destructure can only be left out in the head of a `for-in` or `for-of` loop
+                return Err(parser_error!(MissingInitializer)
+                    .with_expected(&[TokenKind::Operator])
+                    .with_label(*id.node(), "destructured here"));
             } else {
                 None
             };
 
             let decl = VariableDeclarator {
                 node: Node::new(start, end_pos),
-                id: identifier,
+                id,
                 type_annotation,
                 init,
             };
@@ -466,9 +1095,8 @@ impl<'a> Parser<'a> {
             self.advance(); // Consume "," token
         }
 
-        if include_semi && self.current_token.is(TokenKind::SemiColon) {
-            end_pos = self.current_token.end;
-            self.advance() // Consume ";" token
+        if include_semi {
+            end_pos = self.consume_semicolon(end_pos);
         }
 
         Ok(VariableDeclaration {
@@ -485,26 +1113,25 @@ impl<'a> Parser<'a> {
     ) -> Result<FunctionDeclaration, ParserErrorInfo> {
         let start_pos = self.current_token.start;
 
+        if is_async {
+            self.advance(); // Consume "async" token
+        }
         self.advance(); // Consume "function" token
 
-        self.expect_token_kind(TokenKind::Identifier)?;
-        let id = Identifier {
-            node: Node::new(self.current_token.start, self.current_token.end),
-            name: self.current_token.value.expect_identifier().clone(),
-        };
-        self.advance(); // Consume Identifier token
+        let is_generator = self.is_operator(BinaryOperator::Mult);
+        if is_generator {
+            self.advance(); // Consume "*" token
+        }
 
-        let type_parameters = if self.current_token.is(TokenKind::LessThan) {
+        // The name belongs to the enclosing function, so it follows its rules for `await` and `yield`
+        let id = self.parse_binding_identifier()?;
+
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
             Some(self.parse_type_parameter_declaration()?)
         } else {
             None
         };
 
-        let is_generator = self.current_token.is(TokenKind::Asterisk);
-        if is_generator {
-            self.advance(); // Consume "*" token
-        }
-
         self.expect_token_kind(TokenKind::OpenParen)?;
 
         let params = self.parse_parameter_list()?;
@@ -515,21 +1142,25 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let body = self.parse_block_statement()?;
+        let context = FunctionContext {
+            is_async,
+            is_generator,
+        };
+        let body = self.with_function_context(context, |p| p.parse_function_body())?;
 
         Ok(FunctionDeclaration {
             node: Node::new(start_pos, body.node.end),
             id,
+            generator: is_generator,
+            _async: is_async,
             type_parameters,
             params,
             return_type,
-            is_generator,
-            is_async,
             body,
         })
     }
 
-    /// Parses a function declaration, including its name, parameters, and body.
+    /// Parses a function expression, including its optional name, parameters, and body.
     fn parse_function_expression(
         &mut self,
         is_async: bool,
@@ -539,9 +1170,12 @@ impl<'a> Parser<'a> {
         let mut return_type: Option<TypeAnnotation> = None;
         let mut id: Option<Identifier> = None;
 
+        if is_async {
+            self.advance(); // Consume "async" token
+        }
         self.advance(); // Consume "function" keyword token
 
-        if self.current_token.is(TokenKind::Asterisk) {
+        if self.is_operator(BinaryOperator::Mult) {
             is_generator = true;
             self.advance(); // Consume "*" token
         }
@@ -549,11 +1183,17 @@ impl<'a> Parser<'a> {
         if self.current_token.is(TokenKind::Identifier) {
             id = Some(Identifier {
                 node: Node::new(self.current_token.start, self.current_token.end),
-                name: self.current_token.value.expect_identifier().clone(),
+                name: self.current_identifier()?,
             });
             self.advance(); // Consume Identifier token
         }
 
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
+            Some(self.parse_type_parameter_declaration()?)
+        } else {
+            None
+        };
+
         self.expect_token_kind(TokenKind::OpenParen)?;
 
         let params = self.parse_parameter_list()?;
@@ -563,24 +1203,30 @@ impl<'a> Parser<'a> {
             return_type = Some(self.parse_type_annotation()?);
         }
 
-        let body = self.parse_block_statement()?;
+        let context = FunctionContext {
+            is_async,
+            is_generator,
+        };
+        let body = self.with_function_context(context, |p| p.parse_function_body())?;
 
         Ok(FunctionExpression {
             node: Node::new(start_pos, body.node.end),
             id,
+            generator: is_generator,
+            _async: is_async,
+            type_parameters,
             params,
             return_type,
-            body,
-            is_generator,
-            is_async,
+            body: Some(body),
         })
     }
 
-    fn parse_method(&mut self) -> Result<Method, ParserErrorInfo> {
+    /// Parses a method of an object literal, like `a() {}`, which is a property whose value is a function
+    fn parse_method(&mut self) -> Result<Property, ParserErrorInfo> {
         let start_pos = self.current_token.start;
 
         let is_async = if self.current_token.is(TokenKind::Keyword)
-            && self.current_token.value.expect_keyword() == Keyword::Async
+            && self.current_token.value.as_keyword() == Some(Keyword::Async)
         {
             self.advance(); // Consume "async" keyword
             true
@@ -588,7 +1234,7 @@ impl<'a> Parser<'a> {
             false
         };
 
-        let is_generator = if self.current_token.is(TokenKind::Asterisk) {
+        let is_generator = if self.is_operator(BinaryOperator::Mult) {
             self.advance(); // Consume "*" token
             true
         } else {
@@ -596,19 +1242,22 @@ impl<'a> Parser<'a> {
         };
 
         self.expect_token_kind(TokenKind::Identifier)?;
-        let id = Identifier {
+        let key = Identifier {
             node: Node::new(self.current_token.start, self.current_token.end),
-            name: self.current_token.value.expect_identifier().clone(),
+            name: self.current_identifier()?,
         };
         self.advance(); // Consume Identifier token
 
-        let type_parameters = if self.current_token.is(TokenKind::LessThan) {
+        // The function starts at its parameters, like in ESTree
+        let value_start = self.current_token.start;
+
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
             Some(self.parse_type_parameter_declaration()?)
         } else {
             None
         };
 
-        let parameters = self.parse_parameter_list()?;
+        let params = self.parse_parameter_list()?;
 
         let return_type = if self.current_token.is(TokenKind::Colon) {
             Some(self.parse_type_annotation()?)
@@ -616,23 +1265,69 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let body = self.parse_block_statement()?;
-
-        Ok(Method {
-            node: Node::new(start_pos, body.node.end),
+        let context = FunctionContext {
             is_async,
             is_generator,
-            id,
-            type_parameters,
-            parameters,
-            return_type,
-            body,
+        };
+        let body = self.with_function_context(context, |p| p.parse_function_body())?;
+        let end_pos = body.node.end;
+
+        Ok(Property {
+            node: Node::new(start_pos, end_pos),
+            key: key.into(),
+            value: FunctionExpression {
+                node: Node::new(value_start, end_pos),
+                id: None,
+                generator: is_generator,
+                _async: is_async,
+                type_parameters,
+                params,
+                return_type,
+                body: Some(body),
+            }
+            .into(),
+            kind: PropertyKind::Init,
+            method: true,
+            shorthand: false,
+            computed: false,
         })
     }
 
-    fn parse_arrow_function(&mut self) -> Result<ArrowFunctionExpression, ParserErrorInfo> {
-        let start_pos = self.current_token.start;
-        let parameters = self.parse_parameter_list()?;
+    /// Speculatively parses the head of an arrow function, after `async` if `is_async`.
+    /// Each `(` is only tried once, otherwise nested parentheses like `(a = (b = (c = 1)))`
+    /// would be parsed an exponential number of times.
+    fn try_parse_arrow_function_head(
+        &mut self,
+        is_async: bool,
+    ) -> Option<(Vec<Parameter>, Option<TypeAnnotation>)> {
+        let paren_start = if is_async {
+            self.lexer.peek_token().start
+        } else {
+            self.current_token.start
+        };
+
+        if self.failed_arrow_heads.contains(&paren_start) {
+            return None;
+        }
+
+        let head = self.try_parse(|p| {
+            if is_async {
+                p.advance(); // Consume "async" token
+            }
+            p.parse_arrow_function_head()
+        });
+
+        if head.is_none() {
+            self.failed_arrow_heads.insert(paren_start);
+        }
+        head
+    }
+
+    /// Parses the parameters and the return type of an arrow function, up to and including its `=>`
+    fn parse_arrow_function_head(
+        &mut self,
+    ) -> Result<(Vec<Parameter>, Option<TypeAnnotation>), ParserErrorInfo> {
+        let params = self.parse_parameter_list()?;
 
         let return_type = if self.current_token.is(TokenKind::Colon) {
             Some(self.parse_type_annotation()?)
@@ -640,32 +1335,224 @@ impl<'a> Parser<'a> {
             None
         };
 
+        // A line break can't come before the `=>`, so `(a)\n=> a` isn't an arrow function
+        if self.current_token.newline_before {
+            throw_error!(InvalidToken);
+        }
+        self.expect_and_consume_token(TokenKind::ArrowFn)?;
+
+        Ok((params, return_type))
+    }
+
+    /// Parses the only parameter of an arrow function without parentheses,
+    /// like the `x` of `x => x`, up to and including the `=>`
+    fn parse_arrow_function_parameter(&mut self) -> Result<Vec<Parameter>, ParserErrorInfo> {
+        let id = self.parse_binding_identifier()?;
+
+        if self.current_token.newline_before {
+            throw_error!(InvalidToken);
+        }
         self.expect_and_consume_token(TokenKind::ArrowFn)?;
-        let body = self.parse_statement(false)?;
+
+        Ok(vec![Parameter {
+            node: id.node,
+            pattern: id.into(),
+            type_annotation: None,
+            optional: false,
+            accessibility: None,
+            readonly: false,
+            _override: false,
+        }])
+    }
+
+    /// Parses the body of an arrow function, after its `=>`
+    fn parse_arrow_function(
+        &mut self,
+        start_pos: usize,
+        params: Vec<Parameter>,
+        return_type: Option<TypeAnnotation>,
+        is_async: bool,
+    ) -> Result<ArrowFunctionExpression, ParserErrorInfo> {
+        // Arrow functions can't be generators
+        let context = FunctionContext {
+            is_async,
+            is_generator: false,
+        };
+        // The body is either a block, or a single expression which is returned
+        let body = self.with_function_context(context, |p| {
+            if p.current_token.is(TokenKind::OpenBrace) {
+                Ok(ArrowFunctionExpressionBody::FunctionBody(
+                    p.parse_function_body()?,
+                ))
+            } else {
+                Ok(ArrowFunctionExpressionBody::Expression(
+                    p.parse_expression()?,
+                ))
+            }
+        })?;
 
         Ok(ArrowFunctionExpression {
             node: Node::new(start_pos, body.node().end),
-            parameters,
+            id: None,
+            params,
             return_type,
+            expression: matches!(body, ArrowFunctionExpressionBody::Expression(_)),
             body,
+            _async: is_async,
+        })
+    }
+
+    /// Parses an expression that starts with `async`: an async function or an async arrow function.
+    /// Anything else makes `async` a plain identifier, like in `async(1)`.
+    fn parse_async_expression(&mut self) -> Result<Expression, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        if self.is_async_function() {
+            let function = self.parse_function_expression(true)?;
+            self.relex_division_after_brace();
+            return Ok(function.into());
+        }
+
+        let peek = self.lexer.peek_token();
+        if peek.is(TokenKind::OpenParen) && !peek.newline_before {
+            if let Some((parameters, return_type)) = self.try_parse_arrow_function_head(true) {
+                let arr_fn_expr =
+                    self.parse_arrow_function(start_pos, parameters, return_type, true)?;
+                return Ok(Expression::ArrowFunctionExpression(Box::new(arr_fn_expr)));
+            }
+        } else if peek.is(TokenKind::ArrowFn) && !peek.newline_before {
+            // `async` is the name of the parameter, like in `async => async`
+            let parameters = self.parse_arrow_function_parameter()?;
+            let arr_fn_expr = self.parse_arrow_function(start_pos, parameters, None, false)?;
+            return Ok(Expression::ArrowFunctionExpression(Box::new(arr_fn_expr)));
+        } else if peek.is(TokenKind::Identifier) && !peek.newline_before {
+            // Like `async x => x`, otherwise `async` is followed by an inserted `;` or an error
+            if let Some(parameters) = self.try_parse(|p| {
+                p.advance(); // Consume "async" token
+                p.parse_arrow_function_parameter()
+            }) {
+                let arr_fn_expr = self.parse_arrow_function(start_pos, parameters, None, true)?;
+                return Ok(Expression::ArrowFunctionExpression(Box::new(arr_fn_expr)));
+            }
+        }
+
+        let identifier = Identifier {
+            node: Node::new(start_pos, self.current_token.end),
+            name: Atom::from("async"),
+        };
+        self.advance(); // Consume "async" token
+        Ok(identifier.into())
+    }
+
+    /// Parses `await promise`, which is only allowed in async functions and at the top level
+    fn parse_await_expression(&mut self) -> Result<AwaitExpression, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        if !self.is_await_allowed() {
+            return Err(parser_error!(AwaitOutsideAsync).with_label(
+                Node::new(start_pos, self.current_token.end),
+                "the enclosing function is not async",
+            ));
+        }
+        self.advance(); // Consume "await" token
+
+        // `await` binds like a unary operator, so `await a + b` is `(await a) + b`
+        let argument = self.parse_unary_expression()?;
+        Ok(AwaitExpression {
+            node: Node::new(start_pos, argument.node().end),
+            argument,
+        })
+    }
+
+    /// Parses `yield value` and `yield* iterable` in the body of a generator
+    fn parse_yield_expression(&mut self) -> Result<YieldExpression, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        let mut end_pos = self.current_token.end;
+        self.advance(); // Consume "yield" token
+
+        let delegate = self.is_operator(BinaryOperator::Mult) && !self.current_token.newline_before;
+        if delegate {
+            end_pos = self.current_token.end;
+            self.advance(); // Consume "*" token
+        }
+
+        // The value is optional, like in `yield;` and `f(yield, 1)`, except after `yield*`
+        let has_argument = delegate
+            || !(self.can_insert_semicolon()
+                || matches!(
+                    self.current_token.kind,
+                    TokenKind::SemiColon
+                        | TokenKind::CloseParen
+                        | TokenKind::CloseBracket
+                        | TokenKind::Comma
+                        | TokenKind::Colon
+                ));
+
+        let argument = if has_argument {
+            let expr = self.parse_expression()?;
+            end_pos = expr.node().end;
+            Some(expr)
+        } else {
+            None
+        };
+
+        Ok(YieldExpression {
+            node: Node::new(start_pos, end_pos),
+            argument,
+            delegate,
         })
     }
 
     fn parse_parameter_list(&mut self) -> Result<Vec<Parameter>, ParserErrorInfo> {
+        let open_paren = Node::new(self.current_token.start, self.current_token.end);
         self.expect_and_consume_token(TokenKind::OpenParen)?;
 
         let mut params: Vec<Parameter> = Vec::new();
 
         while self.current_token.kind != TokenKind::CloseParen {
             let start_pos = self.current_token.start;
-            self.expect_token_kind(TokenKind::Identifier)?;
-            let id = self.current_token.value.expect_identifier().clone();
-            let identifier = Identifier {
-                node: Node::new(self.current_token.start, self.current_token.end),
-                name: id,
-            };
-            let mut end_pos = self.current_token.end;
-            self.advance(); // Consume Identifier token
+
+            // A constructor can declare fields with its parameters, like `private readonly a: number`.
+            // A modifier is only a modifier if a name follows it, otherwise it's the name itself.
+            let mut accessibility = None;
+            let mut is_readonly = false;
+            let mut is_override = false;
+            while matches!(
+                self.current_token.kind,
+                TokenKind::Identifier | TokenKind::Keyword
+            ) && matches!(
+                self.lexer.peek_token().kind,
+                TokenKind::Identifier
+                    | TokenKind::Keyword
+                    | TokenKind::OpenBrace
+                    | TokenKind::OpenBracket
+            ) {
+                match self.current_token.text(self.source) {
+                    "public" => accessibility = Some(Accessibility::Public),
+                    "private" => accessibility = Some(Accessibility::Private),
+                    "protected" => accessibility = Some(Accessibility::Protected),
+                    "readonly" => is_readonly = true,
+                    "override" => is_override = true,
+                    _ => break,
+                }
+                self.advance(); // Consume modifier token
+            }
+
+            let is_rest = self.current_token.is(TokenKind::Ellipsis);
+            if is_rest {
+                self.advance(); // Consume "..." token
+            }
+
+            let mut pattern = self.parse_binding_pattern()?;
+            let mut end_pos = pattern.node().end;
+
+            if is_rest {
+                pattern = RestElement {
+                    node: Node::new(start_pos, end_pos),
+                    argument: pattern,
+                }
+                .into();
+            }
 
             let optional = if self.current_token.is(TokenKind::QuestionMark) {
                 end_pos = self.current_token.end;
@@ -683,13 +1570,28 @@ impl<'a> Parser<'a> {
                 None
             };
 
+            // A default value comes after the type, like `a: number = 1`
+            if self.is_operator(AssignmentOperator::Assign) && !is_rest {
+                pattern = self.parse_binding_default(pattern)?;
+                end_pos = pattern.node().end;
+            }
+
             let param = Parameter {
                 node: Node::new(start_pos, end_pos),
-                identifier,
+                pattern,
                 optional,
                 type_annotation,
+                accessibility,
+                readonly: is_readonly,
+                _override: is_override,
             };
 
+            if is_rest && !self.current_token.is(TokenKind::CloseParen) {
+                return Err(parser_error!(RestElementNotLast)
+                    .with_expected(&[TokenKind::CloseParen])
+                    .with_label(param.node, "rest parameter here"));
+            }
+
             params.push(param);
 
             match self.current_token.kind {
@@ -697,7 +1599,11 @@ impl<'a> Parser<'a> {
                     self.advance(); // Consume "," token
                 }
                 TokenKind::CloseParen => break,
-                _ => throw_error!(InvalidToken),
+                _ => {
+                    return Err(parser_error!(ExpectedCloseParenAfterParameters)
+                        .with_expected(&[TokenKind::Comma, TokenKind::CloseParen])
+                        .with_label(open_paren, "parameter list starts here"))
+                }
             }
         }
 
@@ -706,104 +1612,1231 @@ impl<'a> Parser<'a> {
         Ok(params)
     }
 
-    /// Parses an `if` statement, including `else if` and `else` clauses.
-    fn parse_if_statement(&mut self) -> Result<IfStatement, ParserErrorInfo> {
+    /// Parses a class declaration, like `abstract class A<T> extends B implements C {}`.
+    fn parse_class_declaration(&mut self) -> Result<ClassDeclaration, ParserErrorInfo> {
         let start_pos = self.current_token.start;
-        self.advance(); // Consume "if" keyword token
-        self.expect_and_consume_token(TokenKind::OpenParen)?;
 
-        let test = self.parse_expression()?;
-
-        self.expect_and_consume_token(TokenKind::CloseParen)?;
+        let is_abstract = self.current_keyword()? == Keyword::Abstract;
+        if is_abstract {
+            self.advance(); // Consume "abstract" token
+        }
 
-        let body = self.parse_statement(true)?;
+        self.advance(); // Consume "class" token
 
-        let consequent: Option<Statement> = match self.current_token.kind {
-            TokenKind::Keyword => match self.current_token.value.expect_keyword() {
-                Keyword::Else => {
-                    self.advance(); // Consume "else" keyword token
-                    Some(self.parse_statement(true)?)
-                }
-                _ => None,
-            },
-            _ => None,
+        self.expect_token_kind(TokenKind::Identifier)?;
+        let id = Identifier {
+            node: Node::new(self.current_token.start, self.current_token.end),
+            name: self.current_identifier()?,
         };
+        self.advance(); // Consume Identifier token
 
-        let end_pos = match &consequent {
-            Some(stmt) => stmt.node().end,
-            None => body.node().end,
-        };
+        let (type_parameters, super_class, super_type_arguments, implements) =
+            self.parse_class_heritage()?;
+        let body = self.parse_class_body()?;
 
-        Ok(IfStatement {
-            node: Node::new(start_pos, end_pos),
-            test,
-            alternate: body,
-            consequent,
+        Ok(ClassDeclaration {
+            node: Node::new(start_pos, body.node.end),
+            id,
+            type_parameters,
+            super_class,
+            super_type_arguments,
+            implements,
+            body,
+            _abstract: is_abstract,
         })
     }
 
-    /// Parses a `for` loop, including `for-in` and `for-of` loops.
-    fn parse_for_statement(&mut self) -> Result<ForStatement, ParserErrorInfo> {
+    /// Parses a class expression, whose name is optional, like `class extends B {}`.
+    fn parse_class_expression(&mut self) -> Result<ClassExpression, ParserErrorInfo> {
         let start_pos = self.current_token.start;
-        self.advance(); // Consume "for" keyword token
-        self.expect_and_consume_token(TokenKind::OpenParen)?;
+        self.advance(); // Consume "class" token
 
-        let head = self.parse_for_head()?;
+        let id = if self.current_token.is(TokenKind::Identifier) {
+            let id = Identifier {
+                node: Node::new(self.current_token.start, self.current_token.end),
+                name: self.current_identifier()?,
+            };
+            self.advance(); // Consume Identifier token
+            Some(id)
+        } else {
+            None
+        };
 
-        match self.current_token.kind {
-            TokenKind::SemiColon => Ok(self.parse_for_classic(start_pos, head)?.into()),
-            TokenKind::Keyword => match self.current_token.value.expect_keyword() {
-                Keyword::In => {
-                    self.advance(); // Consume "in" token
-                    Ok(self.parse_for_in_or_of(start_pos, head, false)?.into())
-                }
-                Keyword::Of => {
-                    self.advance(); // Consume "of" token
-                    Ok(self.parse_for_in_or_of(start_pos, head, true)?.into())
-                }
-                _ => throw_error!(InvalidToken),
-            },
-            _ => throw_error!(InvalidToken),
-        }
+        let (type_parameters, super_class, super_type_arguments, implements) =
+            self.parse_class_heritage()?;
+        let body = self.parse_class_body()?;
+
+        Ok(ClassExpression {
+            node: Node::new(start_pos, body.node.end),
+            id,
+            type_parameters,
+            super_class,
+            super_type_arguments,
+            implements,
+            body,
+        })
     }
 
-    fn parse_for_head(&mut self) -> Result<ForHead, ParserErrorInfo> {
-        if self.current_token.is(TokenKind::SemiColon) {
-            return Ok(ForHead::Empty);
-        }
+    /// Parses what comes between the name of a class and its body:
+    /// its type parameters, and its `extends` and `implements` clauses
+    fn parse_class_heritage(&mut self) -> Result<ClassHeritage, ParserErrorInfo> {
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
+            Some(self.parse_type_parameter_declaration()?)
+        } else {
+            None
+        };
+
+        let mut super_class = None;
+        let mut super_type_arguments = None;
+        if self.current_token.is(TokenKind::Keyword) && self.current_keyword()? == Keyword::Extends
+        {
+            self.advance(); // Consume "extends" token
 
-        if self.current_token.is(TokenKind::Keyword) {
-            let kw = self.current_token.value.expect_keyword();
-            if matches!(kw, Keyword::Var | Keyword::Let | Keyword::Const) {
-                let decl = self.parse_variable_declaration(false)?;
-                return Ok(ForHead::VarDecl(decl));
+            // A `<` after the parent class starts its type arguments, like in `extends B<T>`
+            super_class = Some(self.parse_left_hand_side_expression()?);
+            if self.is_operator(BinaryOperator::LessThan) {
+                super_type_arguments = Some(self.parse_type_arguments()?);
             }
         }
 
-        let expr = self.parse_expression()?;
-        Ok(ForHead::Expr(expr))
-    }
+        let mut implements = Vec::new();
+        if self.current_token.is(TokenKind::Keyword)
+            && self.current_keyword()? == Keyword::Implements
+        {
+            self.advance(); // Consume "implements" token
 
-    fn parse_for_classic(
-        &mut self,
-        start_pos: usize,
-        head: ForHead,
-    ) -> Result<ForClassic, ParserErrorInfo> {
-        let init: Option<Statement> = match head {
-            ForHead::Empty => None,
-            ForHead::VarDecl(decl) => Some(decl.into()),
-            ForHead::Expr(expr) => Some(Statement::ExpressionStatement(Box::new(
-                ExpressionStatement {
-                    node: Node::new(start_pos, expr.node().end),
-                    expression: expr,
-                },
-            ))),
-        };
+            loop {
+                match self.parse_type_value()? {
+                    AstType::TypeReference(reference) => implements.push(*reference),
+                    _ => throw_error!(ExpectedType),
+                }
 
-        self.expect_and_consume_token(TokenKind::SemiColon)?;
+                if !self.current_token.is(TokenKind::Comma) {
+                    break;
+                }
+                self.advance(); // Consume "," token
+            }
+        }
 
-        let test: Option<Expression> = if !self.current_token.is(TokenKind::SemiColon) {
-            Some(self.parse_expression()?.into())
+        Ok((
+            type_parameters,
+            super_class,
+            super_type_arguments,
+            implements,
+        ))
+    }
+
+    /// Parses the body of a class, which is a list of members enclosed by `{}`.
+    fn parse_class_body(&mut self) -> Result<ClassBody, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.expect_and_consume_token(TokenKind::OpenBrace)?;
+
+        let mut body = Vec::new();
+
+        loop {
+            match self.current_token.kind {
+                TokenKind::CloseBrace => break,
+                TokenKind::SemiColon => self.advance(), // Members can be separated by any number of ";"
+                TokenKind::Eof => {
+                    return Err(parser_error!(UnclosedBrace)
+                        .with_expected(&[TokenKind::CloseBrace])
+                        .with_label(Node::new(start_pos, start_pos + 1), "unmatched `{` here"))
+                }
+                _ => body.push(self.parse_class_element()?),
+            }
+        }
+
+        let class_body = ClassBody {
+            node: Node::new(start_pos, self.current_token.end),
+            body,
+        };
+
+        self.advance(); // Consume "}" token
+
+        Ok(class_body)
+    }
+
+    /// Parses a member of a class: a method, a field, or a `static {}` block.
+    fn parse_class_element(&mut self) -> Result<ClassElement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        if self.current_token.is(TokenKind::Keyword)
+            && self.current_keyword()? == Keyword::Static
+            && self.lexer.peek_token().is(TokenKind::OpenBrace)
+        {
+            self.advance(); // Consume "static" token
+
+            // A static block is a function body of its own, where `await` isn't allowed
+            let block = self
+                .with_function_context(FunctionContext::default(), |p| p.parse_block_statement())?;
+            return Ok(ClassElement::StaticBlock(Box::new(StaticBlock {
+                node: Node::new(start_pos, block.node.end),
+                body: block.body,
+            })));
+        }
+
+        let mut is_static = false;
+        let mut is_abstract = false;
+        let mut is_override = false;
+        let mut is_readonly = false;
+        let mut is_declare = false;
+        let mut is_async = false;
+        let mut accessibility = None;
+        let mut kind = MethodDefinitionKind::Method;
+
+        // A modifier is only a modifier if a name follows it,
+        // otherwise it's the name of the member, like in `static() {}` or `get = 1;`
+        while self.peek_is_class_element_name() {
+            match self.current_token.kind {
+                TokenKind::Keyword => match self.current_keyword()? {
+                    Keyword::Static => is_static = true,
+                    Keyword::Abstract => is_abstract = true,
+                    Keyword::Private => accessibility = Some(Accessibility::Private),
+                    Keyword::Protected => accessibility = Some(Accessibility::Protected),
+                    Keyword::Declare => is_declare = true,
+                    // `async` must be on the same line as the name of the method
+                    Keyword::Async if !self.lexer.peek_token().newline_before => is_async = true,
+                    _ => break,
+                },
+                TokenKind::Identifier => match self.current_token.text(self.source) {
+                    "public" => accessibility = Some(Accessibility::Public),
+                    "readonly" => is_readonly = true,
+                    "override" => is_override = true,
+                    "get" => kind = MethodDefinitionKind::Get,
+                    "set" => kind = MethodDefinitionKind::Set,
+                    _ => break,
+                },
+                _ => break,
+            }
+            self.advance(); // Consume modifier token
+        }
+
+        let is_generator = self.is_operator(BinaryOperator::Mult);
+        if is_generator {
+            self.advance(); // Consume "*" token
+        }
+
+        let (key, computed) = self.parse_class_element_key()?;
+
+        let optional = self.current_token.is(TokenKind::QuestionMark);
+        if optional {
+            self.advance(); // Consume "?" token
+        }
+
+        if self.current_token.is(TokenKind::OpenParen) || self.is_operator(BinaryOperator::LessThan)
+        {
+            let is_constructor = match &key {
+                ClassElementKey::Expression(Expression::Identifier(id)) => {
+                    &*id.name == "constructor"
+                }
+                ClassElementKey::Expression(Expression::Literal(literal)) => {
+                    matches!(&**literal, Literal::StringLiteral(s) if s.value == "constructor")
+                }
+                _ => false,
+            };
+            if is_constructor && !is_static && kind == MethodDefinitionKind::Method {
+                kind = MethodDefinitionKind::Constructor;
+            }
+
+            let value = self.parse_class_method_value(is_async, is_generator, is_abstract)?;
+            let end_pos = match value.body {
+                Some(_) => value.node.end,
+                None => self.consume_semicolon(value.node.end),
+            };
+
+            return Ok(ClassElement::MethodDefinition(Box::new(MethodDefinition {
+                node: Node::new(start_pos, end_pos),
+                key,
+                value,
+                kind,
+                computed,
+                _static: is_static,
+                accessibility,
+                _abstract: is_abstract,
+                _override: is_override,
+                optional,
+            })));
+        }
+
+        if is_async || is_generator || kind != MethodDefinitionKind::Method {
+            // Only methods can be async, generators, getters or setters
+            return Err(parser_error!(InvalidToken).with_expected(&[TokenKind::OpenParen]));
+        }
+
+        // A `!` after the name says that the field is definitely assigned, like `y!: string;`
+        let definite = self.is_operator(UnaryOperator::LogicalNot);
+        if definite {
+            self.advance(); // Consume "!" token
+        }
+
+        let type_annotation = if self.current_token.is(TokenKind::Colon) {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+
+        let value = if self.is_operator(AssignmentOperator::Assign) {
+            self.advance(); // Consume "=" token
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        // Like statements, fields end with a `;` which can be inserted automatically
+        let end_pos = self.consume_semicolon(self.previous_token_end);
+
+        let property = PropertyDefinition {
+            node: Node::new(start_pos, end_pos),
+            key,
+            value,
+            computed,
+            _static: is_static,
+            accessibility,
+            _abstract: is_abstract,
+            _override: is_override,
+            readonly: is_readonly,
+            declare: is_declare,
+            optional,
+            definite,
+            type_annotation,
+        };
+
+        Ok(ClassElement::PropertyDefinition(Box::new(property)))
+    }
+
+    /// Whether the token after the current one can be the name of a class member
+    fn peek_is_class_element_name(&mut self) -> bool {
+        let next = self.lexer.peek_token();
+        next.value == TokenValue::Operator(BinaryOperator::Mult.into())
+            || matches!(
+                next.kind,
+                TokenKind::Identifier
+                    | TokenKind::PrivateName
+                    | TokenKind::Keyword
+                    | TokenKind::Boolean
+                    | TokenKind::Null
+                    | TokenKind::String
+                    | TokenKind::Number
+                    | TokenKind::BigInt
+                    | TokenKind::OpenBracket
+            )
+    }
+
+    /// Parses the name of a class member, and returns whether it's computed, like `[Symbol.iterator]`.
+    /// Any keyword can be the name of a member.
+    fn parse_class_element_key(&mut self) -> Result<(ClassElementKey, bool), ParserErrorInfo> {
+        let node = Node::new(self.current_token.start, self.current_token.end);
+
+        let key = match self.current_token.kind {
+            TokenKind::Identifier => {
+                let id = Identifier {
+                    node,
+                    name: self.current_identifier()?,
+                };
+                self.advance(); // Consume Identifier token
+                id.into()
+            }
+            TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => {
+                let id = Identifier {
+                    node,
+                    name: Atom::from(self.current_token.text(self.source)),
+                };
+                self.advance(); // Consume keyword token
+                id.into()
+            }
+            TokenKind::PrivateName => {
+                let id = PrivateIdentifier {
+                    node,
+                    name: Atom::from(self.current_token.identifier_name(self.source)),
+                };
+                self.advance(); // Consume PrivateName token
+                return Ok((ClassElementKey::PrivateIdentifier(id), false));
+            }
+            TokenKind::String | TokenKind::Number | TokenKind::BigInt => {
+                self.parse_literal()?.into()
+            }
+            TokenKind::OpenBracket => {
+                self.advance(); // Consume "[" token
+                let expr = self.parse_expression()?;
+                self.expect_closing_token(
+                    TokenKind::CloseBracket,
+                    node.start,
+                    parser_error!(UnclosedBracket),
+                )?;
+                return Ok((ClassElementKey::Expression(expr), true));
+            }
+            _ => throw_error!(ExpectedClassMember),
+        };
+
+        Ok((ClassElementKey::Expression(key), false))
+    }
+
+    /// Parses the parameters and the body of a class method, which starts at its parameters.
+    /// Abstract methods don't have a body.
+    fn parse_class_method_value(
+        &mut self,
+        is_async: bool,
+        is_generator: bool,
+        is_abstract: bool,
+    ) -> Result<FunctionExpression, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
+            Some(self.parse_type_parameter_declaration()?)
+        } else {
+            None
+        };
+
+        let params = self.parse_parameter_list()?;
+
+        let return_type = if self.current_token.is(TokenKind::Colon) {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+
+        let body = if is_abstract && !self.current_token.is(TokenKind::OpenBrace) {
+            None
+        } else {
+            let context = FunctionContext {
+                is_async,
+                is_generator,
+            };
+            Some(self.with_function_context(context, |p| p.parse_function_body())?)
+        };
+
+        Ok(FunctionExpression {
+            node: Node::new(start_pos, self.previous_token_end),
+            id: None,
+            type_parameters,
+            params,
+            return_type,
+            body,
+            generator: is_generator,
+            _async: is_async,
+        })
+    }
+
+    /// Whether the current token starts an import or export declaration.
+    /// `import` also starts expressions, like `import("./a.js")` and `import.meta`.
+    fn is_import_or_export(&mut self) -> bool {
+        match self.current_token.value.as_keyword() {
+            Some(Keyword::Import) => !matches!(
+                self.lexer.peek_token().kind,
+                TokenKind::OpenParen | TokenKind::Dot
+            ),
+            Some(Keyword::Export) => true,
+            _ => false,
+        }
+    }
+
+    /// Parses a top level `import` or `export` declaration.
+    fn parse_import_or_export_declaration(
+        &mut self,
+    ) -> Result<ImportOrExportDeclaration, ParserErrorInfo> {
+        match self.current_keyword()? {
+            Keyword::Import => Ok(ImportOrExportDeclaration::ImportDeclaration(
+                self.parse_import_declaration()?,
+            )),
+            _ => self.parse_export_declaration(),
+        }
+    }
+
+    /// Whether the current token is the `type` of `import type` or `export type`,
+    /// rather than the start of a type alias like `export type A = B;`.
+    /// Only imports can have a name right after the modifier, like `import type A from "a";`
+    fn is_type_modifier(&mut self, is_import: bool) -> bool {
+        if self.current_token.value.as_keyword() != Some(Keyword::Type) {
+            return false;
+        }
+
+        let source = self.source;
+        let next = self.lexer.peek_token();
+        match next.kind {
+            TokenKind::OpenBrace => true,
+            TokenKind::Operator => next.value == TokenValue::Operator(BinaryOperator::Mult.into()),
+            // `type` is the imported name in `import { type as t }`
+            TokenKind::Identifier => is_import && next.text(source) != "as",
+            _ => false,
+        }
+    }
+
+    /// Parses an `import` declaration, like `import a, { b as c } from "d";` or `import "./e.js";`.
+    fn parse_import_declaration(&mut self) -> Result<ImportDeclaration, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "import" token
+
+        let import_kind = if self.is_type_modifier(true) {
+            self.advance(); // Consume "type" token
+            ImportOrExportKind::Type
+        } else {
+            ImportOrExportKind::Value
+        };
+
+        let mut specifiers = Vec::new();
+
+        // Without any specifiers, the module is only imported for its side effects
+        if !self.current_token.is(TokenKind::String) {
+            let mut has_more = true;
+
+            if self.current_token.is(TokenKind::Identifier) {
+                let local = self.parse_binding_identifier()?;
+                specifiers.push(ImportDeclarationSpecifier::ImportDefaultSpecifier(
+                    ImportDefaultSpecifier {
+                        node: local.node,
+                        local,
+                    },
+                ));
+
+                has_more = self.current_token.is(TokenKind::Comma);
+                if has_more {
+                    self.advance(); // Consume "," token
+                }
+            }
+
+            if has_more {
+                match self.current_token.kind {
+                    TokenKind::Operator if self.is_operator(BinaryOperator::Mult) => {
+                        let start = self.current_token.start;
+                        self.advance(); // Consume "*" token
+
+                        self.expect_contextual_keyword("as")?;
+                        let local = self.parse_binding_identifier()?;
+
+                        specifiers.push(ImportDeclarationSpecifier::ImportNamespaceSpecifier(
+                            ImportNamespaceSpecifier {
+                                node: Node::new(start, local.node.end),
+                                local,
+                            },
+                        ));
+                    }
+                    TokenKind::OpenBrace => self.parse_import_specifiers(&mut specifiers)?,
+                    _ => {
+                        return Err(parser_error!(InvalidToken).with_expected(&[
+                            TokenKind::Identifier,
+                            TokenKind::Operator,
+                            TokenKind::OpenBrace,
+                        ]))
+                    }
+                }
+            }
+
+            if !self.is_contextual_keyword("from") {
+                throw_error!(ExpectedFrom);
+            }
+            self.advance(); // Consume "from" token
+        }
+
+        let source = self.parse_module_source()?;
+        let end_pos = self.consume_semicolon(source.node.end);
+
+        Ok(ImportDeclaration {
+            node: Node::new(start_pos, end_pos),
+            specifiers,
+            source,
+            import_kind,
+        })
+    }
+
+    /// Parses the named imports of an import declaration, like `{ a, b as c, type D }`.
+    fn parse_import_specifiers(
+        &mut self,
+        specifiers: &mut Vec<ImportDeclarationSpecifier>,
+    ) -> Result<(), ParserErrorInfo> {
+        let open_start = self.current_token.start;
+        self.advance(); // Consume "{" token
+
+        while !self.current_token.is(TokenKind::CloseBrace) {
+            let start = self.current_token.start;
+
+            let import_kind = if self.is_type_modifier(true) {
+                self.advance(); // Consume "type" token
+                ImportOrExportKind::Type
+            } else {
+                ImportOrExportKind::Value
+            };
+
+            let imported = self.parse_module_export_name()?;
+            let local = if self.is_contextual_keyword("as") {
+                self.advance(); // Consume "as" token
+                self.parse_binding_identifier()?
+            } else {
+                imported.clone()
+            };
+
+            specifiers.push(ImportDeclarationSpecifier::ImportSpecifier(
+                ImportSpecifier {
+                    node: Node::new(start, local.node.end),
+                    local,
+                    imported,
+                    import_kind,
+                },
+            ));
+
+            if !self.consume_list_separator(open_start)? {
+                break;
+            }
+        }
+
+        self.advance(); // Consume "}" token
+
+        Ok(())
+    }
+
+    /// Parses an `export` declaration: a declaration like `export const a = 1;`,
+    /// a list like `export { a as b } from "c";`, `export * as d from "e";` or `export default f;`.
+    fn parse_export_declaration(&mut self) -> Result<ImportOrExportDeclaration, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "export" token
+
+        let export_kind = if self.is_type_modifier(false) {
+            self.advance(); // Consume "type" token
+            ImportOrExportKind::Type
+        } else {
+            ImportOrExportKind::Value
+        };
+
+        match self.current_token.kind {
+            TokenKind::Operator if self.is_operator(BinaryOperator::Mult) => {
+                self.advance(); // Consume "*" token
+
+                let exported = if self.is_contextual_keyword("as") {
+                    self.advance(); // Consume "as" token
+                    Some(self.parse_module_export_name()?)
+                } else {
+                    None
+                };
+
+                if !self.is_contextual_keyword("from") {
+                    throw_error!(ExpectedFrom);
+                }
+                self.advance(); // Consume "from" token
+
+                let source = self.parse_module_source()?;
+                let end_pos = self.consume_semicolon(source.node.end);
+
+                Ok(ImportOrExportDeclaration::ExportAllDeclaration(
+                    ExportAllDeclaration {
+                        node: Node::new(start_pos, end_pos),
+                        exported,
+                        source,
+                        export_kind,
+                    },
+                ))
+            }
+            TokenKind::OpenBrace => {
+                let open_start = self.current_token.start;
+                self.advance(); // Consume "{" token
+
+                let mut specifiers = Vec::new();
+
+                while !self.current_token.is(TokenKind::CloseBrace) {
+                    let start = self.current_token.start;
+
+                    let local = self.parse_module_export_name()?;
+                    let exported = if self.is_contextual_keyword("as") {
+                        self.advance(); // Consume "as" token
+                        self.parse_module_export_name()?
+                    } else {
+                        local.clone()
+                    };
+
+                    specifiers.push(ExportSpecifier {
+                        node: Node::new(start, exported.node.end),
+                        local,
+                        exported,
+                        export_kind: ImportOrExportKind::Value,
+                    });
+
+                    if !self.consume_list_separator(open_start)? {
+                        break;
+                    }
+                }
+
+                let mut end_pos = self.current_token.end;
+                self.advance(); // Consume "}" token
+
+                // A re-export, like `export { a } from "b";`
+                let source = if self.is_contextual_keyword("from") {
+                    self.advance(); // Consume "from" token
+                    let source = self.parse_module_source()?;
+                    end_pos = source.node.end;
+                    Some(source)
+                } else {
+                    None
+                };
+
+                let end_pos = self.consume_semicolon(end_pos);
+
+                Ok(ImportOrExportDeclaration::ExportNamedDeclaration(
+                    ExportNamedDeclaration {
+                        node: Node::new(start_pos, end_pos),
+                        declaration: None,
+                        specifiers,
+                        source,
+                        export_kind,
+                    },
+                ))
+            }
+            TokenKind::Keyword if self.current_keyword()? == Keyword::Default => {
+                Ok(ImportOrExportDeclaration::ExportDefaultDeclaration(
+                    self.parse_export_default_declaration(start_pos)?,
+                ))
+            }
+            _ => {
+                let declaration = self.parse_exported_declaration()?;
+
+                Ok(ImportOrExportDeclaration::ExportNamedDeclaration(
+                    ExportNamedDeclaration {
+                        node: Node::new(start_pos, declaration.node().end),
+                        declaration: Some(declaration),
+                        specifiers: Vec::new(),
+                        source: None,
+                        export_kind,
+                    },
+                ))
+            }
+        }
+    }
+
+    /// Parses the declaration of `export const a = 1;`, `export function b() {}`, and the like
+    fn parse_exported_declaration(&mut self) -> Result<Declaration, ParserErrorInfo> {
+        let is_declaration = self.current_token.is(TokenKind::Keyword)
+            && matches!(
+                self.current_keyword()?,
+                Keyword::Var
+                    | Keyword::Let
+                    | Keyword::Const
+                    | Keyword::Function
+                    | Keyword::Class
+                    | Keyword::Abstract
+                    | Keyword::Enum
+                    | Keyword::Declare
+                    | Keyword::Interface
+                    | Keyword::Type
+            )
+            || self.is_async_function();
+
+        if !is_declaration {
+            throw_error!(ExpectedExportDeclaration);
+        }
+
+        match self.parse_statement(true)? {
+            Statement::VariableDeclaration(decl) => Ok(Declaration::VariableDeclaration(decl)),
+            Statement::FunctionDeclaration(decl) => Ok(Declaration::FunctionDeclaration(decl)),
+            Statement::ClassDeclaration(decl) => Ok(Declaration::ClassDeclaration(decl)),
+            Statement::EnumStatement(decl) => Ok(Declaration::EnumStatement(decl)),
+            Statement::InterfaceDeclaration(decl) => Ok(Declaration::InterfaceDeclaration(decl)),
+            Statement::TypeAliasDeclaration(decl) => Ok(Declaration::TypeAliasDeclaration(decl)),
+            _ => throw_error!(InternalError),
+        }
+    }
+
+    /// Parses `export default`, followed by a function, a class, or an expression.
+    /// The function or class doesn't need a name.
+    fn parse_export_default_declaration(
+        &mut self,
+        start_pos: usize,
+    ) -> Result<ExportDefaultDeclaration, ParserErrorInfo> {
+        self.advance(); // Consume "default" token
+
+        let is_async_function = self.is_async_function();
+        let declaration = match self.current_token.value.as_keyword() {
+            Some(keyword @ (Keyword::Function | Keyword::Async))
+                if keyword == Keyword::Function || is_async_function =>
+            {
+                let func = self.parse_function_expression(is_async_function)?;
+                let body = func.body.ok_or_else(|| parser_error!(InternalError))?;
+
+                match func.id {
+                    Some(id) => ExportDefaultDeclarationDeclaration::FunctionDeclaration(
+                        FunctionDeclaration {
+                            node: func.node,
+                            id,
+                            type_parameters: func.type_parameters,
+                            params: func.params,
+                            return_type: func.return_type,
+                            generator: func.generator,
+                            _async: func._async,
+                            body,
+                        },
+                    ),
+                    None => {
+                        ExportDefaultDeclarationDeclaration::AnonymousDefaultExportedFunctionDeclaration(
+                            AnonymousDefaultExportedFunctionDeclaration {
+                                node: func.node,
+                                type_parameters: func.type_parameters,
+                                params: func.params,
+                                return_type: func.return_type,
+                                body,
+                                generator: func.generator,
+                                _async: func._async,
+                            },
+                        )
+                    }
+                }
+            }
+            Some(Keyword::Abstract) => ExportDefaultDeclarationDeclaration::ClassDeclaration(
+                self.parse_class_declaration()?,
+            ),
+            Some(Keyword::Class) => {
+                let class = self.parse_class_expression()?;
+
+                match class.id {
+                    Some(id) => ExportDefaultDeclarationDeclaration::ClassDeclaration(
+                        ClassDeclaration {
+                            node: class.node,
+                            id,
+                            type_parameters: class.type_parameters,
+                            super_class: class.super_class,
+                            super_type_arguments: class.super_type_arguments,
+                            implements: class.implements,
+                            body: class.body,
+                            _abstract: false,
+                        },
+                    ),
+                    None => {
+                        ExportDefaultDeclarationDeclaration::AnonymousDefaultExportedClassDeclaration(
+                            AnonymousDefaultExportedClassDeclaration {
+                                node: class.node,
+                                type_parameters: class.type_parameters,
+                                super_class: class.super_class,
+                                super_type_arguments: class.super_type_arguments,
+                                implements: class.implements,
+                                body: class.body,
+                            },
+                        )
+                    }
+                }
+            }
+            _ => {
+                let expr = self.parse_expression()?;
+                let end_pos = self.consume_semicolon(expr.node().end);
+
+                return Ok(ExportDefaultDeclaration {
+                    node: Node::new(start_pos, end_pos),
+                    declaration: ExportDefaultDeclarationDeclaration::Expression(expr),
+                });
+            }
+        };
+
+        Ok(ExportDefaultDeclaration {
+            node: Node::new(start_pos, declaration.node().end),
+            declaration,
+        })
+    }
+
+    /// Consumes the `,` between two items of an import or export list, and returns whether another item can follow.
+    /// The list must end with a `}` matching the `{` at `open_start`.
+    fn consume_list_separator(&mut self, open_start: usize) -> Result<bool, ParserErrorInfo> {
+        match self.current_token.kind {
+            TokenKind::Comma => {
+                self.advance(); // Consume "," token
+                Ok(true)
+            }
+            TokenKind::CloseBrace => Ok(false),
+            _ => Err(parser_error!(UnclosedBrace)
+                .with_expected(&[TokenKind::Comma, TokenKind::CloseBrace])
+                .with_label(Node::new(open_start, open_start + 1), "unmatched `{` here")),
+        }
+    }
+
+    /// Consumes a keyword that is only a keyword in some places, like `as`
+    fn expect_contextual_keyword(&mut self, name: &str) -> Result<(), ParserErrorInfo> {
+        if !self.is_contextual_keyword(name) {
+            throw_error!(InvalidToken);
+        }
+        self.advance(); // Consume keyword token
+        Ok(())
+    }
+
+    /// Consumes the keyword `keyword`, like the `while` that ends a `do ... while` loop
+    fn expect_keyword(&mut self, keyword: Keyword) -> Result<(), ParserErrorInfo> {
+        if self.current_token.value.as_keyword() != Some(keyword) {
+            throw_error!(InvalidToken);
+        }
+        self.advance(); // Consume keyword token
+        Ok(())
+    }
+
+    /// Parses the name of a binding, like the `a` of `import a from "b"`.
+    /// `await` and `yield` are only names outside the functions where they are operators.
+    fn parse_binding_identifier(&mut self) -> Result<Identifier, ParserErrorInfo> {
+        let node = Node::new(self.current_token.start, self.current_token.end);
+
+        let name = if self.current_token.value.as_keyword() == Some(Keyword::Await) {
+            if self
+                .function_context
+                .is_some_and(|context| context.is_async)
+            {
+                return Err(parser_error!(ExpectedIdentifier)
+                    .with_expected(&[TokenKind::Identifier])
+                    .with_label(node, "`await` is an operator in async functions"));
+            }
+            Atom::from("await")
+        } else {
+            if self.is_yield_allowed() && self.is_contextual_keyword("yield") {
+                return Err(parser_error!(ExpectedIdentifier)
+                    .with_expected(&[TokenKind::Identifier])
+                    .with_label(node, "`yield` is an operator in generators"));
+            }
+            self.current_identifier()?
+        };
+        self.advance(); // Consume Identifier token
+
+        Ok(Identifier { node, name })
+    }
+
+    /// Parses the target of a binding, which is an identifier, or an array or object pattern that destructures the value
+    fn parse_binding_pattern(&mut self) -> Result<Pattern, ParserErrorInfo> {
+        match self.current_token.kind {
+            TokenKind::OpenBracket => Ok(self.parse_array_binding_pattern()?.into()),
+            TokenKind::OpenBrace => Ok(self.parse_object_binding_pattern()?.into()),
+            _ => Ok(self.parse_binding_identifier()?.into()),
+        }
+    }
+
+    /// Parses a binding pattern with an optional default value, like the `b = []` of `[a, b = []]`
+    fn parse_binding_element(&mut self) -> Result<Pattern, ParserErrorInfo> {
+        let left = self.parse_binding_pattern()?;
+        self.parse_binding_default(left)
+    }
+
+    /// Parses the default value of a binding, if it has one
+    fn parse_binding_default(&mut self, left: Pattern) -> Result<Pattern, ParserErrorInfo> {
+        if !self.is_operator(AssignmentOperator::Assign) {
+            return Ok(left);
+        }
+        self.advance(); // Consume "=" token
+
+        let right = self.parse_expression()?;
+
+        Ok(AssignmentPattern {
+            node: Node::new(left.node().start, right.node().end),
+            left,
+            right,
+        }
+        .into())
+    }
+
+    /// Parses an array destructuring pattern, like `[a, , ...rest]`
+    fn parse_array_binding_pattern(&mut self) -> Result<ArrayPattern, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "[" token
+
+        let mut elements = Vec::new();
+
+        while !self.current_token.is(TokenKind::CloseBracket) {
+            // A hole skips an element, like in `[, b]`
+            if self.current_token.is(TokenKind::Comma) {
+                self.advance(); // Consume "," token
+                elements.push(None);
+                continue;
+            }
+
+            if self.current_token.is(TokenKind::Ellipsis) {
+                let rest = self.parse_binding_rest_element(TokenKind::CloseBracket)?;
+                elements.push(Some(rest.into()));
+                break;
+            }
+
+            elements.push(Some(self.parse_binding_element()?));
+
+            match self.current_token.kind {
+                TokenKind::Comma => self.advance(), // Consume "," token
+                TokenKind::CloseBracket => break,
+                _ => {
+                    return Err(parser_error!(UnclosedBracket)
+                        .with_expected(&[TokenKind::Comma, TokenKind::CloseBracket])
+                        .with_label(Node::new(start_pos, start_pos + 1), "unmatched `[` here"))
+                }
+            }
+        }
+
+        let pattern = ArrayPattern {
+            node: Node::new(start_pos, self.current_token.end), // Includes "[" and "]" tokens
+            elements,
+        };
+
+        self.advance(); // Consume "]" token
+
+        Ok(pattern)
+    }
+
+    /// Parses an object destructuring pattern, like `{ a, b: [c] = [], ...rest }`
+    fn parse_object_binding_pattern(&mut self) -> Result<ObjectPattern, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "{" token
+
+        let mut properties = Vec::new();
+
+        while !self.current_token.is(TokenKind::CloseBrace) {
+            if self.current_token.is(TokenKind::Ellipsis) {
+                let rest = self.parse_binding_rest_element(TokenKind::CloseBrace)?;
+                properties.push(rest.into());
+                break;
+            }
+
+            let property = self.parse_binding_property()?;
+            properties.push(property.into());
+
+            if !self.consume_list_separator(start_pos)? {
+                break;
+            }
+        }
+
+        let pattern = ObjectPattern {
+            node: Node::new(start_pos, self.current_token.end), // Includes "{" and "}" tokens
+            properties,
+        };
+
+        self.advance(); // Consume "}" token
+
+        Ok(pattern)
+    }
+
+    /// Parses a property of an object destructuring pattern, like `a`, `a = 1`, `a: b` or `[key]: b = 1`.
+    /// Any keyword can be the key of a property that isn't shorthand, like `{ default: a }`.
+    fn parse_binding_property(&mut self) -> Result<AssignmentProperty, ParserErrorInfo> {
+        let node = Node::new(self.current_token.start, self.current_token.end);
+
+        // Shorthand, like the `a` in `{ a } = obj`, which binds `a` to `obj.a`
+        if self.current_token.is(TokenKind::Identifier)
+            && self.lexer.peek_token().kind != TokenKind::Colon
+        {
+            let id = self.parse_binding_identifier()?;
+            let value = self.parse_binding_default(id.clone().into())?;
+
+            return Ok(AssignmentProperty {
+                node: Node::new(node.start, value.node().end),
+                key: id.into(),
+                value,
+                shorthand: true,
+                computed: false,
+            });
+        }
+
+        let (key, computed) = match self.current_token.kind {
+            TokenKind::Identifier => {
+                let id = Identifier {
+                    node,
+                    name: self.current_identifier()?,
+                };
+                self.advance(); // Consume Identifier token
+                (id.into(), false)
+            }
+            TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => {
+                let id = Identifier {
+                    node,
+                    name: Atom::from(self.current_token.text(self.source)),
+                };
+                self.advance(); // Consume keyword token
+                (id.into(), false)
+            }
+            TokenKind::String | TokenKind::Number | TokenKind::BigInt => {
+                (self.parse_literal()?.into(), false)
+            }
+            TokenKind::OpenBracket => {
+                self.advance(); // Consume "[" token
+                let expr = self.parse_expression()?;
+                self.expect_closing_token(
+                    TokenKind::CloseBracket,
+                    node.start,
+                    parser_error!(UnclosedBracket),
+                )?;
+                (expr, true)
+            }
+            _ => {
+                return Err(parser_error!(ExpectedIdentifier)
+                    .with_expected(&[TokenKind::Identifier, TokenKind::OpenBracket]))
+            }
+        };
+
+        self.expect_and_consume_token(TokenKind::Colon)?;
+        let value = self.parse_binding_element()?;
+
+        Ok(AssignmentProperty {
+            node: Node::new(node.start, value.node().end),
+            key,
+            value,
+            shorthand: false,
+            computed,
+        })
+    }
+
+    /// Parses a rest element, like `...rest`, which must be the last element of its pattern
+    fn parse_binding_rest_element(
+        &mut self,
+        closing_kind: TokenKind,
+    ) -> Result<RestElement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "..." token
+
+        let argument = self.parse_binding_pattern()?;
+        let rest = RestElement {
+            node: Node::new(start_pos, argument.node().end),
+            argument,
+        };
+
+        if !self.current_token.is(closing_kind) {
+            return Err(parser_error!(RestElementNotLast)
+                .with_expected(&[closing_kind])
+                .with_label(rest.node, "rest element here"));
+        }
+
+        Ok(rest)
+    }
+
+    /// Parses the name that a module exports something as, which can be a keyword, like in `export { a as default }`
+    fn parse_module_export_name(&mut self) -> Result<Identifier, ParserErrorInfo> {
+        let name = match self.current_token.kind {
+            TokenKind::Identifier => self.current_identifier()?,
+            TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => {
+                Atom::from(self.current_token.text(self.source))
+            }
+            _ => {
+                return Err(
+                    parser_error!(ExpectedIdentifier).with_expected(&[TokenKind::Identifier])
+                )
+            }
+        };
+
+        let id = Identifier {
+            node: Node::new(self.current_token.start, self.current_token.end),
+            name,
+        };
+        self.advance(); // Consume name token
+        Ok(id)
+    }
+
+    /// Parses the string of the module to import from, like `"./a.js"`
+    fn parse_module_source(&mut self) -> Result<literal::Literal, ParserErrorInfo> {
+        self.expect_token_kind(TokenKind::String)?;
+        let source = literal::Literal {
+            node: Node::new(self.current_token.start, self.current_token.end),
+            value: LiteralValue::String(self.current_token.string_value(self.source).into_owned()),
+        };
+        self.advance(); // Consume String token
+        Ok(source)
+    }
+
+    /// Parses an `if` statement, including `else if` and `else` clauses.
+    fn parse_if_statement(&mut self) -> Result<IfStatement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "if" keyword token
+        self.expect_and_consume_token(TokenKind::OpenParen)?;
+
+        let test = self.parse_sequence_expression()?;
+
+        self.expect_and_consume_token(TokenKind::CloseParen)?;
+
+        let consequent = self.parse_statement(true)?;
+
+        let alternate: Option<Statement> = match self.current_token.value {
+            TokenValue::Keyword(Keyword::Else) => {
+                self.advance(); // Consume "else" keyword token
+                Some(self.parse_statement(true)?)
+            }
+            _ => None,
+        };
+
+        let end_pos = match &alternate {
+            Some(stmt) => stmt.node().end,
+            None => consequent.node().end,
+        };
+
+        Ok(IfStatement {
+            node: Node::new(start_pos, end_pos),
+            test,
+            consequent,
+            alternate,
+        })
+    }
+
+    /// Parses a `for` loop, including `for-in` and `for-of` loops.
+    fn parse_for_statement(&mut self) -> Result<Statement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "for" keyword token
+
+        // `for await` can only iterate with `of`, over async iterables
+        let is_await = self.current_token.value.as_keyword() == Some(Keyword::Await);
+        if is_await {
+            if !self.is_await_allowed() {
+                return Err(parser_error!(AwaitOutsideAsync).with_label(
+                    Node::new(self.current_token.start, self.current_token.end),
+                    "the enclosing function is not async",
+                ));
+            }
+            self.advance(); // Consume "await" token
+        }
+
+        self.expect_and_consume_token(TokenKind::OpenParen)?;
+
+        let init = self.parse_for_init()?;
+
+        match self.current_token.kind {
+            TokenKind::SemiColon if !is_await => {
+                Ok(self.parse_for_classic(start_pos, init)?.into())
+            }
+            TokenKind::Keyword => match self.current_keyword()? {
+                Keyword::In if !is_await => {
+                    self.advance(); // Consume "in" token
+                    let (left, right, body) = self.parse_for_in_or_of_rest(init)?;
+                    Ok(ForInStatement {
+                        node: Node::new(start_pos, body.node().end),
+                        left,
+                        right,
+                        body,
+                    }
+                    .into())
+                }
+                Keyword::Of => {
+                    self.advance(); // Consume "of" token
+                    let (left, right, body) = self.parse_for_in_or_of_rest(init)?;
+                    Ok(ForOfStatement {
+                        node: Node::new(start_pos, body.node().end),
+                        left,
+                        right,
+                        body,
+                        _await: is_await,
+                    }
+                    .into())
+                }
+                _ => throw_error!(InvalidToken),
+            },
+            _ => throw_error!(InvalidToken),
+        }
+    }
+
+    /// Parses what comes first in the head of a `for` loop, which can be left out in a classic loop
+    fn parse_for_init(&mut self) -> Result<Option<ForInit>, ParserErrorInfo> {
+        if self.current_token.is(TokenKind::SemiColon) {
+            return Ok(None);
+        }
+
+        self.with_in_allowed(false, |p| {
+            if p.current_token.is(TokenKind::Keyword) {
+                let kw = p.current_keyword()?;
+                if matches!(kw, Keyword::Var | Keyword::Let | Keyword::Const) {
+                    let decl = p.parse_variable_declaration(false)?;
+                    return Ok(Some(ForInit::VariableDeclaration(decl)));
+                }
+            }
+
+            let expr = p.parse_sequence_expression()?;
+            Ok(Some(ForInit::Expression(expr)))
+        })
+    }
+
+    fn parse_for_classic(
+        &mut self,
+        start_pos: usize,
+        init: Option<ForInit>,
+    ) -> Result<ForStatement, ParserErrorInfo> {
+        self.expect_and_consume_token(TokenKind::SemiColon)?;
+
+        let test: Option<Expression> = if !self.current_token.is(TokenKind::SemiColon) {
+            Some(self.parse_sequence_expression()?)
         } else {
             None
         };
@@ -811,7 +2844,7 @@ impl<'a> Parser<'a> {
         self.expect_and_consume_token(TokenKind::SemiColon)?;
 
         let update: Option<Expression> = if !self.current_token.is(TokenKind::CloseParen) {
-            Some(self.parse_expression()?.into())
+            Some(self.parse_sequence_expression()?)
         } else {
             None
         };
@@ -819,50 +2852,33 @@ impl<'a> Parser<'a> {
         self.expect_and_consume_token(TokenKind::CloseParen)?;
         let body = self.parse_statement(true)?;
 
-        Ok(ForClassic {
+        Ok(ForStatement {
             node: Node::new(start_pos, body.node().end),
             init,
             test,
             update,
             body,
-        }
-        .into())
+        })
     }
 
-    fn parse_for_in_or_of(
+    /// Parses the rest of a `for-in` or `for-of` loop, after its `in` or `of`
+    fn parse_for_in_or_of_rest(
         &mut self,
-        start: usize,
-        head: ForHead,
-        is_of: bool,
-    ) -> Result<ForStatement, ParserErrorInfo> {
+        init: Option<ForInit>,
+    ) -> Result<(ForInOrOfLeft, Expression, Statement), ParserErrorInfo> {
+        let left = match init {
+            Some(ForInit::VariableDeclaration(decl)) => ForInOrOfLeft::VariableDeclaration(decl),
+            Some(ForInit::Expression(expr)) => {
+                ForInOrOfLeft::Pattern(self.reinterpret_cover_as_pattern(expr)?)
+            }
+            None => throw_error!(InternalError),
+        };
+
         let right = self.parse_expression()?;
         self.expect_and_consume_token(TokenKind::CloseParen)?;
         let body = self.parse_statement(true)?;
 
-        let node = Node::new(start, body.node().end);
-        let left = match head {
-            ForHead::VarDecl(decl) => ForLeft::VariableDeclaration(decl),
-            ForHead::Expr(expr) => ForLeft::Expression(expr),
-            ForHead::Empty => throw_error!(InternalError),
-        };
-
-        if is_of {
-            Ok(ForOf {
-                node,
-                left,
-                right,
-                body,
-            }
-            .into())
-        } else {
-            Ok(ForIn {
-                node,
-                left,
-                right,
-                body,
-            }
-            .into())
-        }
+        Ok((left, right, body))
     }
 
     /// Parses `while` loop
@@ -872,7 +2888,7 @@ impl<'a> Parser<'a> {
 
         self.expect_and_consume_token(TokenKind::OpenParen)?;
 
-        let condition = self.parse_expression()?;
+        let test = self.parse_sequence_expression()?;
 
         self.expect_and_consume_token(TokenKind::CloseParen)?;
 
@@ -880,8 +2896,156 @@ impl<'a> Parser<'a> {
 
         Ok(WhileStatement {
             node: Node::new(start_pos, body.node().end),
-            condition,
+            test,
+            body,
+        })
+    }
+
+    /// Parses a `do ... while` loop, like `do { a++ } while (a < 10);`
+    fn parse_do_while_statement(&mut self) -> Result<DoWhileStatement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "do" keyword token
+
+        let body = self.parse_statement(true)?;
+
+        self.expect_keyword(Keyword::While)?;
+        self.expect_and_consume_token(TokenKind::OpenParen)?;
+        let test = self.parse_sequence_expression()?;
+        let mut end_pos = self.current_token.end;
+        self.expect_and_consume_token(TokenKind::CloseParen)?;
+
+        // A `;` is always inserted after the `)`, even if the next statement is on the same line
+        if self.current_token.is(TokenKind::SemiColon) {
+            end_pos = self.current_token.end;
+            self.advance(); // Consume ";" token
+        }
+
+        Ok(DoWhileStatement {
+            node: Node::new(start_pos, end_pos),
             body,
+            test,
+        })
+    }
+
+    /// Parses a `try` statement, with a `catch` clause, a `finally` block, or both
+    fn parse_try_statement(&mut self) -> Result<TryStatement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "try" keyword token
+
+        let block = self.parse_block_statement()?;
+
+        let handler = if self.current_token.value.as_keyword() == Some(Keyword::Catch) {
+            let catch_start = self.current_token.start;
+            self.advance(); // Consume "catch" keyword token
+
+            // The binding can be left out, like in `catch {}`
+            let param = if self.current_token.is(TokenKind::OpenParen) {
+                self.advance(); // Consume "(" token
+                let param = self.parse_binding_pattern()?;
+                self.expect_and_consume_token(TokenKind::CloseParen)?;
+                Some(param)
+            } else {
+                None
+            };
+
+            let body = self.parse_block_statement()?;
+            Some(CatchClause {
+                node: Node::new(catch_start, body.node.end),
+                param,
+                body,
+            })
+        } else {
+            None
+        };
+
+        let finalizer = if self.current_token.value.as_keyword() == Some(Keyword::Finally) {
+            self.advance(); // Consume "finally" keyword token
+            Some(self.parse_block_statement()?)
+        } else {
+            None
+        };
+
+        let end_pos = match (&handler, &finalizer) {
+            (_, Some(finalizer)) => finalizer.node.end,
+            (Some(handler), None) => handler.node.end,
+            (None, None) => {
+                return Err(parser_error!(ExpectedCatchOrFinally).with_label(
+                    Node::new(start_pos, block.node.end),
+                    "this `try` block has no `catch` or `finally`",
+                ))
+            }
+        };
+
+        Ok(TryStatement {
+            node: Node::new(start_pos, end_pos),
+            block,
+            handler,
+            finalizer,
+        })
+    }
+
+    /// Parses a `switch` statement, with its `case` and `default` clauses
+    fn parse_switch_statement(&mut self) -> Result<SwitchStatement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "switch" keyword token
+
+        self.expect_and_consume_token(TokenKind::OpenParen)?;
+        let discriminant = self.parse_sequence_expression()?;
+        self.expect_and_consume_token(TokenKind::CloseParen)?;
+
+        let open_start = self.current_token.start;
+        self.expect_and_consume_token(TokenKind::OpenBrace)?;
+
+        let mut cases = Vec::new();
+        while !self.current_token.is(TokenKind::CloseBrace) {
+            let case_start = self.current_token.start;
+            let test = match self.current_token.value.as_keyword() {
+                Some(Keyword::Case) => {
+                    self.advance(); // Consume "case" keyword token
+                    Some(self.parse_sequence_expression()?)
+                }
+                Some(Keyword::Default) => {
+                    self.advance(); // Consume "default" keyword token
+                    None
+                }
+                _ if self.current_token.is(TokenKind::Eof) => {
+                    return Err(parser_error!(UnclosedBrace)
+                        .with_expected(&[TokenKind::CloseBrace])
+                        .with_label(Node::new(open_start, open_start + 1), "unmatched `{` here"));
+                }
+                _ => throw_error!(InvalidToken),
+            };
+            let mut end_pos = self.current_token.end;
+            self.expect_and_consume_token(TokenKind::Colon)?;
+
+            // The statements of a case last until the next case, like in a block without braces
+            let mut consequent = Vec::new();
+            while !self.current_token.is(TokenKind::CloseBrace)
+                && !self.current_token.is(TokenKind::Eof)
+                && !matches!(
+                    self.current_token.value.as_keyword(),
+                    Some(Keyword::Case | Keyword::Default)
+                )
+            {
+                let stmt = self.parse_statement(true)?;
+                end_pos = stmt.node().end;
+                consequent.push(stmt);
+            }
+
+            cases.push(SwitchCase {
+                node: Node::new(case_start, end_pos),
+                test,
+                consequent,
+            });
+        }
+
+        let end_pos = self.current_token.end;
+        self.advance(); // Consume "}" token
+
+        Ok(SwitchStatement {
+            node: Node::new(start_pos, end_pos),
+            discriminant,
+            cases,
         })
     }
 
@@ -891,19 +3055,17 @@ impl<'a> Parser<'a> {
         let mut end_pos = self.current_token.end;
         self.advance(); // Consume "return" token
 
-        // TODO: Fix this case
-        let argument = if !self.current_token.is(TokenKind::SemiColon) {
-            let expr = self.parse_expression()?;
+        // The argument must start on the same line, otherwise a `;` is inserted after `return`
+        let argument = if self.current_token.is(TokenKind::SemiColon) || self.can_insert_semicolon()
+        {
+            None
+        } else {
+            let expr = self.parse_sequence_expression()?;
             end_pos = expr.node().end;
             Some(expr)
-        } else {
-            None
         };
 
-        if self.current_token.is(TokenKind::SemiColon) {
-            end_pos = self.current_token.end;
-            self.advance();
-        }
+        end_pos = self.consume_semicolon(end_pos);
 
         Ok(ReturnStatement {
             node: Node::new(start_pos, end_pos),
@@ -915,50 +3077,201 @@ impl<'a> Parser<'a> {
     fn parse_assignment_expression(
         &mut self,
         lhs: Expression,
+        operator: AssignmentOperator,
     ) -> Result<AssignmentExpression, ParserErrorInfo> {
-        let operator: AssignmentOperator = if self.current_token.is_operator() {
-            Ok(self.current_token.kind.as_operator().unwrap())
-        } else {
-            throw_error!(InternalError)
-        }?;
+        let start_pos = lhs.node().start;
+
+        // Only `=` can destructure, like `[a, b] = [b, a]`
+        let left = match lhs {
+            Expression::Identifier(id) => Pattern::Identifier(id),
+            Expression::MemberExpression(member) => Pattern::MemberExpression(member),
+            lhs if matches!(operator, AssignmentOperator::Assign) => {
+                self.reinterpret_cover_as_pattern(lhs)?
+            }
+            lhs => return Err(Self::invalid_assignment_target(*lhs.node())),
+        };
+
+        self.advance(); // Consume operator token
+
+        let expr = self.parse_expression()?;
+
+        Ok(AssignmentExpression {
+            node: Node::new(start_pos, expr.node().end),
+            operator,
+            left,
+            right: expr,
+        })
+    }
+
+    /// Reinterprets an expression as a pattern with `reinterpret_as_pattern`, which makes the
+    /// default values of its shorthand properties valid, like the `a = 1` of `{ a = 1 } = obj`
+    fn reinterpret_cover_as_pattern(
+        &mut self,
+        expr: Expression,
+    ) -> Result<Pattern, ParserErrorInfo> {
+        let node = *expr.node();
+        self.cover_initializers
+            .retain(|token| token.start < node.start || token.end > node.end);
+
+        Self::reinterpret_as_pattern(expr)
+    }
+
+    /// Reinterprets an expression as the pattern it turned out to be, once it's followed by `=`.
+    /// An object or array literal is only known to be a pattern at that point, like in `[a, b] = [b, a]`.
+    fn reinterpret_as_pattern(expr: Expression) -> Result<Pattern, ParserErrorInfo> {
+        match expr {
+            Expression::Identifier(id) => Ok(Pattern::Identifier(id)),
+            Expression::MemberExpression(member) => Ok(Pattern::MemberExpression(member)),
+            // Only a variable or a property can be parenthesised, like `(a) = 1`
+            Expression::ParenthesisExpression(paren) => match paren.expression {
+                expr @ (Expression::Identifier(_) | Expression::MemberExpression(_)) => {
+                    Self::reinterpret_as_pattern(expr)
+                }
+                _ => Err(Self::invalid_assignment_target(paren.node)),
+            },
+            Expression::ArrayExpression(array) => {
+                let last_index = array.elements.len().saturating_sub(1);
+                let elements = array
+                    .elements
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, element)| match element {
+                        // A hole stays a hole, like in `[, b] = arr`
+                        None => Ok(None),
+                        Some(ArrayElement::Expression(expr)) => {
+                            Self::reinterpret_as_pattern(expr).map(Some)
+                        }
+                        Some(ArrayElement::SpreadElement(spread)) if i == last_index => {
+                            Self::reinterpret_as_rest_element(spread).map(|rest| Some(rest.into()))
+                        }
+                        Some(ArrayElement::SpreadElement(spread)) => {
+                            Err(Self::rest_element_not_last(spread.node))
+                        }
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(ArrayPattern {
+                    node: array.node,
+                    elements,
+                }
+                .into())
+            }
+            Expression::ObjectExpression(object) => {
+                let last_index = object.properties.len().saturating_sub(1);
+                let properties = object
+                    .properties
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, property)| match property {
+                        ObjectExpressionProperty::Property(property) => {
+                            Self::reinterpret_as_pattern_property(property)
+                        }
+                        ObjectExpressionProperty::SpreadElement(spread) if i == last_index => {
+                            Self::reinterpret_as_rest_element(spread)
+                                .map(ObjectPatternProperty::from)
+                        }
+                        ObjectExpressionProperty::SpreadElement(spread) => {
+                            Err(Self::rest_element_not_last(spread.node))
+                        }
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(ObjectPattern {
+                    node: object.node,
+                    properties,
+                }
+                .into())
+            }
+            // A default value, like the `b = 1` of `[a, b = 1] = arr`
+            Expression::AssignmentExpression(assign)
+                if matches!(assign.operator, AssignmentOperator::Assign) =>
+            {
+                Ok(AssignmentPattern {
+                    node: assign.node,
+                    left: assign.left,
+                    right: assign.right,
+                }
+                .into())
+            }
+            expr => Err(Self::invalid_assignment_target(*expr.node())),
+        }
+    }
 
-        self.advance(); // Consume operator token
+    /// Reinterprets a property of an object literal as a property of an object pattern.
+    /// Methods, getters and setters can't be assigned to.
+    fn reinterpret_as_pattern_property(
+        property: Property,
+    ) -> Result<ObjectPatternProperty, ParserErrorInfo> {
+        if property.method || property.kind != PropertyKind::Init {
+            return Err(Self::invalid_assignment_target(property.node));
+        }
 
-        let expr = self.parse_expression()?;
+        Ok(AssignmentProperty {
+            node: property.node,
+            key: property.key,
+            value: Self::reinterpret_as_pattern(property.value)?,
+            shorthand: property.shorthand,
+            computed: property.computed,
+        }
+        .into())
+    }
 
-        Ok(AssignmentExpression {
-            node: Node::new(lhs.node().start, expr.node().end),
-            left: lhs,
-            right: expr,
-            operator,
+    /// Reinterprets a spread element as the rest element it turned out to be, like the `...b` of `[a, ...b] = arr`
+    fn reinterpret_as_rest_element(spread: SpreadElement) -> Result<RestElement, ParserErrorInfo> {
+        Ok(RestElement {
+            node: spread.node,
+            argument: Self::reinterpret_as_pattern(spread.argument)?,
         })
     }
 
+    fn invalid_assignment_target(node: Node) -> ParserErrorInfo {
+        parser_error!(InvalidAssignmentTarget).with_label(node, "this can't be assigned to")
+    }
+
+    fn rest_element_not_last(node: Node) -> ParserErrorInfo {
+        parser_error!(RestElementNotLast).with_label(node, "rest element here")
+    }
+
     /// Parses an array literal, such as [42]
     fn parse_array_literal(&mut self) -> Result<ArrayExpression, ParserErrorInfo> {
         let start_pos = self.current_token.start;
         self.advance(); // Consume "[" token
 
-        let mut items: Vec<Expression> = Vec::new();
+        let mut elements: Vec<Option<ArrayElement>> = Vec::new();
 
         loop {
             if self.current_token.is(TokenKind::CloseBracket) {
                 break;
             }
 
-            let expr = self.parse_expression()?;
-            items.push(expr);
+            // A hole, like the second item of `[a, , b]`
+            if self.current_token.is(TokenKind::Comma) {
+                self.advance(); // Consume "," token
+                elements.push(None);
+                continue;
+            }
+
+            let item = if self.current_token.is(TokenKind::Ellipsis) {
+                self.parse_spread_element()?.into()
+            } else {
+                self.parse_expression()?.into()
+            };
+            elements.push(Some(item));
 
             match self.current_token.kind {
                 TokenKind::Comma => self.advance(),
                 TokenKind::CloseBracket => break,
-                _ => throw_error!(InvalidToken),
+                _ => {
+                    return Err(parser_error!(UnclosedBracket)
+                        .with_expected(&[TokenKind::Comma, TokenKind::CloseBracket])
+                        .with_label(Node::new(start_pos, start_pos + 1), "unmatched `[` here"))
+                }
             }
         }
 
         let arr = ArrayExpression {
             node: Node::new(start_pos, self.current_token.end), // Includes "[" and "]" tokens
-            items,
+            elements,
         };
 
         self.advance(); // Consume "]" token
@@ -971,101 +3284,98 @@ impl<'a> Parser<'a> {
         let start_pos = self.current_token.start;
         self.advance(); // Consume "{" token
 
-        let mut items = Vec::new();
+        let mut properties = Vec::new();
 
         loop {
             if self.current_token.is(TokenKind::CloseBrace) {
                 break;
             }
 
-            let item: ObjectItem = match self.current_token.kind {
-                TokenKind::String => {
-                    let key = StringLiteral {
+            let peek_kind = self.lexer.peek_token().kind;
+            let property: ObjectExpressionProperty = match self.current_token.kind {
+                TokenKind::Ellipsis => self.parse_spread_element()?.into(),
+                // A method, like `greet(name) {}`
+                TokenKind::Identifier if peek_kind == TokenKind::OpenParen => {
+                    self.parse_method()?.into()
+                }
+                // An async method, like `async load() {}`, rather than a property named `async`
+                TokenKind::Keyword
+                    if self.current_keyword()? == Keyword::Async
+                        && matches!(peek_kind, TokenKind::Identifier | TokenKind::Operator) =>
+                {
+                    self.parse_method()?.into()
+                }
+                // A generator method, like `*items() {}`
+                TokenKind::Operator if self.is_operator(BinaryOperator::Mult) => {
+                    self.parse_method()?.into()
+                }
+                // A shorthand property, like `{ name }`
+                TokenKind::Identifier if peek_kind != TokenKind::Colon => {
+                    let id = Identifier {
                         node: Node::new(self.current_token.start, self.current_token.end),
-                        value: self.current_token.value.consume_string(),
-                    }
-                    .into();
-                    self.advance(); // Consume String token
-                    self.expect_and_consume_token(TokenKind::Colon)?;
-                    let value = self.parse_expression()?;
-                    ObjectItem::KV(KV { key, value })
-                }
-                TokenKind::Identifier => {
-                    match self.lexer.peek_token().kind {
-                        TokenKind::OpenParen => ObjectItem::Method(self.parse_method()?),
-                        TokenKind::Colon => {
-                            let key = Identifier {
-                                node: Node::new(self.current_token.start, self.current_token.end),
-                                name: self.current_token.value.expect_identifier().clone(),
-                            }
-                            .into();
-                            self.advance(); // Consume Identifier token
-                            self.advance(); // Consume ":" token
-                            let value = self.parse_expression()?;
-                            ObjectItem::KV(KV { key, value })
-                        }
-                        _ => {
-                            let id = Identifier {
-                                node: Node::new(self.current_token.start, self.current_token.end),
-                                name: self.current_token.value.expect_identifier().clone(),
-                            };
-                            self.advance(); // Consume Identifier token
-                            ObjectItem::Identifier(id)
-                        }
+                        name: self.current_identifier()?,
+                    };
+                    self.advance(); // Consume Identifier token
+
+                    // A default value, like `{ a = 1 }`, is kept as an assignment until
+                    // the object literal is reinterpreted as a pattern
+                    let value: Expression = if self.is_operator(AssignmentOperator::Assign) {
+                        self.cover_initializers.push(self.current_token);
+                        self.parse_assignment_expression(
+                            id.clone().into(),
+                            AssignmentOperator::Assign,
+                        )?
+                        .into()
+                    } else {
+                        id.clone().into()
+                    };
+
+                    Property {
+                        node: Node::new(id.node.start, value.node().end),
+                        key: id.into(),
+                        value,
+                        kind: PropertyKind::Init,
+                        method: false,
+                        shorthand: true,
+                        computed: false,
                     }
+                    .into()
                 }
-                TokenKind::Keyword => match self.current_token.value.expect_keyword() {
-                    Keyword::Async => ObjectItem::Method(self.parse_method()?),
-                    Keyword::StringType
-                    | Keyword::NumberType
-                    | Keyword::BooleanType
-                    | Keyword::Type => {
-                        let key = StringLiteral {
-                            node: Node::new(self.current_token.start, self.current_token.end),
-                            value: self.current_token.value.expect_keyword().to_string(),
-                        }
-                        .into();
-                        self.advance(); // Consume keyword token
-                        self.expect_and_consume_token(TokenKind::Colon)?;
-                        let value = self.parse_expression()?;
-                        ObjectItem::KV(KV { key, value })
-                    }
-                    _ => throw_error!(InvalidToken),
-                },
-                TokenKind::OpenBracket => {
-                    let start_pos = self.current_token.start;
-
-                    self.advance(); // Consume "[" token
-                    let expression = self.parse_expression()?;
-                    self.expect_token_kind(TokenKind::CloseBracket)?;
-                    let key = Key::ComputedProperty(ComputedProperty {
-                        node: Node::new(start_pos, self.current_token.end),
-                        expression,
-                    });
-                    self.advance(); // Consume "]" token
-
+                _ => {
+                    let key_start = self.current_token.start;
+                    let (key, computed) = self.parse_property_key()?;
                     self.expect_and_consume_token(TokenKind::Colon)?;
-
                     let value = self.parse_expression()?;
 
-                    ObjectItem::KV(KV { key, value })
+                    Property {
+                        node: Node::new(key_start, value.node().end),
+                        key,
+                        value,
+                        kind: PropertyKind::Init,
+                        method: false,
+                        shorthand: false,
+                        computed,
+                    }
+                    .into()
                 }
-                TokenKind::Dot => throw_error!(Todo),
-                _ => throw_error!(InvalidToken),
             };
 
-            items.push(item);
+            properties.push(property);
 
             match self.current_token.kind {
                 TokenKind::CloseBrace => break,
                 TokenKind::Comma => self.advance(),
-                _ => throw_error!(InvalidToken),
+                _ => {
+                    return Err(parser_error!(UnclosedBrace)
+                        .with_expected(&[TokenKind::Comma, TokenKind::CloseBrace])
+                        .with_label(Node::new(start_pos, start_pos + 1), "unmatched `{` here"))
+                }
             };
         }
 
         let obj = ObjectExpression {
             node: Node::new(start_pos, self.current_token.end), // Include "{" and "}" tokens
-            items,
+            properties,
         };
 
         self.advance(); // Consume "}" token
@@ -1073,50 +3383,105 @@ impl<'a> Parser<'a> {
         Ok(obj)
     }
 
-    /// Parses binary operations (e.g., `+`, `-`, `*`, `/`, `&&`, `||`).
+    /// Parses the key of a property in an object literal, and returns whether it's computed, like `[key]`.
+    /// Any keyword can be the key of a property, like in `{ type: "a" }`.
+    fn parse_property_key(&mut self) -> Result<(Expression, bool), ParserErrorInfo> {
+        let node = Node::new(self.current_token.start, self.current_token.end);
+
+        let key = match self.current_token.kind {
+            TokenKind::Identifier => {
+                let id = Identifier {
+                    node,
+                    name: self.current_identifier()?,
+                };
+                self.advance(); // Consume Identifier token
+                id.into()
+            }
+            TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => {
+                let id = Identifier {
+                    node,
+                    name: Atom::from(self.current_token.text(self.source)),
+                };
+                self.advance(); // Consume keyword token
+                id.into()
+            }
+            TokenKind::String | TokenKind::Number | TokenKind::BigInt => {
+                self.parse_literal()?.into()
+            }
+            TokenKind::OpenBracket => {
+                self.advance(); // Consume "[" token
+                let expr = self.parse_expression()?;
+                self.expect_closing_token(
+                    TokenKind::CloseBracket,
+                    node.start,
+                    parser_error!(UnclosedBracket),
+                )?;
+                return Ok((expr, true));
+            }
+            _ => throw_error!(InvalidToken),
+        };
+
+        Ok((key, false))
+    }
+
+    /// Parses binary and logical operations (e.g., `+`, `-`, `*`, `/`, `&&`, `||`),
+    /// as long as their operators bind tighter than `min_precedence`.
     fn parse_binary_expression(
         &mut self,
-        left: Option<Expression>,
-        precedence: u8,
-    ) -> Result<BinaryExpression, ParserErrorInfo> {
-        let mut left = match left {
-            Some(expr) => expr,
-            None => self.parse_primary_expression()?,
-        };
-        let start_pos = left.node().start;
+        min_precedence: u8,
+    ) -> Result<Expression, ParserErrorInfo> {
+        let mut left = self.parse_unary_expression()?;
+
+        loop {
+            self.rescan_greater_than();
 
-        while let Some(op_precedence) = self.current_token.kind.get_operator_precedence() {
-            if op_precedence <= precedence {
+            // `in` is lexed as a keyword, and `instanceof` as an identifier
+            let operator = match self.current_token.value {
+                TokenValue::Operator(operator @ (Operator::Binary(_) | Operator::Logical(_))) => {
+                    operator
+                }
+                TokenValue::Keyword(Keyword::In) if self.in_allowed => {
+                    Operator::Binary(BinaryOperator::In)
+                }
+                _ if self.is_contextual_keyword("instanceof") => {
+                    Operator::Binary(BinaryOperator::Instanceof)
+                }
+                _ => break,
+            };
+            let precedence = operator.precedence();
+            if precedence <= min_precedence {
                 break;
             }
-
-            let operator = self.current_token.kind.as_operator().unwrap(); // Safe unwrap because of .get_operator_precedence()
             self.advance(); // Consume operator token
 
-            let mut right = self.parse_primary_expression()?;
+            // `**` is right-associative, so `a ** b ** c` is `a ** (b ** c)`
+            let right = if operator == Operator::Binary(BinaryOperator::Power) {
+                self.parse_binary_expression(precedence - 1)?
+            } else {
+                self.parse_binary_expression(precedence)?
+            };
 
-            if let Some(next_precedence) = self.current_token.kind.get_operator_precedence() {
-                if next_precedence > op_precedence {
-                    right = self
-                        .parse_binary_expression(Some(right), op_precedence)?
-                        .into();
+            let node = Node::new(left.node().start, right.node().end);
+            left = match operator {
+                Operator::Logical(operator) => LogicalExpression {
+                    node,
+                    operator,
+                    left,
+                    right,
                 }
-            }
-
-            left = BinaryExpression {
-                node: Node::new(start_pos, right.node().end),
-                left,
-                right,
-                operator,
-            }
-            .into();
+                .into(),
+                Operator::Binary(operator) => BinaryExpression {
+                    node,
+                    operator,
+                    left,
+                    right,
+                }
+                .into(),
+                _ => unreachable!(),
+            };
         }
 
-        if let Expression::BinaryExpression(bin_exp) = left {
-            Ok(*bin_exp)
-        } else {
-            throw_error!(InvalidToken) // Only happens if we never enter the while loop
-        }
+        Ok(left)
     }
 
     /// Parses a function or method call
@@ -1124,33 +3489,71 @@ impl<'a> Parser<'a> {
         &mut self,
         callee: Expression,
     ) -> Result<CallExpression, ParserErrorInfo> {
+        let (arguments, end_pos) = self.parse_arguments()?;
+        let start_pos = callee.node().start;
+
+        // A call to the parent class's constructor, like `super(name)`
+        let callee = match callee {
+            Expression::Super(sup) => CallExpressionCallee::Super(*sup),
+            callee => CallExpressionCallee::Expression(callee),
+        };
+
+        Ok(CallExpression {
+            node: Node::new(start_pos, end_pos),
+            callee,
+            arguments,
+        })
+    }
+
+    /// Parses the arguments of a call or of a `new` expression, like `(a, ...b)`, and returns where they end
+    fn parse_arguments<T: From<Expression> + From<SpreadElement>>(
+        &mut self,
+    ) -> Result<(Vec<T>, usize), ParserErrorInfo> {
+        let open_paren = Node::new(self.current_token.start, self.current_token.end);
         self.expect_and_consume_token(TokenKind::OpenParen)?;
 
-        let mut arguments: Vec<Expression> = Vec::new();
+        let mut arguments: Vec<T> = Vec::new();
 
         loop {
             if self.current_token.is(TokenKind::CloseParen) {
                 break;
             }
 
-            let expr = self.parse_expression()?;
-            arguments.push(expr);
+            let argument = if self.current_token.is(TokenKind::Ellipsis) {
+                self.parse_spread_element()?.into()
+            } else {
+                self.parse_expression()?.into()
+            };
+            arguments.push(argument);
 
             match self.current_token.kind {
                 TokenKind::Comma => self.advance(), // Consume "," token
                 TokenKind::CloseParen => break,
-                _ => throw_error!(InvalidToken),
+                _ => {
+                    return Err(parser_error!(ExpectedCloseParenAfterArguments)
+                        .with_expected(&[TokenKind::Comma, TokenKind::CloseParen])
+                        .with_label(open_paren, "unmatched `(` here"))
+                }
             }
         }
 
-        let expr = CallExpression {
-            node: Node::new(callee.node().start, self.current_token.end),
-            callee,
-            arguments,
-        };
-
+        let end_pos = self.current_token.end;
         self.advance(); // Consume ")" token
-        Ok(expr)
+
+        Ok((arguments, end_pos))
+    }
+
+    /// Parses a spread element, like the `...b` of `[a, ...b]`, `{ ...b }` or `f(...b)`
+    fn parse_spread_element(&mut self) -> Result<SpreadElement, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "..." token
+
+        let argument = self.parse_expression()?;
+
+        Ok(SpreadElement {
+            node: Node::new(start_pos, argument.node().end),
+            argument,
+        })
     }
 
     /// Parses member access expressions (e.g., `obj.prop` or `obj[prop]`).
@@ -1158,18 +3561,40 @@ impl<'a> Parser<'a> {
         &mut self,
         object: Expression,
     ) -> Result<MemberExpression, ParserErrorInfo> {
-        let property: MemberProperty;
+        let property: Expression;
         let end_pos: usize;
+        let computed: bool;
 
         match self.current_token.kind {
             TokenKind::Dot => {
                 self.advance(); // Consume "." token
-                self.expect_token_kind(TokenKind::Identifier)?;
 
-                property = MemberProperty::Identifier(Identifier {
-                    node: Node::new(self.current_token.start, self.current_token.end),
-                    name: self.current_token.value.expect_identifier().clone(),
-                });
+                let node = Node::new(self.current_token.start, self.current_token.end);
+
+                // Any name can follow a `.`, including keywords, like `a.default`,
+                // and private names inside a class, like `this.#count`
+                property = match self.current_token.kind {
+                    TokenKind::Identifier => Identifier {
+                        node,
+                        name: self.current_identifier()?,
+                    }
+                    .into(),
+                    TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => Identifier {
+                        node,
+                        name: Atom::from(self.current_token.text(self.source)),
+                    }
+                    .into(),
+                    TokenKind::PrivateName => PrivateIdentifier {
+                        node,
+                        name: Atom::from(self.current_token.identifier_name(self.source)),
+                    }
+                    .into(),
+                    _ => {
+                        return Err(parser_error!(ExpectedIdentifier)
+                            .with_expected(&[TokenKind::Identifier]))
+                    }
+                };
+                computed = false;
 
                 end_pos = self.current_token.end;
                 self.advance(); // Consume Identifier token
@@ -1177,16 +3602,16 @@ impl<'a> Parser<'a> {
             TokenKind::OpenBracket => {
                 let bracket_start = self.current_token.start;
                 self.advance(); // Consume "[" token
-                let expr = self.parse_expression()?;
+                property = self.parse_sequence_expression()?;
+                computed = true;
 
                 end_pos = self.current_token.end;
 
-                property = MemberProperty::Computed(ComputedProperty {
-                    node: Node::new(bracket_start, self.current_token.end),
-                    expression: expr,
-                });
-
-                self.expect_and_consume_token(TokenKind::CloseBracket)?;
+                self.expect_closing_token(
+                    TokenKind::CloseBracket,
+                    bracket_start,
+                    parser_error!(UnclosedBracket),
+                )?;
             }
             _ => unreachable!(),
         }
@@ -1195,16 +3620,75 @@ impl<'a> Parser<'a> {
             node: Node::new(object.node().start, end_pos),
             object,
             property,
+            computed,
         })
     }
 
+    /// Parses a template literal, like `` `a${b}c` ``.
+    /// Only tagged templates can contain invalid escape sequences, which leave their cooked text as `None`.
+    fn parse_template_literal(
+        &mut self,
+        is_tagged: bool,
+    ) -> Result<TemplateLiteral, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        let mut quasis = Vec::new();
+        let mut expressions = Vec::new();
+
+        loop {
+            let tail = matches!(
+                self.current_token.kind,
+                TokenKind::NoSubstitutionTemplate | TokenKind::TemplateTail
+            );
+            let value = self.current_token.template_value(self.source);
+
+            if value.cooked.is_none() && !is_tagged {
+                // The rest of the template is still valid, so this isn't fatal
+                self.errors.push(ParserError::new(
+                    parser_error!(InvalidTemplateEscape),
+                    self.current_token,
+                ));
+            }
+
+            // The text is between the opening "`" or "}", and the closing "`" or "${"
+            let text_start = self.current_token.start + 1;
+            let text_end = self.current_token.end - if tail { 1 } else { 2 };
+            quasis.push(TemplateElement {
+                node: Node::new(text_start, text_end.max(text_start)),
+                tail,
+                value,
+            });
+
+            let end_pos = self.current_token.end;
+            self.advance(); // Consume template token
+
+            if tail {
+                return Ok(TemplateLiteral {
+                    node: Node::new(start_pos, end_pos),
+                    quasis,
+                    expressions,
+                });
+            }
+
+            expressions.push(self.parse_sequence_expression()?);
+
+            if !matches!(
+                self.current_token.kind,
+                TokenKind::TemplateMiddle | TokenKind::TemplateTail
+            ) {
+                return Err(parser_error!(UnclosedTemplateSubstitution)
+                    .with_expected(&[TokenKind::TemplateTail])
+                    .with_label(Node::new(end_pos - 2, end_pos), "unmatched `${` here"));
+            }
+        }
+    }
+
     /// Parses literal values (e.g., strings, numbers, booleans).
     fn parse_literal(&mut self) -> Result<Literal, ParserErrorInfo> {
         match self.current_token.kind {
             TokenKind::String => {
                 let s = StringLiteral {
                     node: Node::new(self.current_token.start, self.current_token.end),
-                    value: self.current_token.value.consume_string(),
+                    value: self.current_token.string_value(self.source).into_owned(),
                 };
 
                 self.advance(); // Consume String token
@@ -1213,16 +3697,33 @@ impl<'a> Parser<'a> {
             TokenKind::Number => {
                 let n = NumberLiteral {
                     node: Node::new(self.current_token.start, self.current_token.end),
-                    value: self.current_token.value.expect_number(),
+                    value: self
+                        .current_token
+                        .value
+                        .as_number()
+                        .ok_or_else(|| parser_error!(InternalError))?,
                 };
 
                 self.advance(); // Consume Number token
                 Ok(Literal::NumberLiteral(n))
             }
+            TokenKind::BigInt => {
+                let n = BigIntLiteral {
+                    node: Node::new(self.current_token.start, self.current_token.end),
+                    value: self.current_token.bigint_value(self.source),
+                };
+
+                self.advance(); // Consume BigInt token
+                Ok(Literal::BigIntLiteral(n))
+            }
             TokenKind::Boolean => {
                 let b = BooleanLiteral {
                     node: Node::new(self.current_token.start, self.current_token.end),
-                    value: self.current_token.value.expect_boolean(),
+                    value: self
+                        .current_token
+                        .value
+                        .as_boolean()
+                        .ok_or_else(|| parser_error!(InternalError))?,
                 };
 
                 self.advance(); // Consume Boolean token
@@ -1248,8 +3749,8 @@ impl<'a> Parser<'a> {
     ) -> Result<EnumStatement, ParserErrorInfo> {
         let start_pos = self.current_token.start;
 
-        if self.current_token.value.expect_keyword() != Keyword::Enum {
-            self.advance(); // Consume "enum" or "declare" keyword tok
+        if self.current_keyword()? != Keyword::Enum {
+            self.advance(); // Consume "const" or "declare" keyword token
         }
 
         self.advance(); // Consume "enum" keyword token
@@ -1257,7 +3758,7 @@ impl<'a> Parser<'a> {
         self.expect_token_kind(TokenKind::Identifier)?;
         let id = Identifier {
             node: Node::new(self.current_token.start, self.current_token.end),
-            name: self.current_token.value.expect_identifier().clone(),
+            name: self.current_identifier()?,
         };
         self.advance(); // Consume Identifier token
 
@@ -1274,11 +3775,11 @@ impl<'a> Parser<'a> {
             self.expect_token_kind(TokenKind::Identifier)?;
             let id = Identifier {
                 node: Node::new(self.current_token.start, self.current_token.end),
-                name: self.current_token.value.expect_identifier().clone(),
+                name: self.current_identifier()?,
             };
             self.advance(); // Consume Identifier token
 
-            let (end_pos, init) = if self.current_token.is(TokenKind::Equals) {
+            let (end_pos, init) = if self.is_operator(AssignmentOperator::Assign) {
                 self.advance(); // Consume "=" token
                 let expr = self.parse_expression()?;
                 (expr.node().end, Some(expr))
@@ -1311,25 +3812,149 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a type alias, like `type Pair<T> = Array<T>;`
+    fn parse_type_alias_declaration(&mut self) -> Result<TypeAliasDeclaration, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "type" keyword token
+
+        let id = self.parse_binding_identifier()?;
+
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
+            Some(self.parse_type_parameter_declaration()?)
+        } else {
+            None
+        };
+
+        if !self.is_operator(AssignmentOperator::Assign) {
+            return Err(parser_error!(InvalidToken).with_expected(&[TokenKind::Operator]));
+        }
+        self.advance(); // Consume "=" token
+
+        let type_annotation = self.parse_type_value()?;
+        let end_pos = self.consume_semicolon(type_annotation.node().end);
+
+        Ok(TypeAliasDeclaration {
+            node: Node::new(start_pos, end_pos),
+            id,
+            type_parameters,
+            type_annotation,
+        })
+    }
+
+    /// Parses an interface, like `interface A<T> { a: T; readonly b?: string }`
+    fn parse_interface_declaration(&mut self) -> Result<InterfaceDeclaration, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+        self.advance(); // Consume "interface" keyword token
+
+        let id = self.parse_binding_identifier()?;
+
+        let type_parameters = if self.is_operator(BinaryOperator::LessThan) {
+            Some(self.parse_type_parameter_declaration()?)
+        } else {
+            None
+        };
+
+        let body_start = self.current_token.start;
+        self.expect_and_consume_token(TokenKind::OpenBrace)?;
+
+        let mut body = Vec::new();
+
+        loop {
+            match self.current_token.kind {
+                TokenKind::CloseBrace => break,
+                // Members can be separated by `;` or `,`
+                TokenKind::SemiColon | TokenKind::Comma => self.advance(),
+                TokenKind::Eof => {
+                    return Err(parser_error!(UnclosedBrace)
+                        .with_expected(&[TokenKind::CloseBrace])
+                        .with_label(Node::new(body_start, body_start + 1), "unmatched `{` here"))
+                }
+                _ => body.push(self.parse_property_signature()?),
+            }
+        }
+
+        let end_pos = self.current_token.end;
+        self.advance(); // Consume "}" token
+
+        Ok(InterfaceDeclaration {
+            node: Node::new(start_pos, end_pos),
+            id,
+            type_parameters,
+            body: InterfaceBody {
+                node: Node::new(body_start, end_pos),
+                body,
+            },
+        })
+    }
+
+    /// Parses a property of an interface, like `a: number` or `readonly b?: string`.
+    /// Any keyword can be the name of a property.
+    fn parse_property_signature(&mut self) -> Result<PropertySignature, ParserErrorInfo> {
+        let start_pos = self.current_token.start;
+
+        // `readonly` is only a modifier if a name follows it, otherwise it's the name itself
+        let readonly = self.is_contextual_keyword("readonly")
+            && matches!(
+                self.lexer.peek_token().kind,
+                TokenKind::Identifier | TokenKind::Keyword
+            );
+        if readonly {
+            self.advance(); // Consume "readonly" token
+        }
+
+        let key = match self.current_token.kind {
+            TokenKind::Identifier | TokenKind::Keyword | TokenKind::Boolean | TokenKind::Null => {
+                Identifier {
+                    node: Node::new(self.current_token.start, self.current_token.end),
+                    name: Atom::from(self.current_token.text(self.source)),
+                }
+            }
+            _ => return Err(parser_error!(InvalidToken).with_expected(&[TokenKind::Identifier])),
+        };
+        self.advance(); // Consume the name token
+        let mut end_pos = key.node.end;
+
+        let optional = self.current_token.is(TokenKind::QuestionMark);
+        if optional {
+            end_pos = self.current_token.end;
+            self.advance(); // Consume "?" token
+        }
+
+        let type_annotation = if self.current_token.is(TokenKind::Colon) {
+            let ann = self.parse_type_annotation()?;
+            end_pos = ann.node.end;
+            Some(ann)
+        } else {
+            None
+        };
+
+        Ok(PropertySignature {
+            node: Node::new(start_pos, end_pos),
+            key,
+            optional,
+            readonly,
+            type_annotation,
+        })
+    }
+
     fn parse_type_parameter_declaration(
         &mut self,
     ) -> Result<TypeParameterDeclaration, ParserErrorInfo> {
         let start_pos = self.current_token.start;
-        self.expect_and_consume_token(TokenKind::LessThan)?;
+        self.advance(); // Consume "<" token
 
         let mut parameters = Vec::new();
 
         loop {
-            match self.current_token.kind {
-                TokenKind::Comma if parameters.len() != 0 => {
-                    self.advance(); // Consume "," token
-                }
-                TokenKind::GreaterThan => break,
-                _ => {}
+            if self.is_operator(BinaryOperator::GreaterThan) {
+                break;
+            }
+            if self.current_token.is(TokenKind::Comma) && !parameters.is_empty() {
+                self.advance(); // Consume "," token
             }
 
             self.expect_token_kind(TokenKind::Identifier)?;
-            let name = self.current_token.value.expect_identifier().clone();
+            let name = self.current_identifier()?;
             parameters.push(TypeParameter {
                 node: Node::new(self.current_token.start, self.current_token.end),
                 id: Identifier {
@@ -1365,8 +3990,8 @@ impl<'a> Parser<'a> {
 
         match self.current_token.kind {
             TokenKind::Keyword => {
-                let Some(kw) = self.current_token.value.expect_keyword().as_type_keyword() else {
-                    throw_error!(InvalidToken);
+                let Some(kw) = self.current_keyword()?.as_type_keyword() else {
+                    throw_error!(ExpectedType);
                 };
 
                 let t = KeywordType {
@@ -1389,7 +4014,7 @@ impl<'a> Parser<'a> {
                 }
             }
             TokenKind::Identifier => {
-                let name = self.current_token.value.expect_identifier().clone();
+                let name = self.current_identifier()?;
                 let id = Identifier {
                     node: Node::new(start_pos, self.current_token.end),
                     name,
@@ -1411,47 +4036,52 @@ impl<'a> Parser<'a> {
                         self.expect_and_consume_token(TokenKind::CloseBracket)?;
                         Ok(arr.into())
                     }
-                    TokenKind::LessThan => {
-                        self.advance(); // Consume "<" token
-
-                        let mut type_params = Vec::new();
-
-                        loop {
-                            match self.current_token.kind {
-                                TokenKind::GreaterThan => break,
-                                TokenKind::Comma if type_params.len() != 0 => {
-                                    self.advance(); // Consume "," token
-                                }
-                                _ => {}
-                            }
-                            let inner = self.parse_type_value()?;
-                            type_params.push(inner);
-                        }
+                    _ if self.is_operator(BinaryOperator::LessThan) => {
+                        let type_params = self.parse_type_arguments()?;
 
-                        let t = TypeReference {
-                            node: Node::new(start_pos, self.current_token.end),
+                        Ok(TypeReference {
+                            node: Node::new(start_pos, self.previous_token_end),
                             type_name: id,
                             type_params: Some(type_params),
-                        };
-                        self.expect_and_consume_token(TokenKind::GreaterThan)?;
-                        Ok(t.into())
+                        }
+                        .into())
                     }
                     _ => Ok(TypeReference {
-                        node: id.node.clone(),
+                        node: id.node,
                         type_name: id,
                         type_params: None,
                     }
                     .into()),
                 }
             }
-            _ => throw_error!(InvalidToken),
+            _ => throw_error!(ExpectedType),
+        }
+    }
+
+    /// Parses the type arguments of a generic type, like `<string, number>` in `Map<string, number>`
+    fn parse_type_arguments(&mut self) -> Result<Vec<AstType>, ParserErrorInfo> {
+        self.advance(); // Consume "<" token
+
+        let mut type_args = Vec::new();
+
+        loop {
+            if self.is_operator(BinaryOperator::GreaterThan) {
+                break;
+            }
+            if self.current_token.is(TokenKind::Comma) && !type_args.is_empty() {
+                self.advance(); // Consume "," token
+            }
+            type_args.push(self.parse_type_value()?);
         }
+
+        self.advance(); // Consume ">" token
+        Ok(type_args)
     }
 
     fn parse_typeof_expression(&mut self) -> Result<TypeofExpression, ParserErrorInfo> {
         let start_pos = self.current_token.start;
         self.advance(); // Consume "typeof" token
-        let expr = self.parse_expression()?;
+        let expr = self.parse_unary_expression()?;
         Ok(TypeofExpression {
             node: Node::new(start_pos, expr.node().end),
             expression: expr,
@@ -1461,10 +4091,27 @@ impl<'a> Parser<'a> {
     fn parse_new_expression(&mut self) -> Result<NewExpression, ParserErrorInfo> {
         let start_pos = self.current_token.start;
         self.advance(); // Consume "new" token
-        let expr = self.parse_expression()?;
+
+        // The arguments belong to `new`, so the callee can't be a call itself, like `a.B` in `new a.B(c)`
+        let mut callee = self.parse_primary_expression()?;
+        while matches!(
+            self.current_token.kind,
+            TokenKind::Dot | TokenKind::OpenBracket
+        ) {
+            callee = self.parse_member_expression(callee)?.into();
+        }
+
+        // The parentheses can be left out when there are no arguments, like in `new Date`
+        let (arguments, end_pos) = if self.current_token.is(TokenKind::OpenParen) {
+            self.parse_arguments()?
+        } else {
+            (Vec::new(), callee.node().end)
+        };
+
         Ok(NewExpression {
-            node: Node::new(start_pos, expr.node().end),
-            expr: Box::new(expr),
+            node: Node::new(start_pos, end_pos),
+            callee,
+            arguments,
         })
     }
 }
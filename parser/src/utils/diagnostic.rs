@@ -0,0 +1,104 @@
+use ariadne::{Color, Fmt, Label, Report, ReportKind, Source};
+use lexer::{LineIndex, SourceFile};
+use serde::Serialize;
+
+/// A machine-readable error or warning, used by `--error-format=json`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub code: String,
+    pub severity: Severity,
+    pub message: String,
+    pub primary: DiagnosticSpan,
+    pub secondary: Vec<DiagnosticSpan>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiagnosticSpan {
+    pub start: Position,
+    pub end: Position,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Position {
+    /// Byte offset into the source code
+    pub offset: usize,
+    /// 1-based line number
+    pub line: u32,
+    /// 1-based column, counted in UTF-16 code units
+    pub column: u32,
+}
+
+impl Position {
+    pub fn new(index: &LineIndex, offset: usize) -> Self {
+        let pos = index.line_col(offset);
+        Self {
+            offset,
+            line: pos.line,
+            column: pos.column,
+        }
+    }
+}
+
+impl DiagnosticSpan {
+    pub fn new(index: &LineIndex, start: usize, end: usize, label: Option<String>) -> Self {
+        Self {
+            start: Position::new(index, start),
+            end: Position::new(index, end),
+            label,
+        }
+    }
+}
+
+impl Diagnostic {
+    /// Serializes the diagnostic as a single line of JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("diagnostics are always serializable")
+    }
+
+    /// Renders the diagnostic to stderr, with the source code it points at
+    pub fn print(&self, file: &SourceFile) {
+        let (kind, color) = match self.severity {
+            Severity::Error => (ReportKind::Error, Color::Red),
+            Severity::Warning => (ReportKind::Warning, Color::Yellow),
+        };
+
+        let label = |span: &DiagnosticSpan, color: Color| {
+            let label =
+                Label::new((&file.name, span.start.offset..span.end.offset)).with_color(color);
+            match &span.label {
+                Some(message) => label.with_message(message.fg(color)),
+                None => label,
+            }
+        };
+
+        let mut report = Report::build(
+            kind,
+            (
+                &file.name,
+                self.primary.start.offset..self.primary.end.offset,
+            ),
+        )
+        .with_code(&self.code)
+        .with_message(&self.message)
+        .with_label(label(&self.primary, color));
+
+        for secondary in self.secondary.iter() {
+            report.add_label(label(secondary, Color::Blue));
+        }
+        report.with_helps(self.notes.iter());
+
+        report
+            .finish()
+            .eprint((&file.name, Source::from(file.text())))
+            .unwrap();
+    }
+}
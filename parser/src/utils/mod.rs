@@ -1,3 +1,4 @@
+pub mod diagnostic;
 pub mod impl_from_macro;
 pub mod parser_error;
 pub mod throw_error;
@@ -1,53 +1,319 @@
-use ariadne::{ColorGenerator, Fmt, Label, Report, ReportKind, Source};
-use lexer::Token;
+use crate::{
+    ast_types::node_objects::Node,
+    utils::diagnostic::{Diagnostic, DiagnosticSpan, Severity},
+};
+use lexer::{LexError, LexErrorKind, SourceFile, Token, TokenKind, TokenValue};
 
 // A lightweight error that is used to construct the actual ParserError
 #[derive(Debug, PartialEq)]
 pub struct ParserErrorInfo {
     pub kind: ErrorKind,
+    pub expected: Vec<TokenKind>,
+    pub labels: Vec<ErrorLabel>,
     #[cfg(debug_assertions)]
     pub id: String,
 }
 
+impl ParserErrorInfo {
+    /// Sets the tokens that would have been valid where the error occurred
+    pub fn with_expected(mut self, expected: &[TokenKind]) -> Self {
+        self.expected = expected.to_vec();
+        self
+    }
+
+    /// Adds a secondary label, pointing at a location that helps explain the error
+    pub fn with_label(mut self, node: Node, message: impl Into<String>) -> Self {
+        self.labels.push(ErrorLabel {
+            node,
+            message: message.into(),
+        });
+        self
+    }
+}
+
+/// A secondary location of an error, like the unmatched `(` of a missing `)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorLabel {
+    pub node: Node,
+    pub message: String,
+}
+
 /// The actual error returned by the parser
 #[derive(Debug, PartialEq)]
 pub struct ParserError {
     pub kind: ErrorKind,
+    /// The token that was found where the error occurred
     pub token: Token,
+    /// The tokens that would have been valid instead of `token`
+    pub expected: Vec<TokenKind>,
+    pub labels: Vec<ErrorLabel>,
+    /// Where the parser raised the error, like `parser/src/parser.rs:42`, for debugging the parser.
+    /// It's empty in release builds.
     pub id: String,
 }
+
 impl ParserError {
-    pub fn print(&self, source: &str) {
-        let mut colors = ColorGenerator::new();
+    pub fn new(info: ParserErrorInfo, token: Token) -> Self {
+        Self {
+            kind: info.kind,
+            token,
+            expected: info.expected,
+            labels: info.labels,
+            #[cfg(debug_assertions)]
+            id: info.id,
+            #[cfg(not(debug_assertions))]
+            id: String::new(),
+        }
+    }
+
+    /// Describes the token that was found, e.g. "found `;`"
+    pub fn found(&self, source: &str) -> String {
+        match self.token.kind {
+            TokenKind::Eof => "found end of file".to_owned(),
+            _ => match source.get(self.token.start..self.token.end) {
+                Some(text) if !text.is_empty() => format!("found `{}`", text),
+                _ => format!("found {}", self.token.kind),
+            },
+        }
+    }
 
-        let a = colors.next();
+    /// Lists the tokens that would have been valid, e.g. "expected `,` or `)`"
+    pub fn expected_message(&self) -> Option<String> {
+        let (last, rest) = self.expected.split_last()?;
+        let rest = rest
+            .iter()
+            .map(|kind| kind.to_string())
+            .collect::<Vec<String>>();
 
-        let msg = match self.kind {
-            ErrorKind::Todo => "TODO: This has not yet been implemented",
-            ErrorKind::InternalError => "Internal error",
-            ErrorKind::InvalidToken => "Invalid token",
+        if rest.is_empty() {
+            Some(format!("expected {}", last))
+        } else {
+            Some(format!("expected {} or {}", rest.join(", "), last))
+        }
+    }
+
+    pub fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        let index = file.line_index();
+        let label = match self.expected_message() {
+            Some(expected) => format!("{}, {}", expected, self.found(file.text())),
+            None => self.found(file.text()),
         };
 
-        Report::build(
-            ReportKind::Error,
-            (&self.id, self.token.start..self.token.end),
-        )
-        .with_code(3) // TODO: Errors should have a unique code
-        .with_message(msg)
-        .with_label(
-            Label::new((&self.id, self.token.start..self.token.end))
-                .with_message(msg.fg(a))
-                .with_color(a),
-        )
-        .finish()
-        .eprint((&self.id, Source::from(&source)))
-        .unwrap();
+        Diagnostic {
+            code: self.kind.code().to_owned(),
+            severity: Severity::Error,
+            message: self.kind.message().to_owned(),
+            primary: DiagnosticSpan::new(index, self.token.start, self.token.end, Some(label)),
+            secondary: self
+                .labels
+                .iter()
+                .map(|label| {
+                    DiagnosticSpan::new(
+                        index,
+                        label.node.start,
+                        label.node.end,
+                        Some(label.message.clone()),
+                    )
+                })
+                .collect(),
+            notes: self
+                .kind
+                .help()
+                .map(|help| help.to_owned())
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    pub fn print(&self, file: &SourceFile) {
+        self.to_diagnostic(file).print(file)
+    }
+}
+
+impl From<LexError> for ParserError {
+    fn from(err: LexError) -> Self {
+        Self {
+            kind: ErrorKind::Lex(err.kind),
+            token: Token {
+                kind: TokenKind::Invalid,
+                value: TokenValue::None,
+                start: err.start,
+                end: err.end,
+                newline_before: false,
+            },
+            expected: Vec::new(),
+            labels: Vec::new(),
+            id: String::new(),
+        }
     }
 }
 
+/// Every kind of syntax error, each with a stable code.
+/// Codes must never be reused or renumbered, since tooling may depend on them.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorKind {
+    /// P0001
+    ExpectedCloseParenAfterArguments,
+    /// P0002
+    ExpectedCloseParenAfterParameters,
+    /// P0003
+    UnclosedParen,
+    /// P0004
+    UnclosedBracket,
+    /// P0005
+    UnclosedBrace,
+    /// P0006
+    ExpectedExpression,
+    /// P0007
+    ExpectedIdentifier,
+    /// P0008
+    ExpectedType,
+    /// P0009
+    ExpectedStatement,
+    /// P0010
+    InvalidToken,
+    /// P0011
+    UnclosedTemplateSubstitution,
+    /// P0012
+    InvalidTemplateEscape,
+    /// P0013
+    MissingSemicolon,
+    /// P0014
+    NewlineAfterThrow,
+    /// P0015
+    ExpectedClassMember,
+    /// P0016
+    ExpectedFrom,
+    /// P0017
+    ExpectedExportDeclaration,
+    /// P0018
+    MissingInitializer,
+    /// P0019
+    InvalidAssignmentTarget,
+    /// P0020
+    RestElementNotLast,
+    /// P0021
+    AwaitOutsideAsync,
+    /// P0022
+    ExpectedCatchOrFinally,
+    /// P0023
+    ShorthandPropertyDefault,
+    /// An error found by the lexer, which has its own `L` codes
+    Lex(LexErrorKind),
+    /// P9998
     Todo,
+    /// P9999
     InternalError,
-    InvalidToken,
+}
+
+impl ErrorKind {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ExpectedCloseParenAfterArguments => "P0001",
+            Self::ExpectedCloseParenAfterParameters => "P0002",
+            Self::UnclosedParen => "P0003",
+            Self::UnclosedBracket => "P0004",
+            Self::UnclosedBrace => "P0005",
+            Self::ExpectedExpression => "P0006",
+            Self::ExpectedIdentifier => "P0007",
+            Self::ExpectedType => "P0008",
+            Self::ExpectedStatement => "P0009",
+            Self::InvalidToken => "P0010",
+            Self::UnclosedTemplateSubstitution => "P0011",
+            Self::InvalidTemplateEscape => "P0012",
+            Self::MissingSemicolon => "P0013",
+            Self::NewlineAfterThrow => "P0014",
+            Self::ExpectedClassMember => "P0015",
+            Self::ExpectedFrom => "P0016",
+            Self::ExpectedExportDeclaration => "P0017",
+            Self::MissingInitializer => "P0018",
+            Self::InvalidAssignmentTarget => "P0019",
+            Self::RestElementNotLast => "P0020",
+            Self::AwaitOutsideAsync => "P0021",
+            Self::ExpectedCatchOrFinally => "P0022",
+            Self::ShorthandPropertyDefault => "P0023",
+            Self::Lex(kind) => kind.code(),
+            Self::Todo => "P9998",
+            Self::InternalError => "P9999",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::ExpectedCloseParenAfterArguments => "expected `)` after arguments",
+            Self::ExpectedCloseParenAfterParameters => "expected `)` after parameters",
+            Self::UnclosedParen => "expected `)` to close `(`",
+            Self::UnclosedBracket => "expected `]` to close `[`",
+            Self::UnclosedBrace => "expected `}` to close `{`",
+            Self::ExpectedExpression => "expected an expression",
+            Self::ExpectedIdentifier => "expected an identifier",
+            Self::ExpectedType => "expected a type",
+            Self::ExpectedStatement => "expected a statement",
+            Self::InvalidToken => "unexpected token",
+            Self::UnclosedTemplateSubstitution => "expected `}` to close `${`",
+            Self::InvalidTemplateEscape => "invalid escape sequence in template literal",
+            Self::MissingSemicolon => "expected `;` after statement",
+            Self::NewlineAfterThrow => "line break after `throw`",
+            Self::ExpectedClassMember => "expected a class member",
+            Self::ExpectedFrom => "expected `from`",
+            Self::ExpectedExportDeclaration => "expected a declaration to export",
+            Self::MissingInitializer => "destructuring declaration without an initializer",
+            Self::InvalidAssignmentTarget => "invalid assignment target",
+            Self::RestElementNotLast => "rest element must be last",
+            Self::AwaitOutsideAsync => "`await` outside of an async function",
+            Self::ExpectedCatchOrFinally => "expected `catch` or `finally` after `try` block",
+            Self::ShorthandPropertyDefault => "default value in an object literal",
+            Self::Lex(kind) => kind.message(),
+            Self::Todo => "TODO: This has not yet been implemented",
+            Self::InternalError => "internal parser error",
+        }
+    }
+
+    pub fn help(&self) -> Option<&'static str> {
+        match self {
+            Self::ExpectedCloseParenAfterArguments => {
+                Some("arguments must be separated by `,` and followed by `)`")
+            }
+            Self::ExpectedCloseParenAfterParameters => {
+                Some("parameters must be separated by `,` and followed by `)`")
+            }
+            Self::ExpectedType => Some("types are written like `number`, `string[]` or `Array<T>`"),
+            Self::InvalidTemplateEscape => {
+                Some("only tagged templates can contain invalid escape sequences")
+            }
+            Self::MissingSemicolon => Some("statements on the same line must be separated by `;`"),
+            Self::NewlineAfterThrow => {
+                Some("the thrown expression must start on the same line as `throw`")
+            }
+            Self::ExpectedClassMember => {
+                Some("class members are methods like `foo() {}`, or fields like `foo = 1;`")
+            }
+            Self::ExpectedFrom => Some("the module is written like `from \"./module.js\"`"),
+            Self::ExpectedExportDeclaration => Some(
+                "exports are written like `export const a = 1;`, `export { a };` or `export default a;`",
+            ),
+            Self::MissingInitializer => {
+                Some("the value to destructure is written like `const { a } = obj;`")
+            }
+            Self::InvalidAssignmentTarget => {
+                Some("only variables, properties, and array or object patterns can be assigned to")
+            }
+            Self::RestElementNotLast => {
+                Some("nothing can follow `...rest`, not even a trailing comma")
+            }
+            Self::AwaitOutsideAsync => Some(
+                "`await` is only allowed in async functions, like `async function f() {}`, and at the top level of modules",
+            ),
+            Self::ExpectedCatchOrFinally => {
+                Some("a `try` block is followed by `catch (e) {}`, `finally {}`, or both")
+            }
+            Self::ShorthandPropertyDefault => Some(
+                "`{ a = 1 }` is only valid as a pattern, like in `({ a = 1 } = obj)`; otherwise write `{ a: 1 }`",
+            ),
+            Self::Lex(kind) => kind.help(),
+            Self::Todo => Some("this syntax is valid, but not yet supported by the parser"),
+            Self::InternalError => Some("this is a bug in the parser, please report it"),
+            _ => None,
+        }
+    }
 }
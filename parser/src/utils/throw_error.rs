@@ -1,12 +1,22 @@
+/// Constructs a `ParserErrorInfo` of the given `ErrorKind`.
+/// Use the `with_*` methods to add the expected tokens and secondary labels.
 #[macro_export]
-macro_rules! throw_error {
+macro_rules! parser_error {
     ($kind:ident) => {{
-        use crate::utils::parser_error::{ErrorKind, ParserErrorInfo};
-        let err = ParserErrorInfo {
+        use $crate::utils::parser_error::{ErrorKind, ParserErrorInfo};
+        ParserErrorInfo {
             kind: ErrorKind::$kind,
+            expected: Vec::new(),
+            labels: Vec::new(),
             #[cfg(debug_assertions)]
             id: concat!(file!(), ":", line!()).to_owned(),
-        };
-        return Err(err);
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! throw_error {
+    ($kind:ident) => {{
+        return Err($crate::parser_error!($kind));
     }};
 }
@@ -0,0 +1,362 @@
+//! Traits for traversing the AST.
+//!
+//! Every node in `ast_types` has a `visit_*` method, which by default calls the
+//! matching `walk_*` function to visit the children of the node. A pass overrides
+//! only the methods for the nodes it cares about, and calls the `walk_*` function
+//! from the override if it still wants to visit the children.
+//!
+//! `Visit` borrows the AST immutably, and `VisitMut` mutably. Their walk functions
+//! live in `visit::walk` and `visit::walk_mut` respectively.
+//!
+//! The children of a node are visited by its `Walk` impl, which is derived with
+//! `#[derive(Walk)]` and visits every field in order. A new kind of node only
+//! needs to derive `Walk` and be added to the list at the bottom of this file.
+
+use crate::ast_types::{
+    classes::{
+        class::{
+            Accessibility, ClassBody, ClassElement, ClassElementKey, MethodDefinition,
+            MethodDefinitionKind, PrivateIdentifier, PropertyDefinition, StaticBlock,
+        },
+        class_declaration::ClassDeclaration,
+        class_expression::ClassExpression,
+        meta_property::MetaProperty,
+    },
+    declarations::{
+        declaration::Declaration,
+        function_declaration::{FunctionDeclaration, Parameter},
+        interface_declaration::{InterfaceBody, InterfaceDeclaration, PropertySignature},
+        type_alias_declaration::TypeAliasDeclaration,
+        variable_declaration::{VariableDeclaration, VariableDeclarationKind, VariableDeclarator},
+    },
+    expressions::{
+        types::{
+            ArrayType, AstType, FnType, KeywordType, TypeAnnotation, TypeLiteral, TypeParameter,
+            TypeParameterDeclaration, TypeReference,
+        },
+        ArrayElement, ArrayExpression, ArrowFunctionExpression, ArrowFunctionExpressionBody,
+        AssignmentExpression, AwaitExpression, BigIntLiteral, BinaryExpression, BooleanLiteral,
+        CallExpression, CallExpressionArgument, CallExpressionCallee, ConditionalExpression,
+        Expression, FunctionExpression, Literal, LogicalExpression, MemberExpression,
+        NewExpression, NewExpressionArgument, NullLiteral, NumberLiteral, ObjectExpression,
+        ObjectExpressionProperty, ParenthesisExpression, RegexLiteral, SequenceExpression,
+        StringLiteral, Super, TaggedTemplateExpression, TemplateLiteral, TernaryExpression,
+        ThisExpression, TypeofExpression, UnaryExpression, UpdateExpression, YieldExpression,
+    },
+    identifier::Identifier,
+    literal,
+    modules::{
+        exports::{
+            AnonymousDefaultExportedClassDeclaration, AnonymousDefaultExportedFunctionDeclaration,
+            ExportAllDeclaration, ExportDefaultDeclaration, ExportDefaultDeclarationDeclaration,
+            ExportNamedDeclaration, ExportSpecifier,
+        },
+        import_or_export_declaration::ImportOrExportDeclaration,
+        imports::{
+            ImportDeclaration, ImportDeclarationSpecifier, ImportDefaultSpecifier,
+            ImportNamespaceSpecifier, ImportOrExportKind, ImportSpecifier,
+        },
+    },
+    node_objects::Node,
+    patterns::{
+        array_pattern::ArrayPattern,
+        assignment_pattern::AssignmentPattern,
+        object_pattern::{AssignmentProperty, ObjectPattern, ObjectPatternProperty},
+        pattern::Pattern,
+        rest_element::RestElement,
+    },
+    programs::program::{Program, ProgramBody, SourceType},
+    property::{Property, PropertyKind},
+    spread_element::SpreadElement,
+    statements::{
+        BlockStatement, BreakStatement, CatchClause, ContinueStatement, DebuggerStatement,
+        Directive, DoWhileStatement, EmptyStatement, EnumMember, EnumStatement,
+        ExpressionStatement, ForInOrOfLeft, ForInStatement, ForInit, ForOfStatement, ForStatement,
+        FunctionBody, FunctionBodyBody, IfStatement, LabeledStatement, ReturnStatement, Statement,
+        SwitchCase, SwitchStatement, ThrowStatement, TryStatement, WhileStatement, WithStatement,
+    },
+    template_element::TemplateElement,
+};
+use lexer::{
+    AssignmentOperator, BinaryOperator, Comment, LogicalOperator, RegexValue, TemplateValue,
+    TypeKeyword, UnaryOperator, UpdateOperator,
+};
+use string_cache::DefaultAtom as Atom;
+
+/// Visits the children of a node. Derived with `#[derive(Walk)]`.
+pub trait Walk {
+    fn walk<V: Visit + ?Sized>(&self, v: &mut V);
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V);
+}
+
+/// Passes a value to the matching `visit_*` method of a visitor.
+/// Values that aren't nodes, and nodes that are only visited through their parent
+/// (such as the variants of `Literal`), are leaves that aren't passed to the visitor.
+pub trait Visitable {
+    fn visit<V: Visit + ?Sized>(&self, v: &mut V);
+    fn visit_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V);
+}
+
+impl<T: Visitable> Visitable for Box<T> {
+    fn visit<V: Visit + ?Sized>(&self, v: &mut V) {
+        (**self).visit(v)
+    }
+
+    fn visit_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V) {
+        (**self).visit_mut(v)
+    }
+}
+
+impl<T: Visitable> Visitable for Option<T> {
+    fn visit<V: Visit + ?Sized>(&self, v: &mut V) {
+        if let Some(value) = self {
+            value.visit(v);
+        }
+    }
+
+    fn visit_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V) {
+        if let Some(value) = self {
+            value.visit_mut(v);
+        }
+    }
+}
+
+impl<T: Visitable> Visitable for Vec<T> {
+    fn visit<V: Visit + ?Sized>(&self, v: &mut V) {
+        for value in self {
+            value.visit(v);
+        }
+    }
+
+    fn visit_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V) {
+        for value in self {
+            value.visit_mut(v);
+        }
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Visitable for $ty {
+                fn visit<V: Visit + ?Sized>(&self, _v: &mut V) {}
+                fn visit_mut<V: VisitMut + ?Sized>(&mut self, _v: &mut V) {}
+            }
+        )*
+    };
+}
+
+impl_leaf!(
+    bool,
+    f64,
+    String,
+    Atom,
+    Node,
+    UnaryOperator,
+    UpdateOperator,
+    BinaryOperator,
+    AssignmentOperator,
+    LogicalOperator,
+    RegexValue,
+    TypeKeyword,
+    SourceType,
+    VariableDeclarationKind,
+    PropertyKind,
+    MethodDefinitionKind,
+    Accessibility,
+    ImportOrExportKind,
+    TemplateValue,
+    Comment,
+    StringLiteral,
+    BooleanLiteral,
+    NullLiteral,
+    NumberLiteral,
+    BigIntLiteral,
+    RegexLiteral,
+    // The source of an import or export
+    literal::Literal,
+);
+
+/// Generates the `Visit` and `VisitMut` traits, their walk functions,
+/// and the `Visitable` impls that dispatch to them, from one list of nodes
+macro_rules! define_visitors {
+    ($($method:ident, $walk_fn:ident, $ty:ty;)*) => {
+        pub trait Visit {
+            $(
+                fn $method(&mut self, node: &$ty) {
+                    walk::$walk_fn(self, node)
+                }
+            )*
+        }
+
+        pub trait VisitMut {
+            $(
+                fn $method(&mut self, node: &mut $ty) {
+                    walk_mut::$walk_fn(self, node)
+                }
+            )*
+        }
+
+        pub mod walk {
+            use super::*;
+
+            $(
+                pub fn $walk_fn<V: Visit + ?Sized>(v: &mut V, node: &$ty) {
+                    node.walk(v)
+                }
+            )*
+        }
+
+        pub mod walk_mut {
+            use super::*;
+
+            $(
+                pub fn $walk_fn<V: VisitMut + ?Sized>(v: &mut V, node: &mut $ty) {
+                    node.walk_mut(v)
+                }
+            )*
+        }
+
+        $(
+            impl Visitable for $ty {
+                fn visit<V: Visit + ?Sized>(&self, v: &mut V) {
+                    v.$method(self)
+                }
+
+                fn visit_mut<V: VisitMut + ?Sized>(&mut self, v: &mut V) {
+                    v.$method(self)
+                }
+            }
+        )*
+    };
+}
+
+define_visitors!(
+    visit_program, walk_program, Program;
+    visit_program_body, walk_program_body, ProgramBody;
+    visit_statement, walk_statement, Statement;
+    visit_expression, walk_expression, Expression;
+    visit_pattern, walk_pattern, Pattern;
+    visit_identifier, walk_identifier, Identifier;
+    visit_literal, walk_literal, Literal;
+
+    // Statements
+    visit_block_statement, walk_block_statement, BlockStatement;
+    visit_break_statement, walk_break_statement, BreakStatement;
+    visit_continue_statement, walk_continue_statement, ContinueStatement;
+    visit_debugger_statement, walk_debugger_statement, DebuggerStatement;
+    visit_directive, walk_directive, Directive;
+    visit_do_while_statement, walk_do_while_statement, DoWhileStatement;
+    visit_empty_statement, walk_empty_statement, EmptyStatement;
+    visit_enum_statement, walk_enum_statement, EnumStatement;
+    visit_enum_member, walk_enum_member, EnumMember;
+    visit_expression_statement, walk_expression_statement, ExpressionStatement;
+    visit_for_in_statement, walk_for_in_statement, ForInStatement;
+    visit_for_of_statement, walk_for_of_statement, ForOfStatement;
+    visit_for_in_or_of_left, walk_for_in_or_of_left, ForInOrOfLeft;
+    visit_for_statement, walk_for_statement, ForStatement;
+    visit_for_init, walk_for_init, ForInit;
+    visit_if_statement, walk_if_statement, IfStatement;
+    visit_labeled_statement, walk_labeled_statement, LabeledStatement;
+    visit_return_statement, walk_return_statement, ReturnStatement;
+    visit_switch_statement, walk_switch_statement, SwitchStatement;
+    visit_switch_case, walk_switch_case, SwitchCase;
+    visit_throw_statement, walk_throw_statement, ThrowStatement;
+    visit_try_statement, walk_try_statement, TryStatement;
+    visit_catch_clause, walk_catch_clause, CatchClause;
+    visit_while_statement, walk_while_statement, WhileStatement;
+    visit_with_statement, walk_with_statement, WithStatement;
+
+    // Declarations
+    visit_declaration, walk_declaration, Declaration;
+    visit_function_declaration, walk_function_declaration, FunctionDeclaration;
+    visit_function_body, walk_function_body, FunctionBody;
+    visit_function_body_body, walk_function_body_body, FunctionBodyBody;
+    visit_parameter, walk_parameter, Parameter;
+    visit_interface_declaration, walk_interface_declaration, InterfaceDeclaration;
+    visit_interface_body, walk_interface_body, InterfaceBody;
+    visit_property_signature, walk_property_signature, PropertySignature;
+    visit_type_alias_declaration, walk_type_alias_declaration, TypeAliasDeclaration;
+    visit_variable_declaration, walk_variable_declaration, VariableDeclaration;
+    visit_variable_declarator, walk_variable_declarator, VariableDeclarator;
+
+    // Expressions
+    visit_array_expression, walk_array_expression, ArrayExpression;
+    visit_array_element, walk_array_element, ArrayElement;
+    visit_arrow_function_expression, walk_arrow_function_expression, ArrowFunctionExpression;
+    visit_arrow_function_expression_body, walk_arrow_function_expression_body, ArrowFunctionExpressionBody;
+    visit_assignment_expression, walk_assignment_expression, AssignmentExpression;
+    visit_await_expression, walk_await_expression, AwaitExpression;
+    visit_binary_expression, walk_binary_expression, BinaryExpression;
+    visit_call_expression, walk_call_expression, CallExpression;
+    visit_call_expression_callee, walk_call_expression_callee, CallExpressionCallee;
+    visit_call_expression_argument, walk_call_expression_argument, CallExpressionArgument;
+    visit_conditional_expression, walk_conditional_expression, ConditionalExpression;
+    visit_function_expression, walk_function_expression, FunctionExpression;
+    visit_logical_expression, walk_logical_expression, LogicalExpression;
+    visit_member_expression, walk_member_expression, MemberExpression;
+    visit_meta_property, walk_meta_property, MetaProperty;
+    visit_new_expression, walk_new_expression, NewExpression;
+    visit_new_expression_argument, walk_new_expression_argument, NewExpressionArgument;
+    visit_object_expression, walk_object_expression, ObjectExpression;
+    visit_object_expression_property, walk_object_expression_property, ObjectExpressionProperty;
+    visit_property, walk_property, Property;
+    visit_parenthesis_expression, walk_parenthesis_expression, ParenthesisExpression;
+    visit_sequence_expression, walk_sequence_expression, SequenceExpression;
+    visit_spread_element, walk_spread_element, SpreadElement;
+    visit_super, walk_super, Super;
+    visit_tagged_template_expression, walk_tagged_template_expression, TaggedTemplateExpression;
+    visit_template_literal, walk_template_literal, TemplateLiteral;
+    visit_template_element, walk_template_element, TemplateElement;
+    visit_ternary_expression, walk_ternary_expression, TernaryExpression;
+    visit_this_expression, walk_this_expression, ThisExpression;
+    visit_typeof_expression, walk_typeof_expression, TypeofExpression;
+    visit_unary_expression, walk_unary_expression, UnaryExpression;
+    visit_update_expression, walk_update_expression, UpdateExpression;
+    visit_yield_expression, walk_yield_expression, YieldExpression;
+
+    // Patterns
+    visit_array_pattern, walk_array_pattern, ArrayPattern;
+    visit_object_pattern, walk_object_pattern, ObjectPattern;
+    visit_object_pattern_property, walk_object_pattern_property, ObjectPatternProperty;
+    visit_assignment_property, walk_assignment_property, AssignmentProperty;
+    visit_rest_element, walk_rest_element, RestElement;
+    visit_assignment_pattern, walk_assignment_pattern, AssignmentPattern;
+
+    // Classes
+    visit_class_declaration, walk_class_declaration, ClassDeclaration;
+    visit_class_expression, walk_class_expression, ClassExpression;
+    visit_class_body, walk_class_body, ClassBody;
+    visit_class_element, walk_class_element, ClassElement;
+    visit_class_element_key, walk_class_element_key, ClassElementKey;
+    visit_method_definition, walk_method_definition, MethodDefinition;
+    visit_property_definition, walk_property_definition, PropertyDefinition;
+    visit_static_block, walk_static_block, StaticBlock;
+    visit_private_identifier, walk_private_identifier, PrivateIdentifier;
+
+    // Modules
+    visit_import_or_export_declaration, walk_import_or_export_declaration, ImportOrExportDeclaration;
+    visit_import_declaration, walk_import_declaration, ImportDeclaration;
+    visit_import_declaration_specifier, walk_import_declaration_specifier, ImportDeclarationSpecifier;
+    visit_import_specifier, walk_import_specifier, ImportSpecifier;
+    visit_import_default_specifier, walk_import_default_specifier, ImportDefaultSpecifier;
+    visit_import_namespace_specifier, walk_import_namespace_specifier, ImportNamespaceSpecifier;
+    visit_export_named_declaration, walk_export_named_declaration, ExportNamedDeclaration;
+    visit_export_specifier, walk_export_specifier, ExportSpecifier;
+    visit_export_default_declaration, walk_export_default_declaration, ExportDefaultDeclaration;
+    visit_export_default_declaration_declaration, walk_export_default_declaration_declaration, ExportDefaultDeclarationDeclaration;
+    visit_anonymous_default_exported_function_declaration, walk_anonymous_default_exported_function_declaration, AnonymousDefaultExportedFunctionDeclaration;
+    visit_anonymous_default_exported_class_declaration, walk_anonymous_default_exported_class_declaration, AnonymousDefaultExportedClassDeclaration;
+    visit_export_all_declaration, walk_export_all_declaration, ExportAllDeclaration;
+
+    // Types
+    visit_type_annotation, walk_type_annotation, TypeAnnotation;
+    visit_type_parameter_declaration, walk_type_parameter_declaration, TypeParameterDeclaration;
+    visit_type_parameter, walk_type_parameter, TypeParameter;
+    visit_ast_type, walk_ast_type, AstType;
+    visit_keyword_type, walk_keyword_type, KeywordType;
+    visit_type_reference, walk_type_reference, TypeReference;
+    visit_array_type, walk_array_type, ArrayType;
+    visit_fn_type, walk_fn_type, FnType;
+    visit_type_literal, walk_type_literal, TypeLiteral;
+);
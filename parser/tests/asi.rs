@@ -0,0 +1,105 @@
+use lexer::TokenKind;
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::{estree, NodeConstructor};
+
+#[test]
+fn statements_without_semicolons() {
+    let code = "let a = 1\na = a + 2\nfoo(a)";
+    let ast = estree(code);
+
+    assert_eq!(ast["body"].as_array().unwrap().len(), 3);
+    assert_eq!(ast["body"][0]["type"], "VariableDeclaration");
+    assert_eq!(ast["body"][0]["end"], code.find_n("\n", 0).unwrap());
+    assert_eq!(ast["body"][2]["expression"]["type"], "CallExpression");
+}
+
+#[test]
+fn semicolon_before_close_brace() {
+    let ast = estree("function a() { return 1 }");
+    let body = &ast["body"][0]["body"]["body"];
+
+    assert_eq!(body[0]["type"], "ReturnStatement");
+    assert_eq!(body[0]["argument"]["value"], 1.0);
+}
+
+#[test]
+fn expression_continues_on_next_line() {
+    let ast = estree("let a = b\n  + c\n(d)");
+    let init = &ast["body"][0]["declarations"][0]["init"];
+
+    // No `;` is inserted before `+` or `(`, so this is `b + c(d)`
+    assert_eq!(ast["body"].as_array().unwrap().len(), 1);
+    assert_eq!(init["type"], "BinaryExpression");
+    assert_eq!(init["right"]["type"], "CallExpression");
+    assert_eq!(init["right"]["callee"]["name"], "c");
+}
+
+#[test]
+fn return_followed_by_newline() {
+    let code = "function a() {\n  return\n  1\n}";
+    let ast = estree(code);
+    let body = &ast["body"][0]["body"]["body"];
+
+    assert_eq!(body[0]["type"], "ReturnStatement");
+    assert_eq!(body[0]["argument"], serde_json::Value::Null);
+    assert_eq!(body[0]["end"], code.find_n("return", 0).unwrap() + 6);
+    assert_eq!(body[1]["type"], "ExpressionStatement");
+}
+
+#[test]
+fn break_and_continue_labels() {
+    let code = "while (a) {\n  break foo\n  continue\n  bar\n}";
+    let ast = estree(code);
+    let body = &ast["body"][0]["body"]["body"];
+
+    assert_eq!(body[0]["type"], "BreakStatement");
+    assert_eq!(body[0]["label"]["name"], "foo");
+    assert_eq!(body[1]["type"], "ContinueStatement");
+    assert_eq!(body[1]["label"], serde_json::Value::Null);
+    assert_eq!(body[2]["expression"]["name"], "bar");
+}
+
+#[test]
+fn postfix_update_on_next_line() {
+    let ast = estree("a\n++b");
+
+    assert_eq!(ast["body"][0]["expression"]["type"], "Identifier");
+    assert_eq!(ast["body"][1]["expression"]["type"], "UpdateExpression");
+    assert_eq!(ast["body"][1]["expression"]["prefix"], true);
+    assert_eq!(ast["body"][1]["expression"]["argument"]["name"], "b");
+}
+
+#[test]
+fn postfix_update_on_same_line() {
+    let ast = estree("a++\nb");
+
+    assert_eq!(ast["body"][0]["expression"]["type"], "UpdateExpression");
+    assert_eq!(ast["body"][0]["expression"]["prefix"], false);
+    assert_eq!(ast["body"][1]["expression"]["name"], "b");
+}
+
+#[test]
+fn missing_semicolon() {
+    let code = "let a = 1 let b = 2";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, ErrorKind::MissingSemicolon);
+    assert_eq!(errors[0].kind.code(), "P0013");
+    assert_eq!(errors[0].token.start, code.find_n("let", 1).unwrap());
+    assert_eq!(errors[0].expected, vec![TokenKind::SemiColon]);
+    assert_eq!(program.body.len(), 2);
+}
+
+#[test]
+fn newline_after_throw() {
+    let code = "throw\nnew Error()";
+    let err = Parser::new(code).parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::NewlineAfterThrow);
+    assert_eq!(err.kind.code(), "P0014");
+    assert_eq!(err.token.start, code.find_n("new", 0).unwrap());
+}
@@ -0,0 +1,219 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn async_function_declaration_and_expression() {
+    let code = "async function load() { await fetch(url); } const f = async function () {};";
+    let ast = estree(code);
+
+    let decl = &ast["body"][0];
+    assert_eq!(decl["type"], "FunctionDeclaration");
+    assert_eq!(decl["async"], true);
+    assert_eq!(decl["generator"], false);
+    assert_eq!(decl["start"], 0);
+
+    let await_expr = &decl["body"]["body"][0]["expression"];
+    assert_eq!(await_expr["type"], "AwaitExpression");
+    assert_eq!(await_expr["argument"]["type"], "CallExpression");
+
+    let expr = &ast["body"][1]["declarations"][0]["init"];
+    assert_eq!(expr["type"], "FunctionExpression");
+    assert_eq!(expr["async"], true);
+    assert_eq!(expr["start"], code.rfind("async").unwrap());
+}
+
+#[test]
+fn async_arrow_function() {
+    let ast = estree("const f = async (a: number) => await a; const g = (a) => a;");
+
+    let arrow = &ast["body"][0]["declarations"][0]["init"];
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["async"], true);
+    assert_eq!(arrow["expression"], true);
+    assert_eq!(arrow["body"]["type"], "AwaitExpression");
+
+    let arrow = &ast["body"][1]["declarations"][0]["init"];
+    assert_eq!(arrow["async"], false);
+}
+
+#[test]
+fn arrow_function_without_parentheses() {
+    let ast = estree("x = async x => x; y = x => async => async;");
+
+    let arrow = &ast["body"][0]["expression"]["right"];
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["async"], true);
+    assert_eq!(arrow["params"][0]["pattern"]["name"], "x");
+    assert_eq!(arrow["body"]["name"], "x");
+
+    // `async` can be the name of the parameter
+    let arrow = &ast["body"][1]["expression"]["right"];
+    assert_eq!(arrow["async"], false);
+    assert_eq!(arrow["body"]["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["body"]["params"][0]["pattern"]["name"], "async");
+}
+
+#[test]
+fn line_break_before_arrow_without_parentheses() {
+    assert!(Parser::new("x\n=> x;").parse().is_err());
+    assert!(Parser::new("async x\n=> x;").parse().is_err());
+}
+
+#[test]
+fn async_as_an_identifier() {
+    let ast = estree("async(1); let x = async;");
+
+    let call = &ast["body"][0]["expression"];
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["callee"]["name"], "async");
+    assert_eq!(ast["body"][1]["declarations"][0]["init"]["name"], "async");
+}
+
+#[test]
+fn async_and_generator_methods() {
+    let ast = estree(
+        "class A { async load() { await this.x; } *items() {} } let o = { *keys() { yield 1; } };",
+    );
+
+    let methods = &ast["body"][0]["body"]["body"];
+    assert_eq!(methods[0]["value"]["async"], true);
+    assert_eq!(methods[0]["value"]["generator"], false);
+    assert_eq!(methods[1]["value"]["async"], false);
+    assert_eq!(methods[1]["value"]["generator"], true);
+
+    let method = &ast["body"][1]["declarations"][0]["init"]["properties"][0];
+    assert_eq!(method["value"]["generator"], true);
+    assert_eq!(
+        method["value"]["body"]["body"][0]["expression"]["type"],
+        "YieldExpression"
+    );
+}
+
+#[test]
+fn generator_with_yield_and_delegate() {
+    let code = "function* gen() { yield 1; yield* other(); yield; }";
+    let ast = estree(code);
+    let decl = &ast["body"][0];
+
+    assert_eq!(decl["generator"], true);
+    assert_eq!(decl["id"]["name"], "gen");
+
+    let body = &decl["body"]["body"];
+    assert_eq!(body[0]["expression"]["type"], "YieldExpression");
+    assert_eq!(body[0]["expression"]["delegate"], false);
+    assert_eq!(body[0]["expression"]["argument"]["value"], 1.0);
+
+    assert_eq!(body[1]["expression"]["delegate"], true);
+    assert_eq!(body[1]["expression"]["argument"]["type"], "CallExpression");
+
+    assert_eq!(body[2]["expression"]["argument"], serde_json::Value::Null);
+    assert_eq!(body[2]["expression"]["end"], code.rfind(';').unwrap());
+}
+
+#[test]
+fn yield_is_an_identifier_outside_generators() {
+    let ast = estree("function f() { let yield = 1; return yield; }");
+
+    let body = &ast["body"][0]["body"]["body"];
+    assert_eq!(body[0]["declarations"][0]["id"]["name"], "yield");
+    assert_eq!(body[1]["argument"]["type"], "Identifier");
+}
+
+#[test]
+fn await_and_yield_as_binding_names() {
+    let ast = estree("function f() { const await = 1; } function g() { const yield = 2; }");
+
+    let decl = &ast["body"][0]["body"]["body"][0]["declarations"][0];
+    assert_eq!(decl["id"]["name"], "await");
+    let decl = &ast["body"][1]["body"]["body"][0]["declarations"][0];
+    assert_eq!(decl["id"]["name"], "yield");
+
+    for code in [
+        "async function f() { const await = 1; }",
+        "function* g() { const yield = 1; }",
+        "function* g() { function yield() {} }",
+    ] {
+        let err = Parser::new(code).parse().unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::ExpectedIdentifier, "{code}");
+    }
+}
+
+#[test]
+fn for_await_of() {
+    let ast = estree(
+        "async function f() { for await (const chunk of stream) {} for (const x of xs) {} }",
+    );
+
+    let body = &ast["body"][0]["body"]["body"];
+    assert_eq!(body[0]["type"], "ForOfStatement");
+    assert_eq!(body[0]["await"], true);
+    assert_eq!(body[0]["right"]["name"], "stream");
+    assert_eq!(body[1]["await"], false);
+}
+
+#[test]
+fn top_level_await() {
+    let ast = estree("await ready; for await (const x of xs) {}");
+
+    assert_eq!(ast["body"][0]["expression"]["type"], "AwaitExpression");
+    assert_eq!(ast["body"][1]["await"], true);
+}
+
+#[test]
+fn await_binds_like_a_unary_operator() {
+    let ast = estree("async function f() { await a + b; await -x; }");
+
+    let body = &ast["body"][0]["body"]["body"];
+    let sum = &body[0]["expression"];
+    assert_eq!(sum["type"], "BinaryExpression");
+    assert_eq!(sum["left"]["type"], "AwaitExpression");
+    assert_eq!(sum["left"]["argument"]["name"], "a");
+    assert_eq!(sum["right"]["name"], "b");
+
+    assert_eq!(body[1]["expression"]["argument"]["type"], "UnaryExpression");
+}
+
+#[test]
+fn await_as_an_identifier() {
+    let ast = estree("await(x); function f() { return await(1) + await; } let y = await\nz;");
+
+    let call = &ast["body"][0]["expression"];
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["callee"]["name"], "await");
+
+    let sum = &ast["body"][1]["body"]["body"][0]["argument"];
+    assert_eq!(sum["left"]["type"], "CallExpression");
+    assert_eq!(sum["right"]["name"], "await");
+
+    // A line break after `await` ends the statement
+    assert_eq!(ast["body"][2]["declarations"][0]["init"]["name"], "await");
+}
+
+#[test]
+fn await_outside_async_function() {
+    for code in [
+        "function f() { await x; }",
+        "const f = () => await x;",
+        "async function f() { function g() { await x; } }",
+        "function f() { for await (const x of xs) {} }",
+        "class A { static { await x; } }",
+    ] {
+        let err = Parser::new(code).parse().unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::AwaitOutsideAsync, "{code}");
+        assert_eq!(err.kind.code(), "P0021");
+    }
+}
+
+#[test]
+fn for_await_requires_of() {
+    for code in [
+        "async function f() { for await (const x in xs) {} }",
+        "async function f() { for await (;;) {} }",
+    ] {
+        assert!(Parser::new(code).parse().is_err(), "{code}");
+    }
+}
@@ -0,0 +1,59 @@
+use parser::Parser;
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn arrow_function_with_typed_parameters() {
+    let ast = estree("let f = (a: number, b: string): void => a;");
+    let init = &ast["body"][0]["declarations"][0]["init"];
+
+    assert_eq!(init["type"], "ArrowFunctionExpression");
+    assert_eq!(init["params"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn parenthesized_expression_after_failed_arrow_function() {
+    let ast = estree("let a = (b, c);");
+    let init = &ast["body"][0]["declarations"][0]["init"];
+
+    assert_eq!(init["type"], "ParenthesizedExpression");
+}
+
+#[test]
+fn arrow_function_without_parameters() {
+    let ast = estree("let f = () => 1;");
+    let init = &ast["body"][0]["declarations"][0]["init"];
+
+    assert_eq!(init["type"], "ArrowFunctionExpression");
+    assert_eq!(init["params"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn no_errors_left_from_failed_attempt() {
+    let code = "(a + b) * c;";
+    let (program, errors) = Parser::new(code).parse_with_errors();
+
+    assert!(errors.is_empty());
+    assert_eq!(program.body.len(), 1);
+}
+
+#[test]
+fn deeply_nested_parentheses_are_tried_once() {
+    let depth = 40;
+    let code = format!(
+        "x = {}1{};",
+        (0..depth).map(|i| format!("(a{i} = ")).collect::<String>(),
+        ")".repeat(depth)
+    );
+    let (_, errors) = Parser::new(&code).parse_with_errors();
+
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn line_break_before_arrow() {
+    let (_, errors) = Parser::new("(a)\n=> a;").parse_with_errors();
+
+    assert!(!errors.is_empty());
+}
@@ -0,0 +1,199 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+/// The members of the first class in the program
+fn members(ast: &serde_json::Value) -> &Vec<serde_json::Value> {
+    ast["body"][0]["body"]["body"].as_array().unwrap()
+}
+
+#[test]
+fn class_declaration() {
+    let code = "class Dog extends Animal {
+        constructor(name) { super(name); }
+        bark() { return 1; }
+        get age() { return this.a; }
+        set age(value) { this.a = value; }
+        static create() { return new Dog(); }
+    }";
+    let ast = estree(code);
+    let class = &ast["body"][0];
+
+    assert_eq!(class["type"], "ClassDeclaration");
+    assert_eq!(class["id"]["name"], "Dog");
+    assert_eq!(class["superClass"]["name"], "Animal");
+    assert_eq!(class["end"], code.len());
+
+    let kinds: Vec<_> = members(&ast)
+        .iter()
+        .map(|member| (member["key"]["name"].clone(), member["kind"].clone()))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("constructor".into(), "constructor".into()),
+            ("bark".into(), "method".into()),
+            ("age".into(), "get".into()),
+            ("age".into(), "set".into()),
+            ("create".into(), "method".into()),
+        ]
+    );
+    assert_eq!(members(&ast)[4]["static"], true);
+    assert_eq!(members(&ast)[1]["value"]["type"], "FunctionExpression");
+}
+
+#[test]
+fn fields_and_private_names() {
+    let code = "class Counter {
+        count = 0;
+        #step = 1
+        static instances;
+        [key] = 2;
+        #increment() { this.count += this.#step; }
+    }";
+    let ast = estree(code);
+    let members = members(&ast);
+
+    assert_eq!(members[0]["type"], "PropertyDefinition");
+    assert_eq!(members[0]["value"]["value"], 0.0);
+    assert_eq!(members[0]["end"], code.find("0;").unwrap() + 2);
+
+    // The `;` is inserted after a line break
+    assert_eq!(members[1]["key"]["type"], "PrivateIdentifier");
+    assert_eq!(members[1]["key"]["name"], "step");
+    assert_eq!(members[1]["end"], code.find("= 1").unwrap() + 3);
+
+    assert_eq!(members[2]["static"], true);
+    assert_eq!(members[2]["value"], serde_json::Value::Null);
+    assert_eq!(members[3]["computed"], true);
+    assert_eq!(members[4]["type"], "MethodDefinition");
+    assert_eq!(members[4]["key"]["type"], "PrivateIdentifier");
+}
+
+#[test]
+fn static_block() {
+    let ast = estree("class A { static { init(); } static x = 1; }");
+    let members = members(&ast);
+
+    assert_eq!(members[0]["type"], "StaticBlock");
+    assert_eq!(members[0]["body"].as_array().unwrap().len(), 1);
+    assert_eq!(members[1]["type"], "PropertyDefinition");
+}
+
+#[test]
+fn modifiers_as_names() {
+    let ast = estree("class A { static() {} get = 1; async\nfoo() {} static static() {} }");
+    let members = members(&ast);
+
+    assert_eq!(members[0]["key"]["name"], "static");
+    assert_eq!(members[0]["static"], false);
+    assert_eq!(members[1]["type"], "PropertyDefinition");
+    assert_eq!(members[1]["key"]["name"], "get");
+    // `async` followed by a line break is a field
+    assert_eq!(members[2]["key"]["name"], "async");
+    assert_eq!(members[3]["key"]["name"], "foo");
+    assert_eq!(members[4]["key"]["name"], "static");
+    assert_eq!(members[4]["static"], true);
+}
+
+#[test]
+fn typescript_modifiers() {
+    let code = "abstract class Shape<T> extends Base implements Drawable, Sized<T> {
+        private readonly id: number = 1;
+        protected name?: string;
+        public override draw(): void {}
+        abstract area(): number;
+    }";
+    let ast = estree(code);
+    let class = &ast["body"][0];
+    let members = members(&ast);
+
+    assert_eq!(class["abstract"], true);
+    assert_eq!(class["typeParameters"]["parameters"][0]["id"]["name"], "T");
+    assert_eq!(class["implements"].as_array().unwrap().len(), 2);
+    assert_eq!(class["implements"][1]["typeName"]["name"], "Sized");
+
+    assert_eq!(members[0]["accessibility"], "private");
+    assert_eq!(members[0]["readonly"], true);
+    assert_eq!(members[0]["typeAnnotation"]["type"], "TypeAnnotation");
+    assert_eq!(members[1]["accessibility"], "protected");
+    assert_eq!(members[1]["optional"], true);
+    assert_eq!(members[2]["accessibility"], "public");
+    assert_eq!(members[2]["override"], true);
+
+    // Abstract methods don't have a body, and end with their `;`
+    assert_eq!(members[3]["abstract"], true);
+    assert_eq!(members[3]["value"]["body"], serde_json::Value::Null);
+    assert_eq!(members[3]["end"], code.find("number;").unwrap() + 7);
+}
+
+#[test]
+fn parameter_properties() {
+    let code = "class A {
+        constructor(private readonly a: number, public b = 1, override c, readonly) {}
+    }";
+    let ast = estree(code);
+    let params = &members(&ast)[0]["value"]["params"];
+
+    assert_eq!(params[0]["accessibility"], "private");
+    assert_eq!(params[0]["readonly"], true);
+    assert_eq!(params[0]["pattern"]["name"], "a");
+    assert_eq!(params[0]["start"], code.find("private").unwrap());
+    assert_eq!(params[1]["accessibility"], "public");
+    assert_eq!(params[1]["pattern"]["type"], "AssignmentPattern");
+    assert_eq!(params[2]["override"], true);
+
+    // Without a name after it, a modifier is the name of the parameter
+    assert_eq!(params[3]["pattern"]["name"], "readonly");
+    assert_eq!(params[3]["readonly"], false);
+}
+
+#[test]
+fn declared_and_definite_fields() {
+    let ast = estree("class A { declare x: number; y!: string; declare = 1; }");
+    let members = members(&ast);
+
+    assert_eq!(members[0]["declare"], true);
+    assert_eq!(members[0]["key"]["name"], "x");
+    assert_eq!(members[1]["definite"], true);
+    assert_eq!(members[1]["typeAnnotation"]["type"], "TypeAnnotation");
+    assert_eq!(members[2]["key"]["name"], "declare");
+    assert_eq!(members[2]["declare"], false);
+}
+
+#[test]
+fn parent_class_type_arguments() {
+    let ast = estree("class A extends B<T> {} let C = class extends ns.D<string, number> {};");
+
+    let class = &ast["body"][0];
+    assert_eq!(class["superClass"]["name"], "B");
+    assert_eq!(class["superTypeArguments"][0]["typeName"]["name"], "T");
+
+    let expr = &ast["body"][1]["declarations"][0]["init"];
+    assert_eq!(expr["superClass"]["type"], "MemberExpression");
+    assert_eq!(expr["superTypeArguments"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn class_expression() {
+    let ast = estree("let A = class extends B {}; let C = class D {};");
+
+    let anonymous = &ast["body"][0]["declarations"][0]["init"];
+    assert_eq!(anonymous["type"], "ClassExpression");
+    assert_eq!(anonymous["id"], serde_json::Value::Null);
+    assert_eq!(anonymous["superClass"]["name"], "B");
+
+    let named = &ast["body"][1]["declarations"][0]["init"];
+    assert_eq!(named["id"]["name"], "D");
+}
+
+#[test]
+fn invalid_member() {
+    let code = "class A { + }";
+    let err = Parser::new(code).parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedClassMember);
+    assert_eq!(err.kind.code(), "P0015");
+    assert_eq!(err.token.start, code.find('+').unwrap());
+}
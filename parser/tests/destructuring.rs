@@ -0,0 +1,148 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn object_and_array_patterns() {
+    let code = "let { a, b: [c, ...d] = [] } = obj;";
+    let ast = estree(code);
+    let declarator = &ast["body"][0]["declarations"][0];
+    let properties = declarator["id"]["properties"].as_array().unwrap();
+
+    assert_eq!(declarator["id"]["type"], "ObjectPattern");
+    assert_eq!(declarator["init"]["name"], "obj");
+
+    assert_eq!(properties[0]["type"], "Property");
+    assert_eq!(properties[0]["shorthand"], true);
+    assert_eq!(properties[0]["value"]["name"], "a");
+
+    let value = &properties[1]["value"];
+    assert_eq!(properties[1]["shorthand"], false);
+    assert_eq!(value["type"], "AssignmentPattern");
+    assert_eq!(value["left"]["type"], "ArrayPattern");
+    assert_eq!(value["left"]["elements"][1]["type"], "RestElement");
+    assert_eq!(value["left"]["elements"][1]["argument"]["name"], "d");
+    assert_eq!(value["right"]["type"], "ArrayExpression");
+    assert_eq!(value["end"], code.find(" }").unwrap());
+}
+
+#[test]
+fn holes_defaults_and_rest_properties() {
+    let ast =
+        estree("const [, second, third = 3] = arr; const { a = 1, [key]: b, ...rest } = obj;");
+
+    let elements = &ast["body"][0]["declarations"][0]["id"]["elements"];
+    assert_eq!(elements[0], serde_json::Value::Null);
+    assert_eq!(elements[1]["name"], "second");
+    assert_eq!(elements[2]["type"], "AssignmentPattern");
+    assert_eq!(elements.as_array().unwrap().len(), 3);
+
+    let properties = &ast["body"][1]["declarations"][0]["id"]["properties"];
+    assert_eq!(properties[0]["value"]["type"], "AssignmentPattern");
+    assert_eq!(properties[1]["computed"], true);
+    assert_eq!(properties[1]["key"]["name"], "key");
+    assert_eq!(properties[2]["type"], "RestElement");
+}
+
+#[test]
+fn destructured_parameters() {
+    let ast = estree("function f({ a, b }: Options, [first]: number[]) {} const g = ({ x }) => x;");
+
+    let params = &ast["body"][0]["params"];
+    assert_eq!(params[0]["pattern"]["type"], "ObjectPattern");
+    assert_eq!(
+        params[0]["typeAnnotation"]["typeValue"]["type"],
+        "TypeReference"
+    );
+    assert_eq!(params[1]["pattern"]["type"], "ArrayPattern");
+
+    let arrow = &ast["body"][1]["declarations"][0]["init"];
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["params"][0]["pattern"]["type"], "ObjectPattern");
+}
+
+#[test]
+fn destructuring_assignment() {
+    let ast = estree("[a, b] = [b, a]; ({ x, y: obj.y, z: [w = 1] } = point);");
+
+    let swap = &ast["body"][0]["expression"];
+    assert_eq!(swap["type"], "AssignmentExpression");
+    assert_eq!(swap["left"]["type"], "ArrayPattern");
+    assert_eq!(swap["left"]["elements"][1]["name"], "b");
+    assert_eq!(swap["right"]["type"], "ArrayExpression");
+
+    let properties = &ast["body"][1]["expression"]["expression"]["left"]["properties"];
+    assert_eq!(properties[0]["shorthand"], true);
+    assert_eq!(properties[1]["value"]["type"], "MemberExpression");
+    assert_eq!(properties[2]["value"]["type"], "ArrayPattern");
+    assert_eq!(
+        properties[2]["value"]["elements"][0]["type"],
+        "AssignmentPattern"
+    );
+}
+
+#[test]
+fn shorthand_default_in_assignment() {
+    let ast = estree("({ a = 1 } = obj); [{ b = 2 }] = arr; for ({ c = 3 } of items);");
+
+    let property = &ast["body"][0]["expression"]["expression"]["left"]["properties"][0];
+    assert_eq!(property["shorthand"], true);
+    assert_eq!(property["value"]["type"], "AssignmentPattern");
+    assert_eq!(property["value"]["left"]["name"], "a");
+    assert_eq!(property["value"]["right"]["type"], "Literal");
+
+    let element = &ast["body"][1]["expression"]["left"]["elements"][0];
+    assert_eq!(
+        element["properties"][0]["value"]["type"],
+        "AssignmentPattern"
+    );
+
+    let left = &ast["body"][2]["left"];
+    assert_eq!(left["properties"][0]["value"]["type"], "AssignmentPattern");
+}
+
+#[test]
+fn shorthand_default_outside_pattern() {
+    for code in ["({ a = 1 });", "f({ a = 1 }, { b });", "x = { a = 1 };"] {
+        let err = Parser::new(code).parse().unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::ShorthandPropertyDefault, "{code}");
+        assert_eq!(err.kind.code(), "P0023");
+        assert_eq!(err.token.start, code.rfind('=').unwrap());
+    }
+}
+
+#[test]
+fn invalid_assignment_targets() {
+    for code in [
+        "[a, 1] = arr;",
+        "f() = c;",
+        "[a] += arr;",
+        "({ f() {} } = obj);",
+    ] {
+        let err = Parser::new(code).parse().unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::InvalidAssignmentTarget, "{code}");
+        assert_eq!(err.kind.code(), "P0019");
+    }
+}
+
+#[test]
+fn rest_element_must_be_last() {
+    let err = Parser::new("let [...a, b] = arr;").parse().unwrap_err();
+    assert_eq!(err.kind, ErrorKind::RestElementNotLast);
+
+    let err = Parser::new("let { ...a, } = obj;").parse().unwrap_err();
+    assert_eq!(err.kind, ErrorKind::RestElementNotLast);
+}
+
+#[test]
+fn destructuring_requires_initializer() {
+    let code = "let { a };";
+    let err = Parser::new(code).parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::MissingInitializer);
+    assert_eq!(err.kind.code(), "P0018");
+    assert_eq!(err.labels[0].node.start, code.find('{').unwrap());
+}
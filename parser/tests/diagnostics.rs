@@ -0,0 +1,162 @@
+use lexer::{LexErrorKind, LineIndex, SourceFile, TokenKind};
+use parser::{
+    utils::{
+        diagnostic::{Position, Severity},
+        parser_error::{ErrorKind, ErrorLabel},
+    },
+    Parser,
+};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::NodeConstructor;
+
+#[test]
+fn missing_close_paren_after_arguments() {
+    let code = "foo(1, 2;";
+    let mut parser = Parser::new(code);
+    let err = parser.parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedCloseParenAfterArguments);
+    assert_eq!(err.kind.code(), "P0001");
+    assert_eq!(err.token.kind, TokenKind::SemiColon);
+    assert_eq!(err.expected, vec![TokenKind::Comma, TokenKind::CloseParen]);
+    assert_eq!(
+        err.labels,
+        vec![ErrorLabel {
+            node: code.node("(", 0),
+            message: "unmatched `(` here".into(),
+        }]
+    );
+    assert_eq!(err.found(code), "found `;`");
+    assert_eq!(
+        err.expected_message(),
+        Some("expected `,` or `)`".to_owned())
+    );
+}
+
+#[test]
+fn unclosed_parenthesis() {
+    let code = "let a = (1 + 2;";
+    let mut parser = Parser::new(code);
+    let err = parser.parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnclosedParen);
+    assert_eq!(err.expected, vec![TokenKind::CloseParen]);
+    assert_eq!(err.labels[0].node, code.node("(", 0));
+}
+
+#[test]
+fn unclosed_block() {
+    let code = "function a() {\n    return 1;";
+    let mut parser = Parser::new(code);
+    let err = parser.parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnclosedBrace);
+    assert_eq!(err.token.kind, TokenKind::Eof);
+    assert_eq!(err.found(code), "found end of file");
+    assert_eq!(err.labels[0].node, code.node("{", 0));
+}
+
+#[test]
+fn expected_expression() {
+    let code = "let a = ;";
+    let mut parser = Parser::new(code);
+    let err = parser.parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedExpression);
+    assert_eq!(err.kind.code(), "P0006");
+}
+
+#[test]
+fn expected_identifier() {
+    let code = "let 5 = a;";
+    let mut parser = Parser::new(code);
+    let err = parser.parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedIdentifier);
+    assert_eq!(err.expected, vec![TokenKind::Identifier]);
+    assert_eq!(
+        err.expected_message(),
+        Some("expected an identifier".to_owned())
+    );
+}
+
+#[test]
+fn positions() {
+    let index = LineIndex::new("let a = 1;\nlet b = 😀;\n");
+    assert_eq!(
+        Position::new(&index, 0),
+        Position {
+            offset: 0,
+            line: 1,
+            column: 1
+        }
+    );
+    assert_eq!(
+        Position::new(&index, 15),
+        Position {
+            offset: 15,
+            line: 2,
+            column: 5
+        }
+    );
+    assert_eq!(
+        Position::new(&index, 24),
+        Position {
+            offset: 24,
+            line: 2,
+            column: 12
+        }
+    );
+}
+
+#[test]
+fn json_diagnostic() {
+    let code = "foo(1,\n  2;";
+    let mut parser = Parser::new(code);
+    let file = SourceFile::new("main.ts", code);
+    let diagnostic = parser.parse().unwrap_err().to_diagnostic(&file);
+
+    assert_eq!(diagnostic.code, "P0001");
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.primary.start.line, 2);
+    assert_eq!(diagnostic.primary.start.column, 4);
+    assert_eq!(diagnostic.secondary.len(), 1);
+    assert_eq!(diagnostic.secondary[0].start.offset, 3);
+    assert_eq!(diagnostic.notes.len(), 1);
+
+    let json = diagnostic.to_json();
+    assert!(json.starts_with(r#"{"code":"P0001","severity":"error","#));
+    assert!(!json.contains('\n'));
+}
+
+#[test]
+fn lexer_errors() {
+    let code = "let a = 0x;\nlet b = 1.5n;";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(program.body.len(), 2);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].kind, ErrorKind::Lex(LexErrorKind::MissingDigits));
+    assert_eq!(errors[0].kind.code(), "L0001");
+    assert_eq!(errors[0].token.start, code.find_n("0x", 0).unwrap());
+    assert_eq!(errors[0].found(code), "found `0x`");
+    assert_eq!(errors[1].kind, ErrorKind::Lex(LexErrorKind::InvalidBigInt));
+    assert!(errors[1].kind.help().is_some());
+}
+
+#[test]
+fn unexpected_character() {
+    let code = "let a = 1 # 2;";
+    let mut parser = Parser::new(code);
+    let (_, errors) = parser.parse_with_errors();
+
+    let err = errors
+        .iter()
+        .find(|err| err.kind == ErrorKind::Lex(LexErrorKind::UnexpectedCharacter))
+        .unwrap();
+    assert_eq!(err.kind.code(), "L0015");
+    assert_eq!(err.token.start, code.find_n("#", 0).unwrap());
+    assert_eq!(err.found(code), "found `#`");
+}
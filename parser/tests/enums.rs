@@ -1,9 +1,13 @@
+use lexer::SourceFile;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 use parser::{
-    expressions::{Identifier, NumberLiteral},
-    nodes::{program::Program, Node},
-    statements::{EnumMember, EnumStatement},
+    ast_types::{
+        expressions::NumberLiteral,
+        identifier::Identifier,
+        node_objects::Node,
+        statements::{EnumMember, EnumStatement},
+    },
     Parser,
 };
 use pretty_assertions::assert_eq;
@@ -11,12 +15,12 @@ use pretty_assertions::assert_eq;
 #[test]
 fn enum_statement() {
     let code = "enum Foo { Bar, Baz }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        body: vec![EnumStatement {
+    let expected = script(
+        code,
+        vec![EnumStatement {
             node: Node::new(0, code.len()),
             is_declare: false,
             is_const: false,
@@ -44,10 +48,10 @@ fn enum_statement() {
             ],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -57,13 +61,12 @@ fn enum_statement() {
 #[test]
 fn enum_with_initialized_values() {
     let code = "enum Color { White = 1, Black = 0 }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![EnumStatement {
+    let expected = script(
+        code,
+        vec![EnumStatement {
             node: Node::new(0, code.len()),
             is_declare: false,
             is_const: false,
@@ -103,10 +106,10 @@ fn enum_with_initialized_values() {
             ],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -116,13 +119,12 @@ fn enum_with_initialized_values() {
 #[test]
 fn const_enum_statement() {
     let code = "const enum Foo { Bar, Baz }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![EnumStatement {
+    let expected = script(
+        code,
+        vec![EnumStatement {
             node: Node::new(0, code.len()),
             is_declare: false,
             is_const: true,
@@ -150,10 +152,10 @@ fn const_enum_statement() {
             ],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -163,13 +165,12 @@ fn const_enum_statement() {
 #[test]
 fn ambient_enum_statement() {
     let code = "declare enum Foo { Bar, Baz }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![EnumStatement {
+    let expected = script(
+        code,
+        vec![EnumStatement {
             node: Node::new(0, code.len()),
             is_declare: true,
             is_const: false,
@@ -197,7 +198,7 @@ fn ambient_enum_statement() {
             ],
         }
         .into()],
-    };
+    );
 
     assert_eq!(result, Ok(expected));
 }
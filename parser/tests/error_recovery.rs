@@ -0,0 +1,70 @@
+use parser::{ast_types::node_objects::Spanned, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::NodeConstructor;
+
+#[test]
+fn reports_every_syntax_error() {
+    let code = "let a = ;\nlet b = 2;\nlet c = );\nlet d = 4;";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].token.start, code.find_n(";", 0).unwrap());
+    assert_eq!(errors[1].token.start, code.find_n(")", 0).unwrap());
+    assert_eq!(program.body.len(), 2);
+    assert_eq!(
+        program.body[0].node().start,
+        code.find_n("let b", 0).unwrap()
+    );
+    assert_eq!(
+        program.body[1].node().start,
+        code.find_n("let d", 0).unwrap()
+    );
+}
+
+#[test]
+fn recovers_inside_blocks() {
+    let code = "function a() {\n    let x = ;\n    return 1;\n}\nlet y = );";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].token.start, code.find_n(";", 0).unwrap());
+    assert_eq!(errors[1].token.start, code.find_n(")", 1).unwrap());
+    assert_eq!(program.body.len(), 1);
+}
+
+#[test]
+fn skips_unmatched_close_brace() {
+    let code = "}\nlet a = 1;";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].token.start, 0);
+    assert_eq!(program.body.len(), 1);
+}
+
+#[test]
+fn unterminated_block() {
+    let code = "function a() {\n    let x = 1;";
+    let mut parser = Parser::new(code);
+    let (program, errors) = parser.parse_with_errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(program.body.len(), 0);
+}
+
+#[test]
+fn parse_returns_first_error() {
+    let code = "let a = );\nlet b = ;";
+    let mut parser = Parser::new(code);
+    let result = parser.parse();
+
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().token.start,
+        code.find_n(")", 0).unwrap()
+    );
+}
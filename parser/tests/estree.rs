@@ -0,0 +1,112 @@
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+use serde_json::json;
+
+#[test]
+fn program() {
+    let ast = estree("a;\nb;");
+
+    assert_eq!(ast["type"], "Program");
+    assert_eq!(ast["sourceType"], "script");
+    assert_eq!(ast["start"], 0);
+    assert_eq!(ast["end"], 5);
+    assert_eq!(
+        ast["loc"],
+        json!({
+            "start": { "line": 1, "column": 0 },
+            "end": { "line": 2, "column": 2 },
+        })
+    );
+    assert_eq!(
+        ast["body"][1]["loc"]["start"],
+        json!({ "line": 2, "column": 0 })
+    );
+}
+
+#[test]
+fn binary_expression() {
+    let ast = estree("1 + x;");
+    let expr = &ast["body"][0]["expression"];
+
+    assert_eq!(ast["body"][0]["type"], "ExpressionStatement");
+    assert_eq!(expr["type"], "BinaryExpression");
+    assert_eq!(expr["operator"], "+");
+    assert_eq!(
+        expr["left"],
+        json!({
+            "type": "Literal",
+            "start": 0,
+            "end": 1,
+            "loc": {
+                "start": { "line": 1, "column": 0 },
+                "end": { "line": 1, "column": 1 },
+            },
+            "value": 1,
+            "raw": "1",
+        })
+    );
+    assert_eq!(expr["right"]["type"], "Identifier");
+    assert_eq!(expr["right"]["name"], "x");
+}
+
+#[test]
+fn variable_declaration() {
+    let ast = estree("const a = null;");
+    let decl = &ast["body"][0];
+
+    assert_eq!(decl["type"], "VariableDeclaration");
+    assert_eq!(decl["kind"], "const");
+    assert_eq!(decl["declarations"][0]["type"], "VariableDeclarator");
+    assert_eq!(
+        decl["declarations"][0]["init"]["value"],
+        serde_json::Value::Null
+    );
+    assert_eq!(decl["declarations"][0]["init"]["raw"], "null");
+}
+
+#[test]
+fn bigint_literal() {
+    let ast = estree("0xF_Fn;");
+    let expr = &ast["body"][0]["expression"];
+
+    assert_eq!(expr["type"], "Literal");
+    assert_eq!(expr["bigint"], "0xFF");
+    assert_eq!(expr["value"], serde_json::Value::Null);
+    assert_eq!(expr["raw"], "0xF_Fn");
+}
+
+#[test]
+fn string_literal() {
+    let ast = estree(r"'a\n\u{1F600}';");
+    let expr = &ast["body"][0]["expression"];
+
+    assert_eq!(expr["type"], "Literal");
+    assert_eq!(expr["value"], "a\n😀");
+    assert_eq!(expr["raw"], r"'a\n\u{1F600}'");
+}
+
+#[test]
+fn number_literal_values() {
+    let ast = estree("1.5; 1e3; 2 ** 53;");
+
+    assert_eq!(ast["body"][0]["expression"]["value"], 1.5);
+    assert_eq!(ast["body"][1]["expression"]["value"], 1000);
+    assert_eq!(ast["body"][2]["expression"]["right"]["value"], 53);
+}
+
+#[test]
+fn utf16_offsets() {
+    // `😀` is 4 bytes, but 2 UTF-16 code units like in acorn
+    let ast = estree(
+        "'😀';
+x;",
+    );
+    let expr = &ast["body"][1]["expression"];
+
+    assert_eq!(ast["body"][0]["end"], 5);
+    assert_eq!(expr["start"], 6);
+    assert_eq!(expr["end"], 7);
+    assert_eq!(expr["loc"]["start"], json!({ "line": 2, "column": 0 }));
+    assert_eq!(ast["end"], 8);
+}
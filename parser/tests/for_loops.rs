@@ -1,30 +1,29 @@
-use lexer::Operator;
+use lexer::{BinaryOperator, SourceFile, UpdateOperator};
 use parser::{
-    expressions::{
-        literal::VariableKind, BinaryExpression, Identifier, NumberLiteral, UpdateExpression,
-        UpdateOperator,
-    },
-    nodes::{program::Program, Node},
-    statements::{
-        BlockStatement, ForClassic, ForIn, ForLeft, ForOf, VariableDeclaration, VariableDeclarator,
+    ast_types::{
+        declarations::variable_declaration::{
+            VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+        },
+        expressions::{BinaryExpression, NumberLiteral, UpdateExpression},
+        identifier::Identifier,
+        statements::{BlockStatement, ForInOrOfLeft, ForInStatement, ForOfStatement, ForStatement},
     },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn for_loop() {
     let code = "for(let i = 0; i < 10; i++) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForClassic {
-            node: code.node(&code, 0),
+    let expected = script(
+        code,
+        vec![ForStatement {
+            node: code.node(code, 0),
             init: Some(
                 VariableDeclaration {
                     node: code.node("let i = 0", 0),
@@ -33,7 +32,8 @@ fn for_loop() {
                         id: Identifier {
                             node: code.node("i", 0),
                             name: "i".into(),
-                        },
+                        }
+                        .into(),
                         init: Some(
                             NumberLiteral {
                                 node: code.node("0", 0),
@@ -43,14 +43,14 @@ fn for_loop() {
                         ),
                         type_annotation: None,
                     }],
-                    kind: VariableKind::Let,
+                    kind: VariableDeclarationKind::Let,
                 }
                 .into(),
             ),
             test: Some(
                 BinaryExpression {
                     node: code.node("i < 10", 0),
-                    operator: Operator::LessThan,
+                    operator: BinaryOperator::LessThan,
                     left: Identifier {
                         node: code.node("i", 1),
                         name: "i".into(),
@@ -79,37 +79,36 @@ fn for_loop() {
             ),
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_loop_without_initializer() {
     let code = "for(; i < 10; i++) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForClassic {
-            node: code.node(&code, 0),
+    let expected = script(
+        code,
+        vec![ForStatement {
+            node: code.node(code, 0),
             init: None,
             test: Some(
                 BinaryExpression {
                     node: code.node("i < 10", 0),
-                    operator: Operator::LessThan,
+                    operator: BinaryOperator::LessThan,
                     left: Identifier {
                         node: code.node("i", 0),
                         name: "i".into(),
@@ -138,32 +137,31 @@ fn for_loop_without_initializer() {
             ),
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_loop_without_test() {
     let code = "for(let i = 0;; i++) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForClassic {
-            node: code.node(&code, 0),
+    let expected = script(
+        code,
+        vec![ForStatement {
+            node: code.node(code, 0),
             init: Some(
                 VariableDeclaration {
                     node: code.node("let i = 0", 0),
@@ -172,7 +170,8 @@ fn for_loop_without_test() {
                         id: Identifier {
                             node: code.node("i", 0),
                             name: "i".into(),
-                        },
+                        }
+                        .into(),
                         init: Some(
                             NumberLiteral {
                                 node: code.node("0", 0),
@@ -182,7 +181,7 @@ fn for_loop_without_test() {
                         ),
                         type_annotation: None,
                     }],
-                    kind: VariableKind::Let,
+                    kind: VariableDeclarationKind::Let,
                 }
                 .into(),
             ),
@@ -202,32 +201,31 @@ fn for_loop_without_test() {
             ),
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_loop_without_update() {
     let code = "for(let i = 0; i < 10;) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForClassic {
-            node: code.node(&code, 0),
+    let expected = script(
+        code,
+        vec![ForStatement {
+            node: code.node(code, 0),
             init: Some(
                 VariableDeclaration {
                     node: code.node("let i = 0", 0),
@@ -236,7 +234,8 @@ fn for_loop_without_update() {
                         id: Identifier {
                             node: code.node("i", 0),
                             name: "i".into(),
-                        },
+                        }
+                        .into(),
                         init: Some(
                             NumberLiteral {
                                 node: code.node("0", 0),
@@ -246,14 +245,14 @@ fn for_loop_without_update() {
                         ),
                         type_annotation: None,
                     }],
-                    kind: VariableKind::Let,
+                    kind: VariableDeclarationKind::Let,
                 }
                 .into(),
             ),
             test: Some(
                 BinaryExpression {
                     node: code.node("i < 10", 0),
-                    operator: Operator::LessThan,
+                    operator: BinaryOperator::LessThan,
                     left: Identifier {
                         node: code.node("i", 1),
                         name: "i".into(),
@@ -270,48 +269,45 @@ fn for_loop_without_update() {
             update: None,
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_in_loop() {
     let code = "for(let key in obj) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForIn {
-            node: code.node(&code, 0),
-            left: ForLeft::VariableDeclaration(
-                VariableDeclaration {
-                    node: code.node("let key", 0),
-                    declarations: vec![VariableDeclarator {
+    let expected = script(
+        code,
+        vec![ForInStatement {
+            node: code.node(code, 0),
+            left: ForInOrOfLeft::VariableDeclaration(VariableDeclaration {
+                node: code.node("let key", 0),
+                declarations: vec![VariableDeclarator {
+                    node: code.node("key", 0),
+                    type_annotation: None,
+                    init: None,
+                    id: Identifier {
                         node: code.node("key", 0),
-                        type_annotation: None,
-                        init: None,
-                        id: Identifier {
-                            node: code.node("key", 0),
-                            name: "key".into(),
-                        },
-                    }],
-                    kind: VariableKind::Let,
-                }
-                .into(),
-            ),
+                        name: "key".into(),
+                    }
+                    .into(),
+                }],
+                kind: VariableDeclarationKind::Let,
+            }),
             right: Identifier {
                 node: code.node("obj", 0),
                 name: "obj".into(),
@@ -319,33 +315,32 @@ fn for_in_loop() {
             .into(),
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_of_loop() {
     let code = "for(let key of obj) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForOf {
-            node: code.node(&code, 0),
-            left: ForLeft::VariableDeclaration(VariableDeclaration {
+    let expected = script(
+        code,
+        vec![ForOfStatement {
+            node: code.node(code, 0),
+            left: ForInOrOfLeft::VariableDeclaration(VariableDeclaration {
                 node: code.node("let key", 0),
                 declarations: vec![VariableDeclarator {
                     node: code.node("key", 0),
@@ -354,9 +349,10 @@ fn for_of_loop() {
                     id: Identifier {
                         node: code.node("key", 0),
                         name: "key".into(),
-                    },
+                    }
+                    .into(),
                 }],
-                kind: VariableKind::Let,
+                kind: VariableDeclarationKind::Let,
             }),
             right: Identifier {
                 node: code.node("obj", 0),
@@ -365,17 +361,18 @@ fn for_of_loop() {
             .into(),
             body: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
+            _await: false,
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
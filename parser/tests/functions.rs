@@ -1,109 +1,125 @@
-use lexer::{Operator, TypeKeyword};
+use lexer::{BinaryOperator, SourceFile, TypeKeyword};
 use parser::{
-    expressions::{
-        types::{KeywordType, TypeAnnotation},
-        ArrowFunctionExpression, BinaryExpression, CallExpression, FunctionExpression, Identifier,
-        MemberExpression, NumberLiteral, StringLiteral, VariableKind,
-    },
-    nodes::{program::Program, Node},
-    statements::{
-        BlockStatement, ExpressionStatement, FunctionDeclaration, Parameter, ReturnStatement,
-        VariableDeclaration, VariableDeclarator,
+    ast_types::{
+        declarations::{
+            function_declaration::{FunctionDeclaration, Parameter},
+            variable_declaration::{
+                VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+            },
+        },
+        expressions::{
+            types::{KeywordType, TypeAnnotation},
+            ArrowFunctionExpression, ArrowFunctionExpressionBody, BinaryExpression, CallExpression,
+            CallExpressionArgument, CallExpressionCallee, FunctionExpression, MemberExpression,
+            NumberLiteral, StringLiteral,
+        },
+        identifier::Identifier,
+        node_objects::Node,
+        statements::{ExpressionStatement, FunctionBody, FunctionBodyBody, ReturnStatement},
     },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn function_call() {
     let code = "my_func(50.5, \"abc123\")";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ExpressionStatement {
+    let expected = script(
+        code,
+        vec![ExpressionStatement {
             node: Node::new(0, code.len()),
             expression: CallExpression {
                 node: Node::new(0, code.len()),
-                callee: Identifier {
-                    node: code.node("my_func", 0),
-                    name: "my_func".into(),
-                }
-                .into(),
-                arguments: vec![
-                    NumberLiteral {
-                        node: code.node("50.5", 0),
-                        value: 50.5,
-                    }
-                    .into(),
-                    StringLiteral {
-                        node: code.node("\"abc123\"", 0),
-                        value: "\"abc123\"".into(),
+                callee: CallExpressionCallee::Expression(
+                    Identifier {
+                        node: code.node("my_func", 0),
+                        name: "my_func".into(),
                     }
                     .into(),
+                ),
+                arguments: vec![
+                    CallExpressionArgument::Expression(
+                        NumberLiteral {
+                            node: code.node("50.5", 0),
+                            value: 50.5,
+                        }
+                        .into(),
+                    ),
+                    CallExpressionArgument::Expression(
+                        StringLiteral {
+                            node: code.node("\"abc123\"", 0),
+                            value: "abc123".into(),
+                        }
+                        .into(),
+                    ),
                 ],
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn member_expression_function_call() {
     let code = "console.log(50.5)";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Ok(Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ExpressionStatement {
+    let expected = script(
+        code,
+        vec![ExpressionStatement {
             node: Node::new(0, code.len()),
             expression: CallExpression {
                 node: Node::new(0, code.len()),
-                callee: MemberExpression {
-                    node: code.node("console.log", 0),
-                    object: Identifier {
-                        node: code.node("console", 0),
-                        name: "console".into(),
+                callee: CallExpressionCallee::Expression(
+                    MemberExpression {
+                        node: code.node("console.log", 0),
+                        object: Identifier {
+                            node: code.node("console", 0),
+                            name: "console".into(),
+                        }
+                        .into(),
+                        property: Identifier {
+                            node: code.node("log", 0),
+                            name: "log".into(),
+                        }
+                        .into(),
+                        computed: false,
                     }
                     .into(),
-                    property: Identifier {
-                        node: code.node("log", 0),
-                        name: "log".into(),
+                ),
+                arguments: vec![CallExpressionArgument::Expression(
+                    NumberLiteral {
+                        node: code.node("50.5", 0),
+                        value: 50.5,
                     }
                     .into(),
-                }
-                .into(),
-                arguments: vec![NumberLiteral {
-                    node: code.node("50.5", 0),
-                    value: 50.5,
-                }
-                .into()],
+                )],
             }
             .into(),
         }
         .into()],
-    });
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, expected);
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
@@ -111,16 +127,15 @@ fn function_declaration() {
     let code = "function add(n1: number, n2: number): number {
         return n1 + n2;
     }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![FunctionDeclaration {
+    let expected = script(
+        code,
+        vec![FunctionDeclaration {
             node: Node::new(0, code.len()),
-            is_generator: false,
-            is_async: false,
+            generator: false,
+            _async: false,
             id: Identifier {
                 node: code.node("add", 0),
                 name: "add".into(),
@@ -129,10 +144,11 @@ fn function_declaration() {
             params: vec![
                 Parameter {
                     node: code.node("n1: number", 0),
-                    identifier: Identifier {
+                    pattern: Identifier {
                         node: code.node("n1", 0),
                         name: "n1".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": number", 0),
                         type_value: KeywordType {
@@ -142,13 +158,17 @@ fn function_declaration() {
                         .into(),
                     }),
                     optional: false,
+                    accessibility: None,
+                    readonly: false,
+                    _override: false,
                 },
                 Parameter {
                     node: code.node("n2: number", 0),
-                    identifier: Identifier {
+                    pattern: Identifier {
                         node: code.node("n2", 0),
                         name: "n2".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": number", 1),
                         type_value: KeywordType {
@@ -158,6 +178,9 @@ fn function_declaration() {
                         .into(),
                     }),
                     optional: false,
+                    accessibility: None,
+                    readonly: false,
+                    _override: false,
                 },
             ],
             return_type: Some(TypeAnnotation {
@@ -168,38 +191,42 @@ fn function_declaration() {
                 }
                 .into(),
             }),
-            body: BlockStatement {
+            body: FunctionBody {
                 node: code.between_incl(("{", 0), ("}", 0)),
-                statements: vec![ReturnStatement {
-                    node: code.node("return n1 + n2;", 0),
-                    value: BinaryExpression {
-                        node: code.node("n1 + n2", 0),
-                        operator: Operator::Plus,
-                        left: Identifier {
-                            node: code.node("n1", 1),
-                            name: "n1".into(),
-                        }
-                        .into(),
-                        right: Identifier {
-                            node: code.node("n2", 1),
-                            name: "n2".into(),
-                        }
-                        .into(),
+                body: vec![FunctionBodyBody::Statement(
+                    ReturnStatement {
+                        node: code.node("return n1 + n2;", 0),
+                        argument: Some(
+                            BinaryExpression {
+                                node: code.node("n1 + n2", 0),
+                                operator: BinaryOperator::Plus,
+                                left: Identifier {
+                                    node: code.node("n1", 1),
+                                    name: "n1".into(),
+                                }
+                                .into(),
+                                right: Identifier {
+                                    node: code.node("n2", 1),
+                                    name: "n2".into(),
+                                }
+                                .into(),
+                            }
+                            .into(),
+                        ),
                     }
                     .into(),
-                }
-                .into()],
+                )],
             },
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
@@ -207,35 +234,37 @@ fn function_expression() {
     let code = "const sum = function(n1: number, n2: number): number {
         return n1 + n2;
     }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("sum", 0), ("}", 0)),
                 id: Identifier {
                     node: code.node("sum", 0),
                     name: "sum".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     FunctionExpression {
                         node: code.between_incl(("function", 0), ("}", 0)),
-                        is_async: false,
-                        is_generator: false,
+                        _async: false,
+                        generator: false,
                         id: None,
+                        type_parameters: None,
                         params: vec![
                             Parameter {
                                 node: code.node("n1: number", 0),
-                                identifier: Identifier {
+                                pattern: Identifier {
                                     node: code.node("n1", 0),
                                     name: "n1".into(),
-                                },
+                                }
+                                .into(),
                                 type_annotation: Some(TypeAnnotation {
                                     node: code.node(": number", 0),
                                     type_value: KeywordType {
@@ -245,13 +274,17 @@ fn function_expression() {
                                     .into(),
                                 }),
                                 optional: false,
+                                accessibility: None,
+                                readonly: false,
+                                _override: false,
                             },
                             Parameter {
                                 node: code.node("n2: number", 0),
-                                identifier: Identifier {
+                                pattern: Identifier {
                                     node: code.node("n2", 0),
                                     name: "n2".into(),
-                                },
+                                }
+                                .into(),
                                 type_annotation: Some(TypeAnnotation {
                                     node: code.node(": number", 1),
                                     type_value: KeywordType {
@@ -261,6 +294,9 @@ fn function_expression() {
                                     .into(),
                                 }),
                                 optional: false,
+                                accessibility: None,
+                                readonly: false,
+                                _override: false,
                             },
                         ],
                         return_type: Some(TypeAnnotation {
@@ -271,75 +307,79 @@ fn function_expression() {
                             }
                             .into(),
                         }),
-                        body: BlockStatement {
+                        body: Some(FunctionBody {
                             node: code.between_incl(("{", 0), ("}", 0)),
-                            statements: vec![ReturnStatement {
-                                node: code.node("return n1 + n2;", 0),
-                                value: BinaryExpression {
-                                    node: code.node("n1 + n2", 0),
-                                    left: Identifier {
-                                        node: code.node("n1", 1),
-                                        name: "n1".into(),
-                                    }
-                                    .into(),
-                                    right: Identifier {
-                                        node: code.node("n2", 1),
-                                        name: "n2".into(),
-                                    }
-                                    .into(),
-                                    operator: Operator::Plus,
+                            body: vec![FunctionBodyBody::Statement(
+                                ReturnStatement {
+                                    node: code.node("return n1 + n2;", 0),
+                                    argument: Some(
+                                        BinaryExpression {
+                                            node: code.node("n1 + n2", 0),
+                                            left: Identifier {
+                                                node: code.node("n1", 1),
+                                                name: "n1".into(),
+                                            }
+                                            .into(),
+                                            right: Identifier {
+                                                node: code.node("n2", 1),
+                                                name: "n2".into(),
+                                            }
+                                            .into(),
+                                            operator: BinaryOperator::Plus,
+                                        }
+                                        .into(),
+                                    ),
                                 }
                                 .into(),
-                            }
-                            .into()],
-                        }
-                        .into(),
+                            )],
+                        }),
                     }
                     .into(),
                 ),
-            }
-            .into()],
+            }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn arrow_function() {
     let code = "const sum = (n1: number, n2: number): number => n1 + n2;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("sum", 0), ("n2", 1)),
                 id: Identifier {
                     node: code.node("sum", 0),
                     name: "sum".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ArrowFunctionExpression {
                         node: code.between_incl(("(n1", 0), ("n2", 1)),
-                        parameters: vec![
+                        id: None,
+                        params: vec![
                             Parameter {
                                 node: code.node("n1: number", 0),
-                                identifier: Identifier {
+                                pattern: Identifier {
                                     node: code.node("n1", 0),
                                     name: "n1".into(),
-                                },
+                                }
+                                .into(),
                                 type_annotation: Some(TypeAnnotation {
                                     node: code.node(": number", 0),
                                     type_value: KeywordType {
@@ -349,13 +389,17 @@ fn arrow_function() {
                                     .into(),
                                 }),
                                 optional: false,
+                                accessibility: None,
+                                readonly: false,
+                                _override: false,
                             },
                             Parameter {
                                 node: code.node("n2: number", 0),
-                                identifier: Identifier {
+                                pattern: Identifier {
                                     node: code.node("n2", 0),
                                     name: "n2".into(),
-                                },
+                                }
+                                .into(),
                                 type_annotation: Some(TypeAnnotation {
                                     node: code.node(": number", 1),
                                     type_value: KeywordType {
@@ -365,6 +409,9 @@ fn arrow_function() {
                                     .into(),
                                 }),
                                 optional: false,
+                                accessibility: None,
+                                readonly: false,
+                                _override: false,
                             },
                         ],
                         return_type: Some(TypeAnnotation {
@@ -375,9 +422,8 @@ fn arrow_function() {
                             }
                             .into(),
                         }),
-                        body: ExpressionStatement {
-                            node: code.node("n1 + n2", 0),
-                            expression: BinaryExpression {
+                        body: ArrowFunctionExpressionBody::Expression(
+                            BinaryExpression {
                                 node: code.node("n1 + n2", 0),
                                 left: Identifier {
                                     node: code.node("n1", 1),
@@ -389,21 +435,22 @@ fn arrow_function() {
                                     name: "n2".into(),
                                 }
                                 .into(),
-                                operator: Operator::Plus,
+                                operator: BinaryOperator::Plus,
                             }
                             .into(),
-                        }
-                        .into(),
+                        ),
+                        expression: true,
+                        _async: false,
                     }
                     .into(),
                 ),
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
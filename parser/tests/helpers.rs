@@ -1,6 +1,34 @@
 #![allow(dead_code)]
 
-use parser::nodes::Node;
+use lexer::SourceFile;
+use parser::{
+    ast_types::{
+        node_objects::Node,
+        programs::{Program, ProgramBody, SourceType},
+        statements::Statement,
+    },
+    estree::to_estree,
+    Parser,
+};
+
+/// Parses `code` and serializes it to ESTree JSON, panicking on syntax errors
+pub fn estree(code: &str) -> serde_json::Value {
+    let program = Parser::new(code).parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    });
+    to_estree(&program, &SourceFile::new("main.ts", code))
+}
+
+/// The program that a script without comments is expected to parse to
+pub fn script(code: &str, body: Vec<Statement>) -> Program {
+    Program {
+        node: Node::new(0, code.len()),
+        source_type: SourceType::Script,
+        body: body.into_iter().map(ProgramBody::Statement).collect(),
+        comments: Vec::new(),
+    }
+}
 
 pub trait NodeConstructor {
     fn find_n(&self, target: &str, n: usize) -> Option<usize>;
@@ -56,14 +84,18 @@ impl NodeConstructor for str {
 
         assert!(self.len() > left_bytes.len() + right_bytes.len());
 
-        let left_index = self.find_n(left.0, left.1).expect(&format!(
-            "Could not find left value\n  left: {}\n  n: {}",
-            left.0, left.1
-        ));
-        let right_index = self.find_n(right.0, right.1).expect(&format!(
-            "Could not find right value\n  right: {}\n  n: {}",
-            right.0, right.1
-        ));
+        let left_index = self.find_n(left.0, left.1).unwrap_or_else(|| {
+            panic!(
+                "Could not find left value\n  left: {}\n  n: {}",
+                left.0, left.1
+            )
+        });
+        let right_index = self.find_n(right.0, right.1).unwrap_or_else(|| {
+            panic!(
+                "Could not find right value\n  right: {}\n  n: {}",
+                right.0, right.1
+            )
+        });
 
         assert!(
             left_index < right_index,
@@ -1,28 +1,30 @@
-use lexer::Operator;
+use lexer::{LogicalOperator, SourceFile};
 use parser::{
-    expressions::{BinaryExpression, BooleanLiteral, Identifier, NumberLiteral},
-    nodes::{program::Program, Node},
-    statements::{BlockStatement, IfStatement, ReturnStatement},
+    ast_types::{
+        expressions::{BooleanLiteral, LogicalExpression, NumberLiteral},
+        identifier::Identifier,
+        node_objects::Node,
+        statements::{BlockStatement, IfStatement, ReturnStatement},
+    },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn if_statement() {
     let code = "if (val && true || false) {}";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![IfStatement {
+    let expected = script(
+        code,
+        vec![IfStatement {
             node: Node::new(0, code.len()),
-            condition: BinaryExpression {
+            test: LogicalExpression {
                 node: code.between(("(", 0), (")", 0)),
-                left: BinaryExpression {
+                left: LogicalExpression {
                     node: code.node("val && true", 0),
                     left: Identifier {
                         node: code.node("val", 0),
@@ -34,7 +36,7 @@ fn if_statement() {
                         value: true,
                     }
                     .into(),
-                    operator: Operator::LogicalAnd,
+                    operator: LogicalOperator::And,
                 }
                 .into(),
                 right: BooleanLiteral {
@@ -42,21 +44,21 @@ fn if_statement() {
                     value: false,
                 }
                 .into(),
-                operator: Operator::LogicalOr,
+                operator: LogicalOperator::Or,
             }
             .into(),
-            body: BlockStatement {
+            consequent: BlockStatement {
                 node: code.node("{}", 0),
-                statements: Vec::new(),
+                body: Vec::new(),
             }
             .into(),
-            consequent: None,
+            alternate: None,
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -70,15 +72,14 @@ fn if_else_statement() {
     } else {
         return 50.5;
     }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![IfStatement {
+    let expected = script(
+        code,
+        vec![IfStatement {
             node: Node::new(0, code.len()),
-            condition: BinaryExpression {
+            test: LogicalExpression {
                 node: code.node("val && true", 0),
                 left: Identifier {
                     node: code.node("val", 0),
@@ -90,32 +91,36 @@ fn if_else_statement() {
                     value: true,
                 }
                 .into(),
-                operator: Operator::LogicalAnd,
+                operator: LogicalOperator::And,
             }
             .into(),
-            body: BlockStatement {
+            consequent: BlockStatement {
                 node: code.between_incl(("{", 0), ("}", 0)),
-                statements: vec![ReturnStatement {
+                body: vec![ReturnStatement {
                     node: code.node("return true;", 0),
-                    value: BooleanLiteral {
-                        node: code.node("true", 1),
-                        value: true,
-                    }
-                    .into(),
+                    argument: Some(
+                        BooleanLiteral {
+                            node: code.node("true", 1),
+                            value: true,
+                        }
+                        .into(),
+                    ),
                 }
                 .into()],
             }
             .into(),
-            consequent: Some(
+            alternate: Some(
                 BlockStatement {
                     node: code.between_incl(("{", 1), ("}", 1)),
-                    statements: vec![ReturnStatement {
+                    body: vec![ReturnStatement {
                         node: code.node("return 50.5;", 0),
-                        value: NumberLiteral {
-                            node: code.node("50.5", 0),
-                            value: 50.5,
-                        }
-                        .into(),
+                        argument: Some(
+                            NumberLiteral {
+                                node: code.node("50.5", 0),
+                                value: 50.5,
+                            }
+                            .into(),
+                        ),
                     }
                     .into()],
                 }
@@ -123,10 +128,10 @@ fn if_else_statement() {
             ),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -141,12 +146,11 @@ fn if_if_else_else_statement() {
     let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![IfStatement {
+    let expected = script(
+        code,
+        vec![IfStatement {
             node: Node::new(0, code.len()),
-            condition: BinaryExpression {
+            test: LogicalExpression {
                 node: code.node("foo && true", 0),
                 left: Identifier {
                     node: code.node("foo", 0),
@@ -158,18 +162,18 @@ fn if_if_else_else_statement() {
                     value: true,
                 }
                 .into(),
-                operator: Operator::LogicalAnd,
+                operator: LogicalOperator::And,
             }
             .into(),
-            body: BlockStatement {
+            consequent: BlockStatement {
                 node: code.node("{}", 0),
-                statements: vec![],
+                body: vec![],
             }
             .into(),
-            consequent: Some(
+            alternate: Some(
                 IfStatement {
                     node: code.between_incl(("if", 1), ("else {}", 0)),
-                    condition: BinaryExpression {
+                    test: LogicalExpression {
                         node: code.node("false || bar", 0),
                         left: BooleanLiteral {
                             node: code.node("false", 0),
@@ -181,18 +185,18 @@ fn if_if_else_else_statement() {
                             name: "bar".into(),
                         }
                         .into(),
-                        operator: Operator::LogicalOr,
+                        operator: LogicalOperator::Or,
                     }
                     .into(),
-                    body: BlockStatement {
+                    consequent: BlockStatement {
                         node: code.node("{}", 1),
-                        statements: vec![],
+                        body: vec![],
                     }
                     .into(),
-                    consequent: Some(
+                    alternate: Some(
                         BlockStatement {
                             node: code.node("{}", 2),
-                            statements: vec![],
+                            body: vec![],
                         }
                         .into(),
                     ),
@@ -201,10 +205,10 @@ fn if_if_else_else_statement() {
             ),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -221,42 +225,45 @@ fn if_else_without_curly_braces() {
     let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![IfStatement {
+    let expected = script(
+        code,
+        vec![IfStatement {
             node: code.between_incl(("if", 0), ("false;", 0)),
-            condition: Identifier {
+            test: Identifier {
                 node: code.node("foo", 0),
                 name: "foo".into(),
             }
             .into(),
-            body: ReturnStatement {
+            consequent: ReturnStatement {
                 node: code.node("return true;", 0),
-                value: BooleanLiteral {
-                    node: code.node("true", 0),
-                    value: true,
-                }
-                .into(),
+                argument: Some(
+                    BooleanLiteral {
+                        node: code.node("true", 0),
+                        value: true,
+                    }
+                    .into(),
+                ),
             }
             .into(),
-            consequent: Some(
+            alternate: Some(
                 ReturnStatement {
                     node: code.node("return false;", 0),
-                    value: BooleanLiteral {
-                        node: code.node("false", 0),
-                        value: false,
-                    }
-                    .into(),
+                    argument: Some(
+                        BooleanLiteral {
+                            node: code.node("false", 0),
+                            value: false,
+                        }
+                        .into(),
+                    ),
                 }
                 .into(),
             ),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -1,20 +1,24 @@
-use lexer::{Lexer, Operator, Token, TokenKind};
+use lexer::{
+    AssignmentOperator, BinaryOperator, Lexer, SourceFile, Token, TokenKind, UpdateOperator,
+};
 use parser::{
-    expressions::{
-        AssignmentExpression, BinaryExpression, CallExpression, ComputedProperty, Identifier,
-        MemberExpression, NumberLiteral, StringLiteral, UpdateExpression, UpdateOperator,
-        VariableKind,
-    },
-    nodes::{program::Program, Node},
-    statements::{
-        BlockStatement, ExpressionStatement, ForClassic, VariableDeclaration, VariableDeclarator,
-        WhileStatement,
+    ast_types::{
+        declarations::variable_declaration::{
+            VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+        },
+        expressions::{
+            AssignmentExpression, BinaryExpression, CallExpression, CallExpressionArgument,
+            CallExpressionCallee, MemberExpression, NumberLiteral, StringLiteral, UpdateExpression,
+        },
+        identifier::Identifier,
+        node_objects::Node,
+        statements::{BlockStatement, ExpressionStatement, ForStatement, WhileStatement},
     },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn lexer_works() {
@@ -32,42 +36,35 @@ fn lexer_works() {
 #[test]
 fn empty_program() {
     let code = "";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    assert_eq!(
-        result,
-        Ok(Program {
-            node: Node::new(0, 0),
-            shebang: None,
-            body: vec![],
-        })
-    );
+    assert_eq!(result.unwrap(), script(code, vec![]));
 }
 
 #[test]
 fn binary_operation() {
     let code = "let y = 6 + 5 * x";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: code.node("let y = 6 + 5 * x", 0),
-            kind: VariableKind::Let,
+            kind: VariableDeclarationKind::Let,
             declarations: vec![VariableDeclarator {
                 node: code.node("y = 6 + 5 * x", 0),
                 type_annotation: None,
                 id: Identifier {
                     node: code.node("y", 0),
                     name: "y".into(),
-                },
+                }
+                .into(),
                 init: Some(
                     BinaryExpression {
                         node: code.node("6 + 5 * x", 0),
-                        operator: Operator::Plus,
+                        operator: BinaryOperator::Plus,
                         left: NumberLiteral {
                             node: code.node("6", 0),
                             value: 6.0,
@@ -75,7 +72,7 @@ fn binary_operation() {
                         .into(),
                         right: BinaryExpression {
                             node: code.node("5 * x", 0),
-                            operator: Operator::Mult,
+                            operator: BinaryOperator::Mult,
                             left: NumberLiteral {
                                 node: code.node("5", 0),
                                 value: 5.0,
@@ -94,10 +91,10 @@ fn binary_operation() {
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
@@ -107,13 +104,12 @@ fn binary_operation() {
 #[test]
 fn computed_member_expression() {
     let code = "console[\"log\"]";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ExpressionStatement {
+    let expected = script(
+        code,
+        vec![ExpressionStatement {
             node: Node::new(0, code.len()),
             expression: MemberExpression {
                 node: Node::new(0, code.len()),
@@ -122,27 +118,24 @@ fn computed_member_expression() {
                     name: "console".into(),
                 }
                 .into(),
-                property: ComputedProperty {
-                    node: code.node("[\"log\"]", 0),
-                    expression: StringLiteral {
-                        node: code.node("\"log\"", 0),
-                        value: "\"log\"".into(),
-                    }
-                    .into(),
+                property: StringLiteral {
+                    node: code.node("\"log\"", 0),
+                    value: "log".into(),
                 }
                 .into(),
+                computed: true,
             }
             .into(),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
@@ -151,10 +144,9 @@ fn assignment_statement() {
     let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![
+    let expected = script(
+        code,
+        vec![
             ExpressionStatement {
                 node: code.node("foo -= 50.5;", 0),
                 expression: AssignmentExpression {
@@ -169,7 +161,7 @@ fn assignment_statement() {
                         value: 50.5,
                     }
                     .into(),
-                    operator: Operator::MinusEquals,
+                    operator: AssignmentOperator::MinusEquals,
                 }
                 .into(),
             }
@@ -185,37 +177,36 @@ fn assignment_statement() {
                     .into(),
                     right: StringLiteral {
                         node: code.node("\"World\"", 0),
-                        value: "\"World\"".into(),
+                        value: "World".into(),
                     }
                     .into(),
-                    operator: Operator::PlusEquals,
+                    operator: AssignmentOperator::PlusEquals,
                 }
                 .into(),
             }
             .into(),
         ],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn while_loop() {
     let code = "while (foo <= bar) { baz(1); }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![WhileStatement {
+    let expected = script(
+        code,
+        vec![WhileStatement {
             node: code.between_incl(("while", 0), ("}", 0)),
-            condition: BinaryExpression {
+            test: BinaryExpression {
                 node: code.node("foo <= bar", 0),
                 left: Identifier {
                     node: code.node("foo", 0),
@@ -227,25 +218,29 @@ fn while_loop() {
                     name: "bar".into(),
                 }
                 .into(),
-                operator: Operator::LessOrEqualsThan,
+                operator: BinaryOperator::LessOrEquals,
             }
             .into(),
             body: BlockStatement {
                 node: code.between_incl(("{", 0), ("}", 0)),
-                statements: vec![ExpressionStatement {
+                body: vec![ExpressionStatement {
                     node: code.node("baz(1);", 0),
                     expression: CallExpression {
                         node: code.node("baz(1)", 0),
-                        callee: Identifier {
-                            node: code.node("baz", 0),
-                            name: "baz".into(),
-                        }
-                        .into(),
-                        arguments: vec![NumberLiteral {
-                            node: code.node("1", 0),
-                            value: 1.0,
-                        }
-                        .into()],
+                        callee: CallExpressionCallee::Expression(
+                            Identifier {
+                                node: code.node("baz", 0),
+                                name: "baz".into(),
+                            }
+                            .into(),
+                        ),
+                        arguments: vec![CallExpressionArgument::Expression(
+                            NumberLiteral {
+                                node: code.node("1", 0),
+                                value: 1.0,
+                            }
+                            .into(),
+                        )],
                     }
                     .into(),
                 }
@@ -254,26 +249,25 @@ fn while_loop() {
             .into(),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn update_expression() {
     let code = "i++";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: code.node("i++", 0),
-        shebang: None,
-        body: vec![ExpressionStatement {
+    let expected = script(
+        code,
+        vec![ExpressionStatement {
             node: code.node("i++", 0),
             expression: UpdateExpression {
                 node: code.node("i++", 0),
@@ -288,26 +282,25 @@ fn update_expression() {
             .into(),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn for_loop() {
     let code = "for (let i = 0; i < code.length; i++) { bar(1); }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![ForClassic {
+    let expected = script(
+        code,
+        vec![ForStatement {
             node: Node::new(0, code.len()),
             init: Some(
                 VariableDeclaration {
@@ -328,7 +321,7 @@ fn for_loop() {
                             .into(),
                         ),
                     }],
-                    kind: VariableKind::Let,
+                    kind: VariableDeclarationKind::Let,
                 }
                 .into(),
             ),
@@ -352,9 +345,10 @@ fn for_loop() {
                             name: "length".into(),
                         }
                         .into(),
+                        computed: false,
                     }
                     .into(),
-                    operator: Operator::LessThan,
+                    operator: BinaryOperator::LessThan,
                 }
                 .into(),
             ),
@@ -373,20 +367,24 @@ fn for_loop() {
             ),
             body: BlockStatement {
                 node: code.between_incl(("{", 0), ("}", 0)),
-                statements: vec![ExpressionStatement {
+                body: vec![ExpressionStatement {
                     node: code.node("bar(1);", 0),
                     expression: CallExpression {
                         node: code.node("bar(1)", 0),
-                        callee: Identifier {
-                            node: code.node("bar", 0),
-                            name: "bar".into(),
-                        }
-                        .into(),
-                        arguments: vec![NumberLiteral {
-                            node: code.node("1", 0),
-                            value: 1.0,
-                        }
-                        .into()],
+                        callee: CallExpressionCallee::Expression(
+                            Identifier {
+                                node: code.node("bar", 0),
+                                name: "bar".into(),
+                            }
+                            .into(),
+                        ),
+                        arguments: vec![CallExpressionArgument::Expression(
+                            NumberLiteral {
+                                node: code.node("1", 0),
+                                value: 1.0,
+                            }
+                            .into(),
+                        )],
                     }
                     .into(),
                 }
@@ -395,12 +393,12 @@ fn for_loop() {
             .into(),
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
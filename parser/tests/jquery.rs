@@ -1,5 +1,6 @@
 use std::{fs, path::Path};
 
+use lexer::SourceFile;
 use parser::Parser;
 
 #[test]
@@ -19,7 +20,7 @@ fn parse_jquery() {
     let result = parser.parse();
 
     if let Err(err) = result {
-        err.print(&source_code);
+        err.print(&SourceFile::new("jquery-3.7.1.js", source_code));
         panic!();
     }
     // assert!(result.is_ok());
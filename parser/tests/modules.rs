@@ -0,0 +1,180 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn source_type() {
+    assert_eq!(estree("let a = 1;")["sourceType"], "script");
+    assert_eq!(estree("import 'a';")["sourceType"], "module");
+    assert_eq!(estree("let a = 1;\nexport { a };")["sourceType"], "module");
+}
+
+#[test]
+fn imports() {
+    let code = "import a from 'a';
+import b, { c, d as e, default as f } from \"b\";
+import * as ns from './ns.js';
+import g, * as h from 'g';
+import './polyfill.js'";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "ImportDeclaration");
+    assert_eq!(body[0]["specifiers"][0]["type"], "ImportDefaultSpecifier");
+    assert_eq!(body[0]["specifiers"][0]["local"]["name"], "a");
+    assert_eq!(body[0]["source"]["value"], "a");
+    assert_eq!(body[0]["end"], code.find(';').unwrap() + 1);
+
+    let named = &body[1]["specifiers"];
+    assert_eq!(named.as_array().unwrap().len(), 4);
+    assert_eq!(named[1]["type"], "ImportSpecifier");
+    assert_eq!(named[1]["imported"]["name"], "c");
+    assert_eq!(named[1]["local"]["name"], "c");
+    assert_eq!(named[2]["imported"]["name"], "d");
+    assert_eq!(named[2]["local"]["name"], "e");
+    assert_eq!(named[3]["imported"]["name"], "default");
+
+    assert_eq!(body[2]["specifiers"][0]["type"], "ImportNamespaceSpecifier");
+    assert_eq!(body[2]["specifiers"][0]["local"]["name"], "ns");
+    assert_eq!(body[3]["specifiers"][1]["type"], "ImportNamespaceSpecifier");
+
+    assert_eq!(body[4]["specifiers"], serde_json::json!([]));
+    assert_eq!(body[4]["source"]["value"], "./polyfill.js");
+    assert_eq!(body[4]["end"], code.len());
+}
+
+#[test]
+fn named_exports() {
+    let code = "export const a = 1;
+export function b() {}
+export class C {}
+export { a as d, b };
+export { e as default } from 'e';";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "ExportNamedDeclaration");
+    assert_eq!(body[0]["declaration"]["type"], "VariableDeclaration");
+    assert_eq!(body[0]["source"], serde_json::Value::Null);
+    assert_eq!(body[1]["declaration"]["type"], "FunctionDeclaration");
+    assert_eq!(body[2]["declaration"]["type"], "ClassDeclaration");
+
+    assert_eq!(body[3]["declaration"], serde_json::Value::Null);
+    assert_eq!(body[3]["specifiers"][0]["local"]["name"], "a");
+    assert_eq!(body[3]["specifiers"][0]["exported"]["name"], "d");
+    assert_eq!(body[3]["specifiers"][1]["exported"]["name"], "b");
+
+    assert_eq!(body[4]["specifiers"][0]["exported"]["name"], "default");
+    assert_eq!(body[4]["source"]["value"], "e");
+}
+
+#[test]
+fn export_all() {
+    let ast = estree("export * from 'a'; export * as ns from 'b';");
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "ExportAllDeclaration");
+    assert_eq!(body[0]["exported"], serde_json::Value::Null);
+    assert_eq!(body[1]["exported"]["name"], "ns");
+    assert_eq!(body[1]["source"]["value"], "b");
+}
+
+#[test]
+fn default_exports() {
+    let ast = estree(
+        "export default function () {}
+export default function named() {}
+export default class extends A {}
+export default a + 1;",
+    );
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "ExportDefaultDeclaration");
+    assert_eq!(body[0]["declaration"]["type"], "FunctionDeclaration");
+    assert_eq!(body[0]["declaration"]["id"], serde_json::Value::Null);
+    assert_eq!(body[1]["declaration"]["id"]["name"], "named");
+    assert_eq!(body[2]["declaration"]["type"], "ClassDeclaration");
+    assert_eq!(body[2]["declaration"]["superClass"]["name"], "A");
+    assert_eq!(body[3]["declaration"]["type"], "BinaryExpression");
+}
+
+#[test]
+fn type_only_imports_and_exports() {
+    let ast = estree(
+        "import type { A } from 'a';
+import { type B, c } from 'b';
+export type { A };
+export type * from 'd';",
+    );
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["importKind"], "type");
+    assert_eq!(body[1]["importKind"], "value");
+    assert_eq!(body[1]["specifiers"][0]["importKind"], "type");
+    assert_eq!(body[1]["specifiers"][0]["imported"]["name"], "B");
+    assert_eq!(body[1]["specifiers"][1]["importKind"], "value");
+    assert_eq!(body[2]["exportKind"], "type");
+    assert_eq!(body[3]["exportKind"], "type");
+}
+
+#[test]
+fn exported_types_and_interfaces() {
+    let code = "export type A = B;
+export type Pair<T> = Array<T>;
+export interface I {}
+export interface J<T> { a: T; readonly b?: string }
+import type C from 'c';";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    let alias = &body[0]["declaration"];
+    assert_eq!(body[0]["exportKind"], "value");
+    assert_eq!(alias["type"], "TypeAliasDeclaration");
+    assert_eq!(alias["id"]["name"], "A");
+    assert_eq!(alias["typeAnnotation"]["typeName"]["name"], "B");
+    assert_eq!(body[0]["end"], code.find(';').unwrap() + 1);
+
+    let alias = &body[1]["declaration"];
+    assert_eq!(alias["typeParameters"]["parameters"][0]["id"]["name"], "T");
+    assert_eq!(alias["typeAnnotation"]["typeName"]["name"], "Array");
+
+    let interface = &body[2]["declaration"];
+    assert_eq!(interface["type"], "InterfaceDeclaration");
+    assert_eq!(interface["id"]["name"], "I");
+    assert_eq!(interface["body"]["body"], serde_json::json!([]));
+
+    let members = &body[3]["declaration"]["body"]["body"];
+    assert_eq!(members[0]["key"]["name"], "a");
+    assert_eq!(members[0]["optional"], false);
+    assert_eq!(members[1]["key"]["name"], "b");
+    assert_eq!(members[1]["readonly"], true);
+    assert_eq!(members[1]["optional"], true);
+
+    assert_eq!(body[4]["importKind"], "type");
+    assert_eq!(body[4]["specifiers"][0]["local"]["name"], "C");
+}
+
+#[test]
+fn from_is_not_reserved() {
+    let ast = estree("let from = 1; Array.from(from);");
+    assert_eq!(ast["sourceType"], "script");
+}
+
+#[test]
+fn missing_from() {
+    let code = "import { a } 'a';";
+    let err = Parser::new(code).parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedFrom);
+    assert_eq!(err.kind.code(), "P0016");
+    assert_eq!(err.token.start, code.find("'a'").unwrap());
+}
+
+#[test]
+fn export_without_declaration() {
+    let err = Parser::new("export a;").parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedExportDeclaration);
+    assert_eq!(err.kind.code(), "P0017");
+}
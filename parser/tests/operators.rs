@@ -0,0 +1,156 @@
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn in_and_instanceof() {
+    let ast = estree("a in b; x instanceof Y && 'k' in o;");
+
+    let expr = &ast["body"][0]["expression"];
+    assert_eq!(expr["type"], "BinaryExpression");
+    assert_eq!(expr["operator"], "in");
+    assert_eq!(expr["left"]["name"], "a");
+
+    let expr = &ast["body"][1]["expression"];
+    assert_eq!(expr["type"], "LogicalExpression");
+    assert_eq!(expr["left"]["operator"], "instanceof");
+    assert_eq!(expr["left"]["right"]["name"], "Y");
+    assert_eq!(expr["right"]["operator"], "in");
+}
+
+#[test]
+fn in_is_not_an_operator_in_for_init() {
+    let ast = estree("for (k in o) {} for (var i = 0, n = (a in b); ;) {}");
+
+    let for_in = &ast["body"][0];
+    assert_eq!(for_in["type"], "ForInStatement");
+    assert_eq!(for_in["left"]["name"], "k");
+    assert_eq!(for_in["right"]["name"], "o");
+
+    let init = &ast["body"][1]["init"]["declarations"][1]["init"];
+    assert_eq!(init["type"], "ParenthesizedExpression");
+    assert_eq!(init["expression"]["operator"], "in");
+}
+
+#[test]
+fn shift_operators() {
+    let ast = estree("a << 2; a >> 1; a >>> 2; a <<= 1; a >>= 1; a >>>= 3; a > 1 >= b;");
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["expression"]["operator"], "<<");
+    assert_eq!(body[1]["expression"]["operator"], ">>");
+    assert_eq!(body[2]["expression"]["operator"], ">>>");
+    assert_eq!(body[2]["expression"]["right"]["value"], 2.0);
+    assert_eq!(body[3]["expression"]["type"], "AssignmentExpression");
+    assert_eq!(body[3]["expression"]["operator"], "<<=");
+    assert_eq!(body[4]["expression"]["operator"], ">>=");
+    assert_eq!(body[5]["expression"]["operator"], ">>>=");
+
+    let cmp = &body[6]["expression"];
+    assert_eq!(cmp["operator"], ">=");
+    assert_eq!(cmp["left"]["operator"], ">");
+}
+
+#[test]
+fn nested_type_arguments_are_not_a_shift() {
+    let ast = estree("let a: Array<Array<number>> = b >> c;");
+
+    let decl = &ast["body"][0]["declarations"][0];
+    let outer = &decl["typeAnnotation"]["typeValue"];
+    assert_eq!(outer["typeName"]["name"], "Array");
+    assert_eq!(outer["typeParams"][0]["typeName"]["name"], "Array");
+    assert_eq!(outer["typeParams"][0]["typeParams"][0]["kind"], "number");
+    assert_eq!(decl["init"]["operator"], ">>");
+}
+
+#[test]
+fn contextual_keywords_as_identifiers() {
+    let ast = estree("type = 1; let of = type + number; string(of);");
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["expression"]["type"], "AssignmentExpression");
+    assert_eq!(body[0]["expression"]["left"]["name"], "type");
+    assert_eq!(body[1]["declarations"][0]["id"]["name"], "of");
+    assert_eq!(
+        body[1]["declarations"][0]["init"]["right"]["name"],
+        "number"
+    );
+    assert_eq!(body[2]["expression"]["callee"]["name"], "string");
+}
+
+#[test]
+fn bitwise_operators() {
+    let ast = estree("~a; a ^ b | c & d; a |= 1; a ^= 2; a &= 3;");
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["expression"]["type"], "UnaryExpression");
+    assert_eq!(body[0]["expression"]["operator"], "~");
+
+    // `&` binds tighter than `^`, which binds tighter than `|`
+    let expr = &body[1]["expression"];
+    assert_eq!(expr["operator"], "|");
+    assert_eq!(expr["left"]["operator"], "^");
+    assert_eq!(expr["right"]["operator"], "&");
+
+    assert_eq!(body[2]["expression"]["operator"], "|=");
+    assert_eq!(body[3]["expression"]["operator"], "^=");
+    assert_eq!(body[4]["expression"]["operator"], "&=");
+}
+
+#[test]
+fn sequence_expressions() {
+    let code = "a = 1, b = 2; for (i = 0, n = 3; i < n; i++, j--) {} f(a, b);";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    let seq = &body[0]["expression"];
+    assert_eq!(seq["type"], "SequenceExpression");
+    assert_eq!(seq["expressions"][1]["type"], "AssignmentExpression");
+    assert_eq!(seq["start"], 0);
+    assert_eq!(seq["end"], code.find(';').unwrap());
+
+    assert_eq!(body[1]["init"]["type"], "SequenceExpression");
+    assert_eq!(body[1]["update"]["expressions"][1]["operator"], "--");
+
+    // The commas of a list separate its items instead
+    let args = &body[2]["expression"]["arguments"];
+    assert_eq!(args.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn division_after_expression_brace() {
+    let ast = estree("f(function(){} / 2 / 3); x = class {} / 2; y = async function(){} / 2;");
+
+    let div = &ast["body"][0]["expression"]["arguments"][0];
+    assert_eq!(div["operator"], "/");
+    assert_eq!(div["right"]["value"], 3);
+    assert_eq!(div["left"]["operator"], "/");
+    assert_eq!(div["left"]["left"]["type"], "FunctionExpression");
+    assert_eq!(
+        ast["body"][1]["expression"]["right"]["left"]["type"],
+        "ClassExpression"
+    );
+    assert_eq!(
+        ast["body"][2]["expression"]["right"]["left"]["type"],
+        "FunctionExpression"
+    );
+
+    // There's no semicolon before the next line, since it continues the expression
+    let ast = estree("let a = {}\n/foo/g.test(x)");
+    let init = &ast["body"][0]["declarations"][0]["init"];
+    assert_eq!(ast["body"].as_array().unwrap().len(), 1);
+    assert_eq!(init["operator"], "/");
+    assert_eq!(init["left"]["left"]["type"], "ObjectExpression");
+    assert_eq!(init["left"]["right"]["name"], "foo");
+    assert_eq!(init["right"]["type"], "CallExpression");
+}
+
+#[test]
+fn regex_after_block_brace() {
+    let ast = estree("if (a) {}\n/foo/g.test(x); f = () => {}\n/bar/.test(y)");
+
+    let call = &ast["body"][1]["expression"];
+    assert_eq!(call["callee"]["object"]["regex"]["pattern"], "foo");
+    let call = &ast["body"][3]["expression"];
+    assert_eq!(call["callee"]["object"]["regex"]["pattern"], "bar");
+}
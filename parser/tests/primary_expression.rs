@@ -1,38 +1,46 @@
-use lexer::TypeKeyword;
+use lexer::{SourceFile, TypeKeyword};
 use parser::{
-    expressions::{
-        types::{KeywordType, TypeAnnotation},
-        ArrayExpression, BooleanLiteral, CallExpression, ComputedProperty, Identifier,
-        MemberExpression, Method, NumberLiteral, ObjectExpression, ParenthesisExpression,
-        StringLiteral, VariableKind, KV,
-    },
-    nodes::{program::Program, Node},
-    statements::{
-        BlockStatement, ExpressionStatement, Parameter, VariableDeclaration, VariableDeclarator,
+    ast_types::{
+        declarations::{
+            function_declaration::Parameter,
+            variable_declaration::{
+                VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+            },
+        },
+        expressions::{
+            types::{KeywordType, TypeAnnotation},
+            ArrayElement, ArrayExpression, BooleanLiteral, CallExpression, CallExpressionArgument,
+            CallExpressionCallee, FunctionExpression, MemberExpression, NumberLiteral,
+            ObjectExpression, ParenthesisExpression, StringLiteral,
+        },
+        identifier::Identifier,
+        node_objects::Node,
+        property::{Property, PropertyKind},
+        statements::{ExpressionStatement, FunctionBody, FunctionBodyBody},
     },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn assignment_number_literal() {
     let code = "let a = 50.5";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("a", 0), ("50.5", 0)),
                 id: Identifier {
                     node: code.node("a", 0),
                     name: "a".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     NumberLiteral {
@@ -42,36 +50,36 @@ fn assignment_number_literal() {
                     .into(),
                 ),
             }],
-            kind: VariableKind::Let,
+            kind: VariableDeclarationKind::Let,
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn assignment_paren_literal() {
     let code = "const a = (50.5)";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
             declarations: vec![VariableDeclarator {
                 node: code.node("a = (50.5)", 0),
                 id: Identifier {
                     node: code.node("a", 0),
                     name: "a".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ParenthesisExpression {
@@ -85,62 +93,70 @@ fn assignment_paren_literal() {
                     .into(),
                 ),
             }],
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn array_literal() {
     let code = "const nums = [1, 2, 3, 4];";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
             declarations: vec![VariableDeclarator {
                 node: code.node("nums = [1, 2, 3, 4]", 0),
                 id: Identifier {
                     node: code.node("nums", 0),
                     name: "nums".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ArrayExpression {
                         node: code.between_incl(("[", 0), ("]", 0)),
-                        items: vec![
-                            NumberLiteral {
-                                node: code.node("1", 0),
-                                value: 1.0,
-                            }
-                            .into(),
-                            NumberLiteral {
-                                node: code.node("2", 0),
-                                value: 2.0,
-                            }
-                            .into(),
-                            NumberLiteral {
-                                node: code.node("3", 0),
-                                value: 3.0,
-                            }
-                            .into(),
-                            NumberLiteral {
-                                node: code.node("4", 0),
-                                value: 4.0,
-                            }
-                            .into(),
+                        elements: vec![
+                            Some(ArrayElement::Expression(
+                                NumberLiteral {
+                                    node: code.node("1", 0),
+                                    value: 1.0,
+                                }
+                                .into(),
+                            )),
+                            Some(ArrayElement::Expression(
+                                NumberLiteral {
+                                    node: code.node("2", 0),
+                                    value: 2.0,
+                                }
+                                .into(),
+                            )),
+                            Some(ArrayElement::Expression(
+                                NumberLiteral {
+                                    node: code.node("3", 0),
+                                    value: 3.0,
+                                }
+                                .into(),
+                            )),
+                            Some(ArrayElement::Expression(
+                                NumberLiteral {
+                                    node: code.node("4", 0),
+                                    value: 4.0,
+                                }
+                                .into(),
+                            )),
                         ],
                     }
                     .into(),
@@ -148,57 +164,65 @@ fn array_literal() {
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn nested_array() {
     let code = "const nums = [[1], [2]];";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
             declarations: vec![VariableDeclarator {
                 node: code.node("nums = [[1], [2]]", 0),
                 id: Identifier {
                     node: code.node("nums", 0),
                     name: "nums".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ArrayExpression {
                         node: code.between_incl(("[[", 0), ("]]", 0)),
-                        items: vec![
-                            ArrayExpression {
-                                node: code.node("[1]", 0),
-                                items: vec![NumberLiteral {
-                                    node: code.node("1", 0),
-                                    value: 1.0,
+                        elements: vec![
+                            Some(ArrayElement::Expression(
+                                ArrayExpression {
+                                    node: code.node("[1]", 0),
+                                    elements: vec![Some(ArrayElement::Expression(
+                                        NumberLiteral {
+                                            node: code.node("1", 0),
+                                            value: 1.0,
+                                        }
+                                        .into(),
+                                    ))],
                                 }
-                                .into()],
-                            }
-                            .into(),
-                            ArrayExpression {
-                                node: code.node("[2]", 0),
-                                items: vec![NumberLiteral {
-                                    node: code.node("2", 0),
-                                    value: 2.0,
+                                .into(),
+                            )),
+                            Some(ArrayElement::Expression(
+                                ArrayExpression {
+                                    node: code.node("[2]", 0),
+                                    elements: vec![Some(ArrayElement::Expression(
+                                        NumberLiteral {
+                                            node: code.node("2", 0),
+                                            value: 2.0,
+                                        }
+                                        .into(),
+                                    ))],
                                 }
-                                .into()],
-                            }
-                            .into(),
+                                .into(),
+                            )),
                         ],
                     }
                     .into(),
@@ -206,40 +230,41 @@ fn nested_array() {
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn object_literal() {
     let code = "var obj = { k1: 101, k2: \"2\", k3: true };";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: code.between_incl(("var", 0), ("};", 0)),
-            kind: VariableKind::Var,
+            kind: VariableDeclarationKind::Var,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("obj", 0), ("}", 0)),
                 id: Identifier {
                     node: code.node("obj", 0),
                     name: "obj".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ObjectExpression {
                         node: code.between_incl(("{", 0), ("}", 0)),
-                        items: vec![
-                            KV {
+                        properties: vec![
+                            Property {
+                                node: code.node("k1: 101", 0),
                                 key: Identifier {
                                     node: code.node("k1", 0),
                                     name: "k1".into(),
@@ -250,9 +275,14 @@ fn object_literal() {
                                     value: 101.0,
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: false,
                             }
                             .into(),
-                            KV {
+                            Property {
+                                node: code.node("k2: \"2\"", 0),
                                 key: Identifier {
                                     node: code.node("k2", 0),
                                     name: "k2".into(),
@@ -260,12 +290,17 @@ fn object_literal() {
                                 .into(),
                                 value: StringLiteral {
                                     node: code.node("\"2\"", 0),
-                                    value: "\"2\"".into(),
+                                    value: "2".into(),
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: false,
                             }
                             .into(),
-                            KV {
+                            Property {
+                                node: code.node("k3: true", 0),
                                 key: Identifier {
                                     node: code.node("k3", 0),
                                     name: "k3".into(),
@@ -276,6 +311,10 @@ fn object_literal() {
                                     value: true,
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: false,
                             }
                             .into(),
                         ],
@@ -285,98 +324,113 @@ fn object_literal() {
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn object_shorthand_property() {
     let code = "var obj = { name, age };";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let name = Identifier {
+        node: code.node("name", 0),
+        name: "name".into(),
+    };
+    let age = Identifier {
+        node: code.node("age", 0),
+        name: "age".into(),
+    };
+
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: code.between_incl(("var", 0), ("};", 0)),
-            kind: VariableKind::Var,
+            kind: VariableDeclarationKind::Var,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("obj", 0), ("}", 0)),
                 id: Identifier {
                     node: code.node("obj", 0),
                     name: "obj".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ObjectExpression {
                         node: code.between_incl(("{", 0), ("}", 0)),
-                        items: [
-                            Identifier {
-                                node: code.node("name", 0),
-                                name: "name".into(),
+                        properties: vec![
+                            Property {
+                                node: name.node,
+                                key: name.clone().into(),
+                                value: name.into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: true,
+                                computed: false,
                             }
                             .into(),
-                            Identifier {
-                                node: code.node("age", 0),
-                                name: "age".into(),
+                            Property {
+                                node: age.node,
+                                key: age.clone().into(),
+                                value: age.into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: true,
+                                computed: false,
                             }
                             .into(),
-                        ]
-                        .to_vec(),
+                        ],
                     }
                     .into(),
                 ),
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn object_computed_property() {
     let code = "var obj = { [key]: value, [123]: 456, [\"hello\"]: \"world\" };";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: code.between_incl(("var", 0), ("};", 0)),
-            kind: VariableKind::Var,
-            declarations: [VariableDeclarator {
+            kind: VariableDeclarationKind::Var,
+            declarations: vec![VariableDeclarator {
                 node: code.between_incl(("obj", 0), ("}", 0)),
                 id: Identifier {
                     node: code.node("obj", 0),
                     name: "obj".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ObjectExpression {
                         node: code.between_incl(("{", 0), ("}", 0)),
-                        items: [
-                            KV {
-                                key: ComputedProperty {
-                                    node: code.node("[key]", 0),
-                                    expression: Identifier {
-                                        node: code.node("key", 0),
-                                        name: "key".into(),
-                                    }
-                                    .into(),
+                        properties: vec![
+                            Property {
+                                node: code.node("[key]: value", 0),
+                                key: Identifier {
+                                    node: code.node("key", 0),
+                                    name: "key".into(),
                                 }
                                 .into(),
                                 value: Identifier {
@@ -384,16 +438,17 @@ fn object_computed_property() {
                                     name: "value".into(),
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: true,
                             }
                             .into(),
-                            KV {
-                                key: ComputedProperty {
-                                    node: code.node("[123]", 0),
-                                    expression: NumberLiteral {
-                                        node: code.node("123", 0),
-                                        value: 123.0,
-                                    }
-                                    .into(),
+                            Property {
+                                node: code.node("[123]: 456", 0),
+                                key: NumberLiteral {
+                                    node: code.node("123", 0),
+                                    value: 123.0,
                                 }
                                 .into(),
                                 value: NumberLiteral {
@@ -401,141 +456,162 @@ fn object_computed_property() {
                                     value: 456.0,
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: true,
                             }
                             .into(),
-                            KV {
-                                key: ComputedProperty {
-                                    node: code.node("[\"hello\"]", 0),
-                                    expression: StringLiteral {
-                                        node: code.node("\"hello\"", 0),
-                                        value: "\"hello\"".into(),
-                                    }
-                                    .into(),
+                            Property {
+                                node: code.node("[\"hello\"]: \"world\"", 0),
+                                key: StringLiteral {
+                                    node: code.node("\"hello\"", 0),
+                                    value: "hello".into(),
                                 }
                                 .into(),
                                 value: StringLiteral {
                                     node: code.node("\"world\"", 0),
-                                    value: "\"world\"".into(),
+                                    value: "world".into(),
                                 }
                                 .into(),
+                                kind: PropertyKind::Init,
+                                method: false,
+                                shorthand: false,
+                                computed: true,
                             }
                             .into(),
-                        ]
-                        .to_vec(),
+                        ],
                     }
                     .into(),
                 ),
-            }]
-            .to_vec(),
+            }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn object_method() {
     let code = "var obj = { print(name: string) { console.log(name); } };";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: code.between_incl(("var", 0), ("};", 0)),
-            kind: VariableKind::Var,
+            kind: VariableDeclarationKind::Var,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("obj", 0), ("}", 1)),
                 id: Identifier {
                     node: code.node("obj", 0),
                     name: "obj".into(),
-                },
+                }
+                .into(),
                 type_annotation: None,
                 init: Some(
                     ObjectExpression {
                         node: code.between_incl(("{", 0), ("}", 1)),
-                        items: [Method {
+                        properties: vec![Property {
                             node: code.between_incl(("print", 0), ("}", 0)),
-                            is_async: false,
-                            is_generator: false,
-                            id: Identifier {
+                            key: Identifier {
                                 node: code.node("print", 0),
                                 name: "print".into(),
-                            },
-                            type_parameters: None,
-                            parameters: [Parameter {
-                                node: code.node("name: string", 0),
-                                identifier: Identifier {
-                                    node: code.node("name", 0),
-                                    name: "name".into(),
-                                },
-                                type_annotation: Some(TypeAnnotation {
-                                    node: code.node(": string", 0),
-                                    type_value: KeywordType {
-                                        node: code.node("string", 0),
-                                        kind: TypeKeyword::String,
+                            }
+                            .into(),
+                            value: FunctionExpression {
+                                node: code.between_incl(("(name", 0), ("}", 0)),
+                                id: None,
+                                generator: false,
+                                _async: false,
+                                type_parameters: None,
+                                params: vec![Parameter {
+                                    node: code.node("name: string", 0),
+                                    pattern: Identifier {
+                                        node: code.node("name", 0),
+                                        name: "name".into(),
                                     }
                                     .into(),
-                                }),
-                                optional: false,
-                            }
-                            .into()]
-                            .to_vec(),
-                            return_type: None,
-                            body: BlockStatement {
-                                node: code.between_incl(("{", 1), ("}", 0)),
-                                statements: [ExpressionStatement {
-                                    node: code.node("console.log(name);", 0),
-                                    expression: CallExpression {
-                                        node: code.node("console.log(name)", 0),
-                                        callee: MemberExpression {
-                                            node: code.node("console.log", 0),
-                                            object: Identifier {
-                                                node: code.node("console", 0),
-                                                name: "console".into(),
-                                            }
-                                            .into(),
-                                            property: Identifier {
-                                                node: code.node("log", 0),
-                                                name: "log".into(),
+                                    type_annotation: Some(TypeAnnotation {
+                                        node: code.node(": string", 0),
+                                        type_value: KeywordType {
+                                            node: code.node("string", 0),
+                                            kind: TypeKeyword::String,
+                                        }
+                                        .into(),
+                                    }),
+                                    optional: false,
+                                    accessibility: None,
+                                    readonly: false,
+                                    _override: false,
+                                }],
+                                return_type: None,
+                                body: Some(FunctionBody {
+                                    node: code.between_incl(("{", 1), ("}", 0)),
+                                    body: vec![FunctionBodyBody::Statement(
+                                        ExpressionStatement {
+                                            node: code.node("console.log(name);", 0),
+                                            expression: CallExpression {
+                                                node: code.node("console.log(name)", 0),
+                                                callee: CallExpressionCallee::Expression(
+                                                    MemberExpression {
+                                                        node: code.node("console.log", 0),
+                                                        object: Identifier {
+                                                            node: code.node("console", 0),
+                                                            name: "console".into(),
+                                                        }
+                                                        .into(),
+                                                        property: Identifier {
+                                                            node: code.node("log", 0),
+                                                            name: "log".into(),
+                                                        }
+                                                        .into(),
+                                                        computed: false,
+                                                    }
+                                                    .into(),
+                                                ),
+                                                arguments: vec![
+                                                    CallExpressionArgument::Expression(
+                                                        Identifier {
+                                                            node: code.node("name", 1),
+                                                            name: "name".into(),
+                                                        }
+                                                        .into(),
+                                                    ),
+                                                ],
                                             }
                                             .into(),
                                         }
                                         .into(),
-                                        arguments: [Identifier {
-                                            node: code.node("name", 1),
-                                            name: "name".into(),
-                                        }
-                                        .into()]
-                                        .to_vec(),
-                                    }
-                                    .into(),
-                                }
-                                .into()]
-                                .to_vec(),
-                            },
+                                    )],
+                                }),
+                            }
+                            .into(),
+                            kind: PropertyKind::Init,
+                            method: true,
+                            shorthand: false,
+                            computed: false,
                         }
-                        .into()]
-                        .to_vec(),
+                        .into()],
                     }
                     .into(),
                 ),
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
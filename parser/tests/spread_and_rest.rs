@@ -0,0 +1,108 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+/// The expression of the first statement in the program
+fn first_expression(ast: &serde_json::Value) -> &serde_json::Value {
+    &ast["body"][0]["expression"]
+}
+
+#[test]
+fn spread_in_array_and_object_literals() {
+    let code = "x = [1, ...nums]; y = { a: 1, ...rest };";
+    let ast = estree(code);
+
+    let spread = &first_expression(&ast)["right"]["elements"][1];
+    assert_eq!(spread["type"], "SpreadElement");
+    assert_eq!(spread["argument"]["name"], "nums");
+    assert_eq!(spread["start"], code.find("...nums").unwrap());
+
+    let spread = &ast["body"][1]["expression"]["right"]["properties"][1];
+    assert_eq!(spread["type"], "SpreadElement");
+    assert_eq!(spread["argument"]["name"], "rest");
+}
+
+#[test]
+fn spread_in_call_arguments() {
+    let ast = estree("f(a, ...b);");
+    let call = first_expression(&ast);
+
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["arguments"][0]["name"], "a");
+    assert_eq!(call["arguments"][1]["type"], "SpreadElement");
+    assert_eq!(call["arguments"][1]["argument"]["name"], "b");
+}
+
+#[test]
+fn new_expression_arguments() {
+    let code = "new a.Point(...coords); new Date;";
+    let ast = estree(code);
+
+    let new_point = first_expression(&ast);
+    assert_eq!(new_point["type"], "NewExpression");
+    assert_eq!(new_point["callee"]["type"], "MemberExpression");
+    assert_eq!(new_point["arguments"][0]["type"], "SpreadElement");
+    assert_eq!(new_point["end"], code.find(';').unwrap());
+
+    let new_date = &ast["body"][1]["expression"];
+    assert_eq!(new_date["callee"]["name"], "Date");
+    assert_eq!(new_date["arguments"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn rest_and_default_parameters() {
+    let code = "function f(a: number = 1, ...rest: string[]) {}";
+    let ast = estree(code);
+    let params = &ast["body"][0]["params"];
+
+    assert_eq!(params[0]["pattern"]["type"], "AssignmentPattern");
+    assert_eq!(params[0]["pattern"]["left"]["name"], "a");
+    assert_eq!(params[0]["pattern"]["right"]["value"], 1.0);
+    assert_eq!(
+        params[0]["typeAnnotation"]["typeValue"]["type"],
+        "KeywordType"
+    );
+
+    assert_eq!(params[1]["pattern"]["type"], "RestElement");
+    assert_eq!(params[1]["pattern"]["argument"]["name"], "rest");
+    assert_eq!(
+        params[1]["typeAnnotation"]["typeValue"]["type"],
+        "ArrayType"
+    );
+    assert_eq!(params[1]["end"], code.find(")").unwrap());
+}
+
+#[test]
+fn arrow_function_with_rest_and_default_parameters() {
+    let ast = estree("const f = (a = 1, ...b) => a;");
+    let arrow = &ast["body"][0]["declarations"][0]["init"];
+
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["params"][0]["pattern"]["type"], "AssignmentPattern");
+    assert_eq!(arrow["params"][1]["pattern"]["type"], "RestElement");
+}
+
+#[test]
+fn spread_reinterpreted_as_rest_element() {
+    let ast = estree("[first, ...others] = list;");
+    let left = &first_expression(&ast)["left"];
+
+    assert_eq!(left["type"], "ArrayPattern");
+    assert_eq!(left["elements"][1]["type"], "RestElement");
+    assert_eq!(left["elements"][1]["argument"]["name"], "others");
+}
+
+#[test]
+fn rest_must_be_last() {
+    for code in [
+        "function f(...a, b) {}",
+        "[...a, b] = c;",
+        "({ ...a, b } = c);",
+    ] {
+        let err = Parser::new(code).parse().unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::RestElementNotLast, "{code}");
+        assert_eq!(err.kind.code(), "P0020");
+    }
+}
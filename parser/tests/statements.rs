@@ -0,0 +1,125 @@
+use parser::{utils::parser_error::ErrorKind, Parser};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::estree;
+
+#[test]
+fn try_catch_finally() {
+    let code = "try { a(); } catch (e) { b(e); } finally { c(); }
+try {} catch ({ message }) {}
+try {} catch {}
+try {} finally {}";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    let stmt = &body[0];
+    assert_eq!(stmt["type"], "TryStatement");
+    assert_eq!(stmt["block"]["body"][0]["type"], "ExpressionStatement");
+    assert_eq!(stmt["handler"]["type"], "CatchClause");
+    assert_eq!(stmt["handler"]["param"]["name"], "e");
+    assert_eq!(stmt["handler"]["start"], code.find("catch").unwrap());
+    assert_eq!(stmt["finalizer"]["type"], "BlockStatement");
+    assert_eq!(stmt["end"], code.find('\n').unwrap());
+
+    assert_eq!(body[1]["handler"]["param"]["type"], "ObjectPattern");
+    assert_eq!(body[2]["handler"]["param"], serde_json::Value::Null);
+    assert_eq!(body[3]["handler"], serde_json::Value::Null);
+    assert_eq!(body[3]["finalizer"]["body"], serde_json::json!([]));
+}
+
+#[test]
+fn try_without_catch_or_finally() {
+    let err = Parser::new("try {} a();").parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::ExpectedCatchOrFinally);
+    assert_eq!(err.kind.code(), "P0022");
+}
+
+#[test]
+fn switch_statement() {
+    let code = "switch (a) {
+    case 1:
+    case 2:
+        b();
+        break;
+    default:
+        c();
+}";
+    let ast = estree(code);
+    let stmt = &ast["body"][0];
+
+    assert_eq!(stmt["type"], "SwitchStatement");
+    assert_eq!(stmt["discriminant"]["name"], "a");
+    assert_eq!(stmt["end"], code.len());
+
+    let cases = &stmt["cases"];
+    assert_eq!(cases.as_array().unwrap().len(), 3);
+    assert_eq!(cases[0]["type"], "SwitchCase");
+    assert_eq!(cases[0]["test"]["value"], 1.0);
+    assert_eq!(cases[0]["consequent"], serde_json::json!([]));
+    assert_eq!(cases[1]["consequent"][1]["type"], "BreakStatement");
+    assert_eq!(cases[1]["end"], code.find("break;").unwrap() + 6);
+    assert_eq!(cases[2]["test"], serde_json::Value::Null);
+    assert_eq!(
+        cases[2]["consequent"][0]["expression"]["type"],
+        "CallExpression"
+    );
+}
+
+#[test]
+fn do_while_statement() {
+    let code = "do { i++; } while (i < 10); do x(); while (y) z();";
+    let ast = estree(code);
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "DoWhileStatement");
+    assert_eq!(body[0]["body"]["type"], "BlockStatement");
+    assert_eq!(body[0]["test"]["operator"], "<");
+    assert_eq!(body[0]["end"], code.find(" do x").unwrap());
+
+    // A `;` is inserted after the `)`, even before a statement on the same line
+    assert_eq!(body[1]["body"]["type"], "ExpressionStatement");
+    assert_eq!(body[1]["end"], code.find(" z()").unwrap());
+    assert_eq!(body[2]["expression"]["callee"]["name"], "z");
+}
+
+#[test]
+fn labeled_statement() {
+    let ast = estree("outer: for (;;) { inner: while (a) { continue outer; } break outer; }");
+    let stmt = &ast["body"][0];
+
+    assert_eq!(stmt["type"], "LabeledStatement");
+    assert_eq!(stmt["label"]["name"], "outer");
+    assert_eq!(stmt["body"]["type"], "ForStatement");
+
+    let body = &stmt["body"]["body"]["body"];
+    assert_eq!(body[0]["label"]["name"], "inner");
+    assert_eq!(body[0]["body"]["body"]["body"][0]["label"]["name"], "outer");
+    assert_eq!(body[1]["label"]["name"], "outer");
+}
+
+#[test]
+fn empty_statement() {
+    let ast = estree(";; if (a);");
+    let body = &ast["body"];
+
+    assert_eq!(body[0]["type"], "EmptyStatement");
+    assert_eq!(body[0]["start"], 0);
+    assert_eq!(body[0]["end"], 1);
+    assert_eq!(body[1]["type"], "EmptyStatement");
+    assert_eq!(body[2]["consequent"]["type"], "EmptyStatement");
+}
+
+#[test]
+fn void_and_delete() {
+    let ast = estree("void 0; delete a.b;");
+
+    let expr = &ast["body"][0]["expression"];
+    assert_eq!(expr["type"], "UnaryExpression");
+    assert_eq!(expr["operator"], "void");
+    assert_eq!(expr["argument"]["value"], 0.0);
+
+    let expr = &ast["body"][1]["expression"];
+    assert_eq!(expr["operator"], "delete");
+    assert_eq!(expr["argument"]["type"], "MemberExpression");
+}
@@ -0,0 +1,116 @@
+use lexer::{SourceFile, TemplateValue};
+use parser::{
+    ast_types::{
+        expressions::{Expression, TemplateLiteral},
+        node_objects::Spanned,
+        programs::{Program, ProgramBody},
+        statements::Statement,
+    },
+    utils::parser_error::ErrorKind,
+    Parser,
+};
+use pretty_assertions::assert_eq;
+mod helpers;
+use helpers::NodeConstructor;
+
+fn parse(code: &str) -> Program {
+    Parser::new(code).parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    })
+}
+
+fn expression(program: &Program) -> &Expression {
+    let ProgramBody::Statement(Statement::ExpressionStatement(stmt)) = &program.body[0] else {
+        panic!("expected an expression statement");
+    };
+    &stmt.expression
+}
+
+fn cooked(template: &TemplateLiteral) -> Vec<Option<&str>> {
+    template
+        .quasis
+        .iter()
+        .map(|quasi| quasi.value.cooked.as_deref())
+        .collect()
+}
+
+#[test]
+fn no_substitution() {
+    let code = "`hello`;";
+    let program = parse(code);
+    let Expression::TemplateLiteral(template) = expression(&program) else {
+        panic!("expected a template literal");
+    };
+
+    assert_eq!(template.node, code.node("`hello`", 0));
+    assert_eq!(template.quasis.len(), 1);
+    assert!(template.quasis[0].tail);
+    assert_eq!(template.quasis[0].node, code.node("hello", 0));
+    assert_eq!(
+        template.quasis[0].value,
+        TemplateValue {
+            cooked: Some("hello".into()),
+            raw: "hello".into(),
+        }
+    );
+    assert!(template.expressions.is_empty());
+}
+
+#[test]
+fn substitutions() {
+    let code = "`a${b + 1}c${ `d${e}` }`;";
+    let program = parse(code);
+    let Expression::TemplateLiteral(template) = expression(&program) else {
+        panic!("expected a template literal");
+    };
+
+    assert_eq!(template.node.end, code.len() - 1);
+    assert_eq!(cooked(template), [Some("a"), Some("c"), Some("")]);
+    assert_eq!(
+        template.quasis.iter().map(|q| q.tail).collect::<Vec<_>>(),
+        [false, false, true]
+    );
+    assert_eq!(template.expressions.len(), 2);
+    assert_eq!(template.expressions[0].node(), &code.node("b + 1", 0));
+
+    let Expression::TemplateLiteral(nested) = &template.expressions[1] else {
+        panic!("expected a nested template literal");
+    };
+    assert_eq!(cooked(nested), [Some("d"), Some("")]);
+}
+
+#[test]
+fn tagged_template() {
+    let code = r"String.raw`\unicode${x}`;";
+    let program = parse(code);
+    let Expression::TaggedTemplateExpression(tagged) = expression(&program) else {
+        panic!("expected a tagged template");
+    };
+
+    assert!(matches!(tagged.tag, Expression::MemberExpression(_)));
+    assert_eq!(tagged.node.start, 0);
+    assert_eq!(tagged.node.end, code.len() - 1);
+    assert_eq!(cooked(&tagged.quasi), [None, Some("")]);
+    assert_eq!(tagged.quasi.quasis[0].value.raw, r"\unicode");
+}
+
+#[test]
+fn invalid_escape_in_untagged_template() {
+    let code = r"let a = `\unicode`;";
+    let (program, errors) = Parser::new(code).parse_with_errors();
+
+    assert_eq!(program.body.len(), 1);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, ErrorKind::InvalidTemplateEscape);
+    assert_eq!(errors[0].kind.code(), "P0012");
+}
+
+#[test]
+fn unclosed_substitution() {
+    let code = "`a${b c}`;";
+    let err = Parser::new(code).parse().unwrap_err();
+
+    assert_eq!(err.kind, ErrorKind::UnclosedTemplateSubstitution);
+    assert_eq!(err.labels[0].node, code.node("${", 0));
+}
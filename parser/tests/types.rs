@@ -1,42 +1,48 @@
-use lexer::{Operator, TypeKeyword};
+use lexer::{BinaryOperator, SourceFile, TypeKeyword};
 use parser::{
-    expressions::{
-        types::{
-            ArrayType, KeywordType, TypeAnnotation, TypeParameter, TypeParameterDeclaration,
-            TypeReference,
+    ast_types::{
+        declarations::{
+            function_declaration::{FunctionDeclaration, Parameter},
+            variable_declaration::{
+                VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+            },
         },
-        ArrayExpression, BinaryExpression, Identifier, VariableKind,
-    },
-    nodes::{program::Program, Node},
-    statements::{
-        BlockStatement, FunctionDeclaration, Parameter, ReturnStatement, VariableDeclaration,
-        VariableDeclarator,
+        expressions::{
+            types::{
+                ArrayType, KeywordType, TypeAnnotation, TypeParameter, TypeParameterDeclaration,
+                TypeReference,
+            },
+            ArrayExpression, BinaryExpression,
+        },
+        identifier::Identifier,
+        node_objects::Node,
+        statements::{FunctionBody, FunctionBodyBody, ReturnStatement},
     },
     Parser,
 };
 use pretty_assertions::assert_eq;
 mod helpers;
-use helpers::NodeConstructor;
+use helpers::{script, NodeConstructor};
 
 #[test]
 fn assignment_with_simple_type() {
     let code = "var el1: number, el2: Foo;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Var,
+            kind: VariableDeclarationKind::Var,
             declarations: vec![
                 VariableDeclarator {
                     node: code.node("el1: number", 0),
                     id: Identifier {
                         node: code.node("el1", 0),
                         name: "el1".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": number", 0),
                         type_value: KeywordType {
@@ -52,7 +58,8 @@ fn assignment_with_simple_type() {
                     id: Identifier {
                         node: code.node("el2", 0),
                         name: "el2".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": Foo", 0),
                         type_value: TypeReference {
@@ -70,35 +77,35 @@ fn assignment_with_simple_type() {
             ],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn array_type() {
     let code = "const num: number[], foo: Foo[];";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Const,
+            kind: VariableDeclarationKind::Const,
             declarations: vec![
                 VariableDeclarator {
                     node: code.node("num: number[]", 0),
                     id: Identifier {
                         node: code.node("num", 0),
                         name: "num".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": number[]", 0),
                         type_value: ArrayType {
@@ -118,7 +125,8 @@ fn array_type() {
                     id: Identifier {
                         node: code.node("foo", 0),
                         name: "foo".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": Foo[]", 0),
                         type_value: ArrayType {
@@ -140,34 +148,34 @@ fn array_type() {
             ],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
 fn type_params() {
     let code = "let grid: Array<Array<number>> = [];";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![VariableDeclaration {
+    let expected = script(
+        code,
+        vec![VariableDeclaration {
             node: Node::new(0, code.len()),
-            kind: VariableKind::Let,
+            kind: VariableDeclarationKind::Let,
             declarations: vec![VariableDeclarator {
                 node: code.between_incl(("grid", 0), ("[]", 0)),
                 id: Identifier {
                     node: code.node("grid", 0),
                     name: "grid".into(),
-                },
+                }
+                .into(),
                 type_annotation: Some(TypeAnnotation {
                     node: code.node(": Array<Array<number>>", 0),
                     type_value: TypeReference {
@@ -195,21 +203,21 @@ fn type_params() {
                 init: Some(
                     ArrayExpression {
                         node: code.node("[]", 0),
-                        items: vec![],
+                        elements: vec![],
                     }
                     .into(),
                 ),
             }],
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
 
 #[test]
@@ -217,16 +225,15 @@ fn generics_on_function_declaration() {
     let code = "function add<T>(el1: T, el2: T): T {
         return el1 + el2;
     }";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let result = parser.parse();
 
-    let expected = Program {
-        node: Node::new(0, code.len()),
-        shebang: None,
-        body: vec![FunctionDeclaration {
+    let expected = script(
+        code,
+        vec![FunctionDeclaration {
             node: Node::new(0, code.len()),
-            is_async: false,
-            is_generator: false,
+            _async: false,
+            generator: false,
             id: Identifier {
                 node: code.node("add", 0),
                 name: "add".into(),
@@ -244,10 +251,11 @@ fn generics_on_function_declaration() {
             params: vec![
                 Parameter {
                     node: code.node("el1: T", 0),
-                    identifier: Identifier {
+                    pattern: Identifier {
                         node: code.node("el1", 0),
                         name: "el1".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": T", 0),
                         type_value: TypeReference {
@@ -261,13 +269,17 @@ fn generics_on_function_declaration() {
                         .into(),
                     }),
                     optional: false,
+                    accessibility: None,
+                    readonly: false,
+                    _override: false,
                 },
                 Parameter {
                     node: code.node("el2: T", 0),
-                    identifier: Identifier {
+                    pattern: Identifier {
                         node: code.node("el2", 0),
                         name: "el2".into(),
-                    },
+                    }
+                    .into(),
                     type_annotation: Some(TypeAnnotation {
                         node: code.node(": T", 1),
                         type_value: TypeReference {
@@ -281,6 +293,9 @@ fn generics_on_function_declaration() {
                         .into(),
                     }),
                     optional: false,
+                    accessibility: None,
+                    readonly: false,
+                    _override: false,
                 },
             ],
             return_type: Some(TypeAnnotation {
@@ -295,36 +310,40 @@ fn generics_on_function_declaration() {
                 }
                 .into(),
             }),
-            body: BlockStatement {
+            body: FunctionBody {
                 node: code.between_incl(("{", 0), ("}", 0)),
-                statements: vec![ReturnStatement {
-                    node: code.node("return el1 + el2;", 0),
-                    value: BinaryExpression {
-                        node: code.node("el1 + el2", 0),
-                        left: Identifier {
-                            node: code.node("el1", 1),
-                            name: "el1".into(),
-                        }
-                        .into(),
-                        right: Identifier {
-                            node: code.node("el2", 1),
-                            name: "el2".into(),
-                        }
-                        .into(),
-                        operator: Operator::Plus,
+                body: vec![FunctionBodyBody::Statement(
+                    ReturnStatement {
+                        node: code.node("return el1 + el2;", 0),
+                        argument: Some(
+                            BinaryExpression {
+                                node: code.node("el1 + el2", 0),
+                                left: Identifier {
+                                    node: code.node("el1", 1),
+                                    name: "el1".into(),
+                                }
+                                .into(),
+                                right: Identifier {
+                                    node: code.node("el2", 1),
+                                    name: "el2".into(),
+                                }
+                                .into(),
+                                operator: BinaryOperator::Plus,
+                            }
+                            .into(),
+                        ),
                     }
                     .into(),
-                }
-                .into()],
+                )],
             },
         }
         .into()],
-    };
+    );
 
     if let Err(err) = result {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     }
 
-    assert_eq!(result, Ok(expected));
+    assert_eq!(result.unwrap(), expected);
 }
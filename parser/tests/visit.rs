@@ -0,0 +1,90 @@
+use lexer::SourceFile;
+use parser::{
+    ast_types::{
+        identifier::Identifier,
+        node_objects::Spanned,
+        programs::{Program, ProgramBody},
+        statements::Statement,
+    },
+    visit::{Visit, VisitMut},
+    Parser,
+};
+use pretty_assertions::assert_eq;
+
+fn parse(code: &str) -> Program {
+    Parser::new(code).parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    })
+}
+
+#[derive(Default)]
+struct IdentifierCollector {
+    names: Vec<String>,
+}
+
+impl Visit for IdentifierCollector {
+    fn visit_identifier(&mut self, node: &Identifier) {
+        self.names.push(node.name.to_string());
+    }
+}
+
+struct Renamer;
+
+impl VisitMut for Renamer {
+    fn visit_identifier(&mut self, node: &mut Identifier) {
+        node.name = format!("_{}", node.name).into();
+    }
+}
+
+#[test]
+fn walks_nested_nodes() {
+    let program = parse(
+        "function f(a, b) {
+            if (a) {
+                return g(a, b + c);
+            }
+            while (x) { y; }
+        }",
+    );
+
+    let mut collector = IdentifierCollector::default();
+    collector.visit_program(&program);
+
+    assert_eq!(
+        collector.names,
+        ["f", "a", "b", "a", "g", "a", "b", "c", "x", "y"]
+    );
+}
+
+#[test]
+fn walks_variable_declarations() {
+    let program = parse("let a: number = b * 2, c = [d, e];");
+
+    let mut collector = IdentifierCollector::default();
+    collector.visit_program(&program);
+
+    assert_eq!(collector.names, ["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn visit_mut_renames_identifiers() {
+    let mut program = parse("x = y + z;");
+
+    Renamer.visit_program(&mut program);
+
+    let mut collector = IdentifierCollector::default();
+    collector.visit_program(&program);
+    assert_eq!(collector.names, ["_x", "_y", "_z"]);
+}
+
+#[test]
+fn derived_spans() {
+    let program = parse("let a = 1;\nb + c;");
+
+    assert_eq!(program.body[0].span(), 0..10);
+    let ProgramBody::Statement(Statement::ExpressionStatement(stmt)) = &program.body[1] else {
+        panic!("expected an expression statement");
+    };
+    assert_eq!(stmt.expression.span(), 11..16);
+}
@@ -1,8 +1,8 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Variant};
 
 #[proc_macro_derive(Expr)]
 pub fn derive_expression(input: TokenStream) -> TokenStream {
@@ -16,6 +16,22 @@ pub fn derive_statement(input: TokenStream) -> TokenStream {
     impl_statement(&input)
 }
 
+/// Implements `Spanned`, reading the `node` field of a struct,
+/// or the node of whichever variant an enum holds
+#[proc_macro_derive(Spanned)]
+pub fn derive_spanned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    impl_spanned(&input)
+}
+
+/// Implements `Walk`, visiting every field of a struct in order,
+/// or the fields of whichever variant an enum holds
+#[proc_macro_derive(Walk)]
+pub fn derive_walk(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    impl_walk(&input)
+}
+
 fn impl_expression(input: &DeriveInput) -> TokenStream {
     let name = &input.ident; // The name of the struct
 
@@ -43,3 +59,95 @@ fn impl_statement(input: &DeriveInput) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+fn impl_spanned(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(_) => quote! { &self.node },
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                quote! {
+                    Self::#ident(inner) => crate::ast_types::node_objects::Spanned::node(inner),
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => panic!("Spanned can't be derived for unions"),
+    };
+
+    let expanded = quote! {
+        impl crate::ast_types::node_objects::Spanned for #name {
+            fn node(&self) -> &crate::ast_types::node_objects::Node {
+                #body
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn impl_walk(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+
+    let (walk, walk_mut) = match &input.data {
+        Data::Struct(data) => {
+            let members: Vec<_> = data.fields.members().collect();
+            (
+                quote! { #(crate::visit::Visitable::visit(&self.#members, v);)* },
+                quote! { #(crate::visit::Visitable::visit_mut(&mut self.#members, v);)* },
+            )
+        }
+        Data::Enum(data) => {
+            let (arms, arms_mut): (Vec<_>, Vec<_>) = data.variants.iter().map(walk_arms).unzip();
+            (
+                quote! { match self { #(#arms)* } },
+                quote! { match self { #(#arms_mut)* } },
+            )
+        }
+        Data::Union(_) => panic!("Walk can't be derived for unions"),
+    };
+
+    let expanded = quote! {
+        impl crate::visit::Walk for #name {
+            #[allow(unused_variables)]
+            fn walk<V: crate::visit::Visit + ?Sized>(&self, v: &mut V) {
+                #walk
+            }
+
+            #[allow(unused_variables)]
+            fn walk_mut<V: crate::visit::VisitMut + ?Sized>(&mut self, v: &mut V) {
+                #walk_mut
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// The match arms that visit the fields of an enum variant, borrowed immutably and mutably
+fn walk_arms(variant: &Variant) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let ident = &variant.ident;
+    let bindings: Vec<_> = (0..variant.fields.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect();
+
+    let pattern = match &variant.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| &f.ident);
+            quote! { Self::#ident { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings),*) },
+        Fields::Unit => quote! { Self::#ident },
+    };
+
+    (
+        quote! { #pattern => { #(crate::visit::Visitable::visit(#bindings, v);)* } },
+        quote! { #pattern => { #(crate::visit::Visitable::visit_mut(#bindings, v);)* } },
+    )
+}
@@ -1,5 +1,8 @@
-use lexer::Operator;
-use parser::nodes::Node;
+use lexer::{Operator, SourceFile};
+use parser::{
+    ast_types::node_objects::Node,
+    utils::diagnostic::{Diagnostic, DiagnosticSpan, Severity},
+};
 use string_cache::DefaultAtom as Atom;
 
 use crate::types::ResolvedType;
@@ -22,6 +25,8 @@ pub enum ErrorData {
     TypeMismatch {
         expected_type: ResolvedType,
         received_type: ResolvedType,
+        /// The type annotation that the expected type comes from, if any
+        annotation: Option<Node>,
     },
     InvalidNumberOfArguments {
         received: u8,
@@ -40,18 +45,112 @@ pub enum ErrorSeverity {
     Warning,
 }
 
+impl ErrorData {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownVariable { .. } => "S0001",
+            Self::UseBeforeInit { .. } => "S0002",
+            Self::TypeMismatch { .. } => "S0003",
+            Self::InvalidNumberOfArguments { .. } => "S0004",
+            Self::UnallowedBinaryOperationTypes { .. } => "S0005",
+        }
+    }
+
+    /// A short, single-line description of the error
+    pub fn message(&self) -> String {
+        match self {
+            Self::UnknownVariable { id } => format!("cannot find variable `{}`", id),
+            Self::UseBeforeInit { id } => {
+                format!("variable `{}` is used before being assigned", id)
+            }
+            Self::TypeMismatch { .. } => "mismatched types".to_owned(),
+            Self::InvalidNumberOfArguments { received, expected } => {
+                format!("expected {} type arguments, but got {}", expected, received)
+            }
+            Self::UnallowedBinaryOperationTypes { operator, .. } => {
+                format!("operator `{}` cannot be applied to these types", operator)
+            }
+        }
+    }
+
+    /// The message shown next to the offending node
+    fn label(&self) -> String {
+        match self {
+            Self::UnknownVariable { .. } => "not found in this scope".to_owned(),
+            Self::UseBeforeInit { .. } => "used here before being assigned".to_owned(),
+            Self::TypeMismatch {
+                expected_type,
+                received_type,
+                ..
+            } => format!("expected `{}`, found `{}`", expected_type, received_type),
+            Self::InvalidNumberOfArguments { expected, .. } => {
+                format!("expected {} type arguments", expected)
+            }
+            Self::UnallowedBinaryOperationTypes {
+                left_type,
+                right_type,
+                ..
+            } => format!("`{}` and `{}`", left_type, right_type),
+        }
+    }
+}
+
+impl SemanticError {
+    pub fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        let index = file.line_index();
+        let secondary = match &self.data {
+            ErrorData::TypeMismatch {
+                expected_type,
+                annotation: Some(annotation),
+                ..
+            } => vec![DiagnosticSpan::new(
+                index,
+                annotation.start,
+                annotation.end,
+                Some(format!(
+                    "expected `{}` because of this annotation",
+                    expected_type
+                )),
+            )],
+            _ => Vec::new(),
+        };
+
+        Diagnostic {
+            code: self.data.code().to_owned(),
+            severity: match self.severity {
+                ErrorSeverity::Critical => Severity::Error,
+                ErrorSeverity::Warning => Severity::Warning,
+            },
+            message: self.data.message(),
+            primary: DiagnosticSpan::new(
+                index,
+                self.node.start,
+                self.node.end,
+                Some(self.data.label()),
+            ),
+            secondary,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn print(&self, file: &SourceFile) {
+        self.to_diagnostic(file).print(file)
+    }
+}
+
 impl std::fmt::Display for ErrorData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::UnknownVariable { id } => {
-                write!(f, "Unknown variable: {}", id.to_string())
+                write!(f, "Unknown variable: {}", id)
             }
             Self::UseBeforeInit { id } => {
-                write!(f, "Variable used before init: {}", id.to_string())
+                write!(f, "Variable used before init: {}", id)
             }
             Self::TypeMismatch {
                 expected_type,
                 received_type,
+                ..
             } => write!(
                 f,
                 "Type mismatch\nExpected: {}\nGot: {}",
@@ -81,15 +180,17 @@ impl std::fmt::Display for ErrorData {
 
 impl std::fmt::Display for SemanticError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let severity_msg: &'static str = match self.severity {
-            ErrorSeverity::Critical => "Critical error",
-            ErrorSeverity::Warning => "Warning",
+        let severity: &'static str = match self.severity {
+            ErrorSeverity::Critical => "error",
+            ErrorSeverity::Warning => "warning",
         };
 
         write!(
             f,
-            "{} on location {}-{}:\n{}",
-            severity_msg, self.node.start, self.node.end, self.data
+            "{}[{}]: {}",
+            severity,
+            self.data.code(),
+            self.data.message()
         )
     }
 }
@@ -4,7 +4,7 @@ pub mod types;
 pub mod visitors;
 
 use errors::{ErrorData, ErrorSeverity, SemanticError};
-use parser::nodes::{program::Program, Node};
+use parser::ast_types::{node_objects::Node, programs::Program};
 use string_cache::DefaultAtom as Atom;
 use symbol::{Symbol, SymbolTable};
 use types::ResolvedType;
@@ -15,6 +15,12 @@ pub struct CheckerContext {
     symbols: SymbolTable,
 }
 
+impl Default for CheckerContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CheckerContext {
     pub fn new() -> Self {
         Self {
@@ -49,6 +55,14 @@ impl CheckerContext {
         None
     }
 
+    pub fn enter_scope(&mut self) {
+        self.symbols.enter_scope();
+    }
+
+    pub fn exit_scope(&mut self) {
+        self.symbols.exit_scope();
+    }
+
     pub fn add_symbol(&mut self, id: Atom, resolved_type: Option<ResolvedType>, declared_at: Node) {
         self.symbols.add(id, resolved_type, declared_at);
     }
@@ -57,8 +71,8 @@ impl CheckerContext {
 pub fn analyze(ast: &Program) -> Vec<SemanticError> {
     let mut ctx = CheckerContext::new();
 
-    DeclVisitor::visit_program(ast, &mut ctx);
-    BodyVisitor::visit_program(ast, &mut ctx);
+    DeclVisitor::analyze(ast, &mut ctx);
+    BodyVisitor::analyze(ast, &mut ctx);
 
     ctx.errors
 }
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use parser::nodes::Node;
+use parser::ast_types::{
+    declarations::function_declaration::Parameter, node_objects::Node, patterns::pattern::Pattern,
+};
 use string_cache::DefaultAtom as Atom;
 
-use crate::types::ResolvedType;
+use crate::{types::ResolvedType, CheckerContext};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Symbol {
@@ -12,10 +14,52 @@ pub struct Symbol {
     pub declared_at: Node,
 }
 
+impl Symbol {
+    /// The symbol that a function parameter declares.
+    /// A destructured parameter has no name of its own, so its `id` is empty.
+    pub fn from_parameter(param: &Parameter, ctx: &mut CheckerContext) -> Self {
+        let annotated_type = param
+            .type_annotation
+            .as_ref()
+            .map(|ann| ResolvedType::from_ast_type(&ann.type_value, ctx));
+
+        // A rest parameter collects the remaining arguments into an array
+        let resolved_type = match &param.pattern {
+            Pattern::RestElement(_) => Some(
+                annotated_type
+                    .unwrap_or_else(|| ResolvedType::Array(Box::new(ResolvedType::Unknown))),
+            ),
+            _ => annotated_type,
+        };
+
+        Self {
+            id: binding_name(&param.pattern),
+            resolved_type,
+            declared_at: param.node,
+        }
+    }
+}
+
+/// The name that a parameter binds, looking through `...rest` and `= default`
+fn binding_name(pattern: &Pattern) -> Atom {
+    match pattern {
+        Pattern::Identifier(id) => id.name.clone(),
+        Pattern::RestElement(rest) => binding_name(&rest.argument),
+        Pattern::AssignmentPattern(assign) => binding_name(&assign.left),
+        _ => Atom::default(),
+    }
+}
+
 pub struct SymbolTable {
     pub scopes: Vec<HashMap<Atom, Symbol>>,
 }
 
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SymbolTable {
     pub fn new() -> Self {
         Self {
@@ -23,8 +67,19 @@ impl SymbolTable {
         }
     }
 
+    /// Starts a new innermost scope, like the one of a function
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Leaves the innermost scope, forgetting the symbols declared in it
+    pub fn exit_scope(&mut self) {
+        debug_assert!(self.scopes.len() > 1, "the global scope can't be left");
+        self.scopes.pop();
+    }
+
     pub fn add(&mut self, id: Atom, resolved_type: Option<ResolvedType>, declared_at: Node) {
-        debug_assert!(self.scopes.len() > 0);
+        debug_assert!(!self.scopes.is_empty());
         let a = self.scopes.last_mut().unwrap();
         a.insert(
             id.clone(),
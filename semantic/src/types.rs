@@ -1,7 +1,10 @@
 #![allow(unreachable_code)]
 
 use lexer::TypeKeyword;
-use parser::expressions::{types::AstType, Literal};
+use parser::ast_types::{
+    expressions::{types::AstType, Literal},
+    node_objects::Spanned,
+};
 
 use crate::{errors::ErrorData, symbol::Symbol, CheckerContext, ErrorSeverity};
 
@@ -9,6 +12,7 @@ use crate::{errors::ErrorData, symbol::Symbol, CheckerContext, ErrorSeverity};
 pub enum ResolvedType {
     Unknown,
     Number,
+    BigInt,
     String,
     Boolean,
     Null,
@@ -36,10 +40,8 @@ impl ResolvedType {
     pub fn extend(&mut self, other: &Self) {
         match self {
             Self::Unknown => *self = other.clone(),
-            Self::Union(types) => {
-                if !types.contains(other) {
-                    types.push(other.clone());
-                }
+            Self::Union(types) if !types.contains(other) => {
+                types.push(other.clone());
             }
             _ if self != other => *self = Self::Union([self.clone(), other.clone()].to_vec()),
             _ => {}
@@ -59,6 +61,7 @@ impl std::fmt::Display for ResolvedType {
         match self {
             Self::Unknown => write!(f, "{{unknown}}"),
             Self::Number => write!(f, "number"),
+            Self::BigInt => write!(f, "bigint"),
             Self::String => write!(f, "string"),
             Self::Boolean => write!(f, "boolean"),
             Self::Null => write!(f, "null"),
@@ -91,6 +94,7 @@ impl ResolvedType {
             AstType::TypeLiteral(type_literal) => match type_literal.literal {
                 Literal::BooleanLiteral(_) => Self::Boolean,
                 Literal::NumberLiteral(_) => Self::Number,
+                Literal::BigIntLiteral(_) => Self::BigInt,
                 Literal::NullLiteral(_) => Self::Null,
                 Literal::StringLiteral(_) => Self::String,
                 Literal::RegexLiteral(_) => Self::Regex,
@@ -105,13 +109,13 @@ impl ResolvedType {
                     b"Array" => todo!(),
                     b"Record" => {
                         let p = type_reference.type_params.as_ref();
-                        if !p.is_some_and(|p| p.len() == 2) {
+                        if p.is_none_or(|p| p.len() != 2) {
                             ctx.report_error(
                                 ErrorData::InvalidNumberOfArguments {
                                     received: p.unwrap().len() as u8,
                                     expected: 2,
                                 },
-                                type_value.node().clone(),
+                                *type_value.node(),
                                 ErrorSeverity::Critical,
                             );
                         }
@@ -135,14 +139,7 @@ impl ResolvedType {
                 let args: Vec<Symbol> = fn_type
                     .params
                     .iter()
-                    .map(|arg| Symbol {
-                        id: arg.identifier.name.clone(),
-                        resolved_type: arg
-                            .type_annotation
-                            .as_ref()
-                            .map(|ann| Self::from_ast_type(&ann.type_value, ctx)),
-                        declared_at: arg.node.clone(),
-                    })
+                    .map(|arg| Symbol::from_parameter(arg, ctx))
                     .collect();
 
                 Self::Function(Box::new(FunctionType {
@@ -1,91 +1,97 @@
 #![allow(unreachable_code)]
 
-use lexer::Operator;
+use lexer::BinaryOperator;
 use parser::{
-    expressions::{
-        ArrayExpression, BinaryExpression, Expression, Identifier, Key, Literal, ObjectExpression,
-        ObjectItem,
-    },
-    nodes::program::Program,
-    statements::{
-        BlockStatement, FunctionDeclaration, ReturnStatement, Statement, VariableDeclaration,
+    ast_types::{
+        classes::class::{MethodDefinition, PropertyDefinition},
+        declarations::{
+            function_declaration::{FunctionDeclaration, Parameter},
+            variable_declaration::VariableDeclaration,
+        },
+        expressions::{
+            types::TypeAnnotation, ArrayElement, ArrayExpression, ArrowFunctionExpression,
+            ArrowFunctionExpressionBody, BinaryExpression, Expression, FunctionExpression, Literal,
+            MemberExpression, ObjectExpression, ObjectExpressionProperty,
+        },
+        identifier::Identifier,
+        node_objects::{Node, Spanned},
+        patterns::{object_pattern::AssignmentProperty, pattern::Pattern},
+        programs::Program,
+        statements::{FunctionBody, ReturnStatement},
     },
+    visit::{walk, Visit},
 };
 
 use crate::{
     errors::{ErrorData, ErrorSeverity},
     types::{ObjectType, ResolvedType},
+    visitors::decl_visitor::DeclVisitor,
     CheckerContext,
 };
 
 pub struct BodyVisitor<'a> {
     ctx: &'a mut CheckerContext,
+    /// The type annotation that the currently expected type comes from
+    annotation: Option<Node>,
+    /// The return type of the function that is currently being checked
+    expected_ret_type: Option<ResolvedType>,
 }
 
 impl<'a> BodyVisitor<'a> {
-    pub fn visit_program(ast: &Program, ctx: &'a mut CheckerContext) {
-        let mut visitor = Self { ctx };
-        ast.body
-            .iter()
-            .for_each(|stmt| visitor.visit_statement(stmt, None));
-    }
-
-    fn visit_statement(&mut self, stmt: &Statement, expected_ret_type: Option<&ResolvedType>) {
-        use Statement as S;
-        match stmt {
-            S::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
-            S::FunctionDeclaration(decl) => self.visit_function_declaration(decl),
-            S::ReturnStatement(stmt) => self.visit_return_statement(stmt, expected_ret_type),
-            _ => todo!("{:?}", &stmt),
-        }
+    pub fn analyze(ast: &Program, ctx: &'a mut CheckerContext) {
+        let mut visitor = Self {
+            ctx,
+            annotation: None,
+            expected_ret_type: None,
+        };
+        visitor.visit_program(ast);
     }
 
-    fn visit_block_statement(
+    /// Checks a function with `check_body`, in a scope of its own where its parameters
+    /// and the declarations in its body are declared. Its `return`s are checked against `return_type`.
+    fn check_function(
         &mut self,
-        stmt: &BlockStatement,
-        expected_ret_type: Option<&ResolvedType>,
+        params: &[Parameter],
+        return_type: Option<&TypeAnnotation>,
+        body: Option<&FunctionBody>,
+        check_body: impl FnOnce(&mut Self),
     ) {
-        for s in stmt.statements.iter() {
-            self.visit_statement(s, expected_ret_type);
-        }
-    }
+        let ret_t = return_type.map(|t| ResolvedType::from_ast_type(&t.type_value, self.ctx));
 
-    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration) {
-        for d in decl.declarations.iter() {
-            let symbol_name = d.id.name.to_owned();
-            let expected_type = {
-                let symbol = self.ctx.get_symbol(symbol_name.to_owned());
-                symbol.unwrap().resolved_type.clone()
-            };
+        self.ctx.enter_scope();
+        DeclVisitor::analyze_function(params, body, self.ctx);
 
-            if let Some(init) = &d.init {
-                let init_t = self.visit_expression(init, expected_type.as_ref());
+        let outer_annotation = std::mem::replace(&mut self.annotation, return_type.map(|t| t.node));
+        let outer_ret_type = std::mem::replace(&mut self.expected_ret_type, ret_t);
+        check_body(self);
+        self.annotation = outer_annotation;
+        self.expected_ret_type = outer_ret_type;
 
-                let symbol = self.ctx.get_symbol_mut(symbol_name).unwrap();
-                if symbol.resolved_type.is_none() {
-                    symbol.resolved_type = Some(init_t);
-                }
-            }
-        }
+        self.ctx.exit_scope();
     }
 
-    fn visit_expression(
+    /// Checks the expression against the expected type and returns its type.
+    /// Expressions that can't be typed yet are walked, and resolve to `Unknown`.
+    fn check_expression(
         &mut self,
         expr: &Expression,
         expected_type: Option<&ResolvedType>,
     ) -> ResolvedType {
         use Expression as E;
         match expr {
-            E::Literal(lit) => self.visit_literal(lit, expected_type),
-            E::ObjectExpression(obj) => self.visit_object_expression(obj, expected_type),
-            E::ArrayExpression(arr) => self.visit_array_expression(arr, expected_type),
-            E::Identifier(id) => self.visit_identifier(id, expected_type),
-            E::BinaryExpression(expr) => self.visit_binary_expression(expr, expected_type),
-            _ => todo!("{:?}", &expr),
+            E::Literal(lit) => self.check_literal(lit, expected_type),
+            E::ObjectExpression(obj) => self.check_object_expression(obj, expected_type),
+            E::ArrayExpression(arr) => self.check_array_expression(arr, expected_type),
+            E::Identifier(id) => self.check_identifier(id, expected_type),
+            E::BinaryExpression(expr) => self.check_binary_expression(expr, expected_type),
+            _ => {
+                walk::walk_expression(self, expr);
+                ResolvedType::Unknown
+            }
         }
     }
 
-    fn visit_literal(
+    fn check_literal(
         &mut self,
         lit: &Literal,
         expected_type: Option<&ResolvedType>,
@@ -95,6 +101,7 @@ impl<'a> BodyVisitor<'a> {
             Literal::StringLiteral(_) => ResolvedType::String,
             Literal::NullLiteral(_) => ResolvedType::Null,
             Literal::NumberLiteral(_) => ResolvedType::Number,
+            Literal::BigIntLiteral(_) => ResolvedType::BigInt,
             Literal::RegexLiteral(_) => ResolvedType::Regex,
         };
 
@@ -103,8 +110,9 @@ impl<'a> BodyVisitor<'a> {
                 ErrorData::TypeMismatch {
                     expected_type: expected_type.unwrap().to_owned(),
                     received_type: expr_type.to_owned(),
+                    annotation: self.annotation,
                 },
-                lit.node().clone(),
+                *lit.node(),
                 ErrorSeverity::Critical,
             );
         }
@@ -112,7 +120,7 @@ impl<'a> BodyVisitor<'a> {
         expr_type
     }
 
-    fn visit_identifier(
+    fn check_identifier(
         &mut self,
         id: &Identifier,
         expected_type: Option<&ResolvedType>,
@@ -122,7 +130,7 @@ impl<'a> BodyVisitor<'a> {
                 ErrorData::UnknownVariable {
                     id: id.name.to_owned(),
                 },
-                id.node.clone(),
+                id.node,
                 ErrorSeverity::Critical,
             );
             return ResolvedType::Unknown;
@@ -144,6 +152,7 @@ impl<'a> BodyVisitor<'a> {
                 ErrorData::TypeMismatch {
                     expected_type: expected_type.unwrap().to_owned(),
                     received_type: t.to_owned(),
+                    annotation: self.annotation,
                 },
                 id.node.to_owned(),
                 ErrorSeverity::Critical,
@@ -153,7 +162,7 @@ impl<'a> BodyVisitor<'a> {
         t.to_owned()
     }
 
-    fn visit_object_expression(
+    fn check_object_expression(
         &mut self,
         obj: &ObjectExpression,
         expected_type: Option<&ResolvedType>,
@@ -166,8 +175,9 @@ impl<'a> BodyVisitor<'a> {
                         ErrorData::TypeMismatch {
                             expected_type: expected_type.unwrap().to_owned(),
                             received_type: ResolvedType::Object(todo!()),
+                            annotation: self.annotation,
                         },
-                        obj.node.clone(),
+                        obj.node,
                         ErrorSeverity::Critical,
                     );
                     (None, None)
@@ -176,35 +186,44 @@ impl<'a> BodyVisitor<'a> {
         let mut key_type = ResolvedType::Unknown;
         let mut value_type = ResolvedType::Unknown;
 
-        obj.items.iter().for_each(|item| match item {
-            ObjectItem::KV(kv) => {
-                match &kv.key {
-                    Key::Identifier(_) | Key::StringLiteral(_) => {
-                        if expected_key_type.is_some_and(|t| !t.includes(&ResolvedType::String)) {
-                            self.ctx.report_error(
-                                ErrorData::TypeMismatch {
-                                    expected_type: expected_key_type.unwrap().to_owned(),
-                                    received_type: ResolvedType::String,
-                                },
-                                kv.key.node().clone(),
-                                ErrorSeverity::Critical,
-                            );
-                        }
-                        key_type.extend(&ResolvedType::String);
-                    }
-                    Key::ComputedProperty(key) => {
-                        let expr_t = self.visit_expression(&key.expression, expected_key_type);
-                        key_type.extend(&expr_t);
-                    }
+        obj.properties.iter().for_each(|property| match property {
+            ObjectExpressionProperty::Property(property) if property.shorthand => {
+                let Expression::Identifier(id) = &property.value else {
+                    unreachable!("the value of a shorthand property is its key");
                 };
-                self.visit_expression(&kv.value, expected_value_type);
-            }
-            ObjectItem::Identifier(id) => {
-                let id_t = self.visit_identifier(id, expected_type);
+                let id_t = self.check_identifier(id, expected_type);
                 key_type.extend(&ResolvedType::String);
                 value_type.extend(&id_t);
             }
-            ObjectItem::Method(_method) => todo!(),
+            ObjectExpressionProperty::Property(property) => {
+                if property.computed {
+                    let expr_t = self.check_expression(&property.key, expected_key_type);
+                    key_type.extend(&expr_t);
+                } else {
+                    if expected_key_type.is_some_and(|t| !t.includes(&ResolvedType::String)) {
+                        self.ctx.report_error(
+                            ErrorData::TypeMismatch {
+                                expected_type: expected_key_type.unwrap().to_owned(),
+                                received_type: ResolvedType::String,
+                                annotation: self.annotation,
+                            },
+                            *property.key.node(),
+                            ErrorSeverity::Critical,
+                        );
+                    }
+                    key_type.extend(&ResolvedType::String);
+                }
+                self.check_expression(&property.value, expected_value_type);
+            }
+            // The properties of the spread object become properties of this one
+            ObjectExpressionProperty::SpreadElement(spread) => {
+                if let ResolvedType::Object(spread_t) =
+                    self.check_expression(&spread.argument, expected_type)
+                {
+                    key_type.extend(&spread_t.key_type);
+                    value_type.extend(&spread_t.value_type);
+                }
+            }
         });
 
         ResolvedType::Object(Box::new(ObjectType {
@@ -213,7 +232,7 @@ impl<'a> BodyVisitor<'a> {
         }))
     }
 
-    fn visit_array_expression(
+    fn check_array_expression(
         &mut self,
         arr: &ArrayExpression,
         expected_type: Option<&ResolvedType>,
@@ -226,8 +245,9 @@ impl<'a> BodyVisitor<'a> {
                         ErrorData::TypeMismatch {
                             expected_type: expected_type.unwrap().to_owned(),
                             received_type: ResolvedType::Array(Box::new(todo!())),
+                            annotation: self.annotation,
                         },
-                        arr.node.clone(),
+                        arr.node,
                         ErrorSeverity::Critical,
                     );
                     None
@@ -237,30 +257,39 @@ impl<'a> BodyVisitor<'a> {
         };
         let mut item_type = ResolvedType::Unknown;
 
-        arr.items.iter().for_each(|it| {
-            let expr_t = self.visit_expression(it, expected_item_type);
+        // Holes, like in `[a, , b]`, don't add to the type of the items
+        arr.elements.iter().flatten().for_each(|it| {
+            let expr_t = match it {
+                ArrayElement::Expression(expr) => self.check_expression(expr, expected_item_type),
+                // The items of the spread array become items of this one
+                ArrayElement::SpreadElement(spread) => {
+                    match self.check_expression(&spread.argument, expected_type) {
+                        ResolvedType::Array(spread_item_t) => *spread_item_t,
+                        _ => ResolvedType::Unknown,
+                    }
+                }
+            };
             item_type.extend(&expr_t);
         });
 
         ResolvedType::Array(Box::new(item_type))
     }
 
-    fn visit_binary_expression(
+    fn check_binary_expression(
         &mut self,
         expr: &BinaryExpression,
         expected_type: Option<&ResolvedType>,
     ) -> ResolvedType {
-        let left_t = self.visit_expression(&expr.left, expected_type);
-        let right_t = self.visit_expression(&expr.right, expected_type);
+        let left_t = self.check_expression(&expr.left, expected_type);
+        let right_t = self.check_expression(&expr.right, expected_type);
 
-        use Operator as OP;
+        use BinaryOperator as OP;
         use ResolvedType as RT;
         let is_allowed_operation: bool = match expr.operator {
-            OP::Plus => match (&left_t, &right_t) {
-                (RT::String, RT::String) => true,
-                (RT::Number, RT::Number) => true,
-                _ => false,
-            },
+            OP::Plus => matches!(
+                (&left_t, &right_t),
+                (RT::String, RT::String) | (RT::Number, RT::Number)
+            ),
             OP::Minus
             | OP::Mult
             | OP::Div
@@ -269,9 +298,8 @@ impl<'a> BodyVisitor<'a> {
             | OP::BitwiseAnd
             | OP::BitwiseOr
             | OP::BitwiseXor
-            | OP::BitwiseNot
-            | OP::BitwiseLeftShift
-            | OP::BitwiseRightShift
+            | OP::LeftShift
+            | OP::RightShift
             | OP::ZeroFillRightShift => {
                 left_t == ResolvedType::Number && right_t == ResolvedType::Number
             }
@@ -285,6 +313,7 @@ impl<'a> BodyVisitor<'a> {
                 ErrorData::TypeMismatch {
                     expected_type: left_t,
                     received_type: right_t,
+                    annotation: None,
                 },
                 expr.left.node().to_owned(),
                 ErrorSeverity::Critical,
@@ -294,21 +323,113 @@ impl<'a> BodyVisitor<'a> {
 
         left_t
     }
+}
+
+impl Visit for BodyVisitor<'_> {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration) {
+        for d in decl.declarations.iter() {
+            let Pattern::Identifier(id) = &d.id else {
+                walk::walk_variable_declarator(self, d);
+                continue;
+            };
+            let symbol_name = id.name.to_owned();
+            let expected_type = {
+                let symbol = self.ctx.get_symbol(symbol_name.to_owned());
+                symbol.unwrap().resolved_type.clone()
+            };
+
+            if let Some(init) = &d.init {
+                self.annotation = d.type_annotation.as_ref().map(|ann| ann.node);
+                let init_t = self.check_expression(init, expected_type.as_ref());
+
+                let symbol = self.ctx.get_symbol_mut(symbol_name).unwrap();
+                if symbol.resolved_type.is_none() {
+                    symbol.resolved_type = Some(init_t);
+                }
+            }
+        }
+    }
 
     fn visit_function_declaration(&mut self, decl: &FunctionDeclaration) {
-        let ret_t = decl
-            .return_type
-            .as_ref()
-            .map(|t| ResolvedType::from_ast_type(&t.type_value, &mut self.ctx));
+        self.check_function(
+            &decl.params,
+            decl.return_type.as_ref(),
+            Some(&decl.body),
+            |v| v.visit_function_body(&decl.body),
+        );
+    }
 
-        self.visit_block_statement(&decl.body, ret_t.as_ref());
+    fn visit_function_expression(&mut self, expr: &FunctionExpression) {
+        self.check_function(
+            &expr.params,
+            expr.return_type.as_ref(),
+            expr.body.as_ref(),
+            |v| {
+                if let Some(body) = &expr.body {
+                    v.visit_function_body(body);
+                }
+            },
+        );
     }
 
-    fn visit_return_statement(
-        &mut self,
-        stmt: &ReturnStatement,
-        expected_ret_type: Option<&ResolvedType>,
-    ) {
-        self.visit_expression(&stmt.value, expected_ret_type);
+    fn visit_arrow_function_expression(&mut self, expr: &ArrowFunctionExpression) {
+        let body = match &expr.body {
+            ArrowFunctionExpressionBody::FunctionBody(body) => Some(body),
+            ArrowFunctionExpressionBody::Expression(_) => None,
+        };
+
+        self.check_function(&expr.params, expr.return_type.as_ref(), body, |v| {
+            match &expr.body {
+                ArrowFunctionExpressionBody::FunctionBody(body) => v.visit_function_body(body),
+                // The expression is what the arrow function returns
+                ArrowFunctionExpressionBody::Expression(returned) => {
+                    let expected_ret_type = v.expected_ret_type.clone();
+                    v.check_expression(returned, expected_ret_type.as_ref());
+                }
+            }
+        });
+    }
+
+    // Only a computed property name, like `[key]`, refers to variables.
+    // Otherwise it's the name of the property, like `b` in `a.b`.
+    fn visit_member_expression(&mut self, expr: &MemberExpression) {
+        self.visit_expression(&expr.object);
+        if expr.computed {
+            self.visit_expression(&expr.property);
+        }
+    }
+
+    fn visit_method_definition(&mut self, def: &MethodDefinition) {
+        if def.computed {
+            self.visit_class_element_key(&def.key);
+        }
+        self.visit_function_expression(&def.value);
+    }
+
+    fn visit_property_definition(&mut self, def: &PropertyDefinition) {
+        if def.computed {
+            self.visit_class_element_key(&def.key);
+        }
+        if let Some(value) = &def.value {
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_assignment_property(&mut self, prop: &AssignmentProperty) {
+        if prop.computed {
+            self.visit_expression(&prop.key);
+        }
+        self.visit_pattern(&prop.value);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement) {
+        if let Some(argument) = &stmt.argument {
+            let expected_ret_type = self.expected_ret_type.clone();
+            self.check_expression(argument, expected_ret_type.as_ref());
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression) {
+        self.check_expression(expr, None);
     }
 }
@@ -4,11 +4,17 @@ use crate::{
     CheckerContext,
 };
 use parser::{
-    expressions::{
-        ArrayExpression, BinaryExpression, Expression, Key, ObjectExpression, ObjectItem,
+    ast_types::{
+        declarations::{
+            function_declaration::{FunctionDeclaration, Parameter},
+            variable_declaration::VariableDeclaration,
+        },
+        expressions::{ArrowFunctionExpression, FunctionExpression},
+        patterns::pattern::Pattern,
+        programs::Program,
+        statements::FunctionBody,
     },
-    nodes::program::Program,
-    statements::{FunctionDeclaration, ReturnStatement, Statement, VariableDeclaration},
+    visit::Visit,
 };
 
 pub struct DeclVisitor<'a> {
@@ -16,35 +22,49 @@ pub struct DeclVisitor<'a> {
 }
 
 impl<'a> DeclVisitor<'a> {
-    pub fn visit_program(ast: &Program, ctx: &'a mut CheckerContext) {
-        let mut visitor = Self { ctx };
-        ast.body
-            .iter()
-            .for_each(|stmt| visitor.visit_statement(stmt));
+    pub fn analyze(ast: &Program, ctx: &'a mut CheckerContext) {
+        Self { ctx }.visit_program(ast);
     }
 
-    fn visit_statement(&mut self, stmt: &Statement) {
-        use Statement as S;
+    /// Declares the parameters of a function and the declarations in its body,
+    /// in the scope of the function, which must already be entered
+    pub fn analyze_function(
+        params: &[Parameter],
+        body: Option<&FunctionBody>,
+        ctx: &'a mut CheckerContext,
+    ) {
+        let mut visitor = Self { ctx };
+
+        for param in params {
+            let symbol = Symbol::from_parameter(param, visitor.ctx);
+            // Destructuring patterns don't declare anything yet
+            if !symbol.id.is_empty() {
+                visitor
+                    .ctx
+                    .add_symbol(symbol.id, symbol.resolved_type, symbol.declared_at);
+            }
+        }
 
-        match stmt {
-            S::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
-            S::FunctionDeclaration(decl) => self.visit_function_declaration(decl),
-            S::ReturnStatement(stmt) => self.visit_return_statement(stmt),
-            _ => todo!("{:?}", &stmt),
+        if let Some(body) = body {
+            visitor.visit_function_body(body);
         }
     }
+}
 
+impl Visit for DeclVisitor<'_> {
     fn visit_variable_declaration(&mut self, decl: &VariableDeclaration) {
         for d in decl.declarations.iter() {
             let ast_type = d.type_annotation.as_ref().map(|ann| &ann.type_value);
-            let resolved_type = ast_type.map(|t| ResolvedType::from_ast_type(t, &mut self.ctx));
+            let resolved_type = ast_type.map(|t| ResolvedType::from_ast_type(t, self.ctx));
 
             d.init.as_ref().inspect(|init| {
                 self.visit_expression(init);
             });
 
-            self.ctx
-                .add_symbol(d.id.name.clone(), resolved_type, d.node.clone());
+            // Destructuring patterns don't declare anything yet
+            if let Pattern::Identifier(id) = &d.id {
+                self.ctx.add_symbol(id.name.clone(), resolved_type, d.node);
+            }
         }
     }
 
@@ -52,20 +72,13 @@ impl<'a> DeclVisitor<'a> {
         let args: Vec<Symbol> = decl
             .params
             .iter()
-            .map(|param| Symbol {
-                id: param.identifier.name.clone(),
-                resolved_type: param
-                    .type_annotation
-                    .as_ref()
-                    .map(|ann| ResolvedType::from_ast_type(&ann.type_value, &mut self.ctx)),
-                declared_at: param.node.clone(),
-            })
+            .map(|param| Symbol::from_parameter(param, self.ctx))
             .collect();
 
         let display_ret_type = decl.return_type.as_ref().map(|t| t.type_value.to_owned());
         let unfolded_ret_type = display_ret_type
             .as_ref()
-            .map(|t| ResolvedType::from_ast_type(&t, &mut self.ctx));
+            .map(|t| ResolvedType::from_ast_type(t, self.ctx));
 
         let resolved_type = ResolvedType::Function(Box::new(FunctionType {
             args: args.to_owned(),
@@ -73,62 +86,13 @@ impl<'a> DeclVisitor<'a> {
             unfolded_ret_type,
         }));
 
-        self.ctx.add_symbol(
-            decl.id.name.to_owned(),
-            Some(resolved_type),
-            decl.node.clone(),
-        );
-
-        for arg in args.iter() {
-            self.ctx.add_symbol(
-                arg.id.to_owned(),
-                arg.resolved_type.to_owned(),
-                arg.declared_at,
-            );
-        }
-
-        for stmt in decl.body.statements.iter() {
-            self.visit_statement(stmt);
-        }
+        self.ctx
+            .add_symbol(decl.id.name.to_owned(), Some(resolved_type), decl.node);
     }
 
-    fn visit_expression(&self, expr: &Expression) {
-        use Expression as E;
-        match expr {
-            E::Literal(_) => {}
-            E::Identifier(_) => {}
-            E::ObjectExpression(obj) => self.visit_object_expression(obj),
-            E::ArrayExpression(arr) => self.visit_array_expression(arr),
-            E::BinaryExpression(bin_expr) => self.visit_binary_expression(bin_expr),
-            _ => todo!("{:?}", &expr),
-        }
-    }
-
-    fn visit_object_expression(&self, obj: &ObjectExpression) {
-        for item in obj.items.iter() {
-            match item {
-                ObjectItem::KV(kv) => match &kv.key {
-                    Key::Identifier(_) | Key::StringLiteral(_) => {}
-                    Key::ComputedProperty(prop) => self.visit_expression(&prop.expression),
-                },
-                ObjectItem::Identifier(_) => {}
-                ObjectItem::Method(_method) => todo!(),
-            };
-        }
-    }
-
-    fn visit_array_expression(&self, arr: &ArrayExpression) {
-        arr.items
-            .iter()
-            .for_each(|expr| self.visit_expression(expr));
-    }
+    // The parameters and the body of a function are declared in its own scope,
+    // once the body visitor enters it
+    fn visit_function_expression(&mut self, _expr: &FunctionExpression) {}
 
-    fn visit_binary_expression(&self, bin_expr: &BinaryExpression) {
-        self.visit_expression(&bin_expr.left);
-        self.visit_expression(&bin_expr.right);
-    }
-
-    fn visit_return_statement(&self, stmt: &ReturnStatement) {
-        self.visit_expression(&stmt.value);
-    }
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression) {}
 }
@@ -1,13 +1,18 @@
+use lexer::SourceFile;
 use parser::Parser;
 use pretty_assertions::assert_eq;
-use semantic::{analyze, errors::ErrorSeverity};
+use semantic::{
+    analyze,
+    errors::{ErrorData, ErrorSeverity},
+    types::ResolvedType,
+};
 
 #[test]
 fn string_number_mismatch() {
     let code = "let foo: string = 123;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -18,9 +23,9 @@ fn string_number_mismatch() {
 #[test]
 fn number_number_match() {
     let code = "let foo: string = 123;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -30,9 +35,9 @@ fn number_number_match() {
 #[test]
 fn string_string_match() {
     let code = "let foo: string = \"abc\";";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -42,9 +47,9 @@ fn string_string_match() {
 #[test]
 fn boolean_boolean_match() {
     let code = "let foo: boolean = true;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -54,9 +59,9 @@ fn boolean_boolean_match() {
 #[test]
 fn boolean_string_mismatch() {
     let code = "let foo: boolean = \"abc\";";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -66,9 +71,9 @@ fn boolean_string_mismatch() {
 #[test]
 fn string_boolean_mismatch() {
     let code = "let foo: string = true;";
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -83,9 +88,9 @@ fn variable_reference_type_match() {
                         
         let n: number = 123
         let m: number = n"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -99,9 +104,9 @@ fn variable_reference_type_mismatch() {
 
                         let fizz = "abc"
                         let buzz: number = fizz"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -111,9 +116,9 @@ fn variable_reference_type_mismatch() {
 #[test]
 fn record_string_number_match() {
     let code = r#"let obj: Record<string, number> = { days: 12, months: 2 }"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -123,9 +128,9 @@ fn record_string_number_match() {
 #[test]
 fn record_string_boolean_mismatch() {
     let code = r#"let obj: Record<string, boolean> = { days: 12, months: 2 }"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -135,9 +140,9 @@ fn record_string_boolean_mismatch() {
 #[test]
 fn array_string_match() {
     let code = r#"let arr: number[] = [123, 456, 789];"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -147,9 +152,9 @@ fn array_string_match() {
 #[test]
 fn array_string_mismatch() {
     let code = r#"let arr: string[] = ["abc", 123, "def", 456];"#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -163,9 +168,9 @@ fn function_statement_matching_return_type_number() {
         return 123;
     }
     "#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -179,9 +184,9 @@ fn function_statement_mismatched_return_type_number() {
         return "abc";
     }
     "#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -195,9 +200,9 @@ fn function_statement_adding_same_types() {
         return num1 + num2;
     }
     "#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
@@ -211,11 +216,82 @@ fn return_binary_operation_type_mismatch() {
         return 3 * s;
     }
     "#;
-    let mut parser = Parser::new(&code);
+    let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap_or_else(|err| {
-        err.print(&code);
+        err.print(&SourceFile::new("main.ts", code));
         panic!();
     });
     let errors = analyze(&ast);
     assert_eq!(errors.len(), 1);
 }
+
+#[test]
+fn mismatch_points_at_annotation_and_expression() {
+    let code = "let foo: string = 123;";
+    let mut parser = Parser::new(code);
+    let ast = parser.parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    });
+    let errors = analyze(&ast);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].data.code(), "S0003");
+    assert_eq!(&code[errors[0].node.start..errors[0].node.end], "123");
+
+    let ErrorData::TypeMismatch {
+        annotation: Some(annotation),
+        ..
+    } = &errors[0].data
+    else {
+        panic!("expected a type mismatch with an annotation");
+    };
+    assert!(code[annotation.start..annotation.end].contains("string"));
+}
+
+#[test]
+fn rest_parameter_is_an_array() {
+    let code = r#"
+    function double(...nums) {
+        return nums * 2;
+    }
+    "#;
+    let mut parser = Parser::new(code);
+    let ast = parser.parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    });
+    let errors = analyze(&ast);
+    assert_eq!(errors.len(), 1);
+
+    let ErrorData::TypeMismatch { expected_type, .. } = &errors[0].data else {
+        panic!("expected a type mismatch");
+    };
+    assert_eq!(
+        expected_type,
+        &ResolvedType::Array(Box::new(ResolvedType::Unknown))
+    );
+}
+
+#[test]
+fn typed_rest_and_default_parameters() {
+    let code = r#"
+    function sum(first: number = 0, ...rest: number[]) {
+        return first + rest;
+    }
+    "#;
+    let mut parser = Parser::new(code);
+    let ast = parser.parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    });
+    let errors = analyze(&ast);
+    assert_eq!(errors.len(), 1);
+
+    let ErrorData::TypeMismatch { received_type, .. } = &errors[0].data else {
+        panic!("expected a type mismatch");
+    };
+    assert_eq!(
+        received_type,
+        &ResolvedType::Array(Box::new(ResolvedType::Number))
+    );
+}
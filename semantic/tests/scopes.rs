@@ -0,0 +1,86 @@
+use lexer::SourceFile;
+use parser::Parser;
+use pretty_assertions::assert_eq;
+use semantic::{analyze, errors::ErrorData};
+
+fn unknown_variables(code: &str) -> Vec<String> {
+    let mut parser = Parser::new(code);
+    let ast = parser.parse().unwrap_or_else(|err| {
+        err.print(&SourceFile::new("main.ts", code));
+        panic!();
+    });
+    analyze(&ast)
+        .into_iter()
+        .filter_map(|err| match err.data {
+            ErrorData::UnknownVariable { id } => Some(id.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn member_property_is_not_a_variable() {
+    let code = "let o = { a: 1 }; let z = o.a;";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn computed_member_property_is_a_variable() {
+    let code = "let o = { a: 1 }; let z = o[key];";
+    assert_eq!(unknown_variables(code), vec!["key"]);
+}
+
+#[test]
+fn object_keys_are_not_variables() {
+    let code = "let b = 1; let o = { b, c: b, m() {} };";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn class_element_keys_are_not_variables() {
+    let code = "class A { x = 1; m() {} static n() {} }";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn computed_class_element_key_is_a_variable() {
+    let code = "class A { [key] = 1 }";
+    assert_eq!(unknown_variables(code), vec!["key"]);
+}
+
+#[test]
+fn arrow_function_parameters() {
+    let code = "let f = (x) => x; let g = (y) => { return y; };";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn function_expression_parameters() {
+    let code = "let f = function (x) { let y = x; return y; };";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn method_parameters() {
+    let code = "class A { m(x) { return x; } }";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}
+
+#[test]
+fn parameters_are_only_visible_in_their_function() {
+    let code = r#"
+    function f(a: number) {
+        let b = a;
+        return b;
+    }
+    let f2 = (c) => c;
+    let d = a + b + c;
+    "#;
+    assert_eq!(unknown_variables(code), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn functions_can_use_outer_variables() {
+    let code = "let a = 1; function f() { return a; } let g = () => a;";
+    assert_eq!(unknown_variables(code), Vec::<String>::new());
+}